@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A range of bytes `[start, end)` within a stream
+ */
+export type ByteRange = { start: number, end: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A range of bytes together with the structures that claim it
+ */
+export type ClaimedRange = { start: number, end: number, structures: Array<string>, };
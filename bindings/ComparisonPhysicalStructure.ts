@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PhysicalStructure } from "./PhysicalStructure";
+
+export type ComparisonPhysicalStructure = { ref_structure: PhysicalStructure | null, comp_structure: PhysicalStructure | null, difference_indices: Array<[number, number]>, };
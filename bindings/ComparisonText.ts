@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The comparison of one text subdocument (main text, footnotes, ...) of two documents
+ */
+export type ComparisonText = { name: string, ref_text: string, comp_text: string, difference_indices: Array<[number, number]>, };
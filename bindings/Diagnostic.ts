@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ByteRange } from "./ByteRange";
+import type { Severity } from "./Severity";
+
+/**
+ * A problem found in a document, e.g. an fc/lcb pair that points past the end of its stream
+ */
+export type Diagnostic = { severity: Severity, 
+/**
+ * Stable identifier of the check, e.g. `fc_lcb_out_of_bounds`
+ */
+code: string, message: string, stream_name: string | null, range: ByteRange | null, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Response to an upload
+ */
+export type DocumentUpload = { id: string, 
+/**
+ * Whether the document had already been uploaded
+ */
+cached: boolean, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What a JSON output holds
+ */
+export type OutputKind = "logical" | "physical" | "logical_comparison" | "physical_comparison" | "text_comparison" | "coverage";
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * storing the physical bytes of a certain section of the document
+ */
+export type PhysicalStructure = { stream_name: string, structure_name: string | null, bytes: string, start_index: number, end_index: number, description: string | null, 
+/**
+ * the code of the diagnostic about the structure, e.g. `fc_lcb_out_of_bounds` for an fc/lcb
+ * pair that ends past its stream
+ */
+diagnostic: string | null, };
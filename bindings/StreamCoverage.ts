@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ByteRange } from "./ByteRange";
+import type { ClaimedRange } from "./ClaimedRange";
+
+/**
+ * How much of a stream is explained by the `PhysicalStructure`s
+ */
+export type StreamCoverage = { stream_name: string, stream_len: number, 
+/**
+ * Count of bytes claimed by at least one structure
+ */
+covered_bytes: number, percent_explained: number, 
+/**
+ * Ranges not claimed by any structure
+ */
+gaps: Array<ByteRange>, 
+/**
+ * Ranges claimed by more than one structure
+ */
+overlaps: Array<ClaimedRange>, 
+/**
+ * Structures that reach past the end of the stream
+ */
+out_of_bounds: Array<ClaimedRange>, };
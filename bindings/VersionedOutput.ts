@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Diagnostic } from "./Diagnostic";
+import type { OutputKind } from "./OutputKind";
+
+/**
+ * The envelope of every JSON output, `data` holds the structures or comparisons
+ */
+export type VersionedOutput<T> = { schema_version: number, kind: OutputKind, data: T, 
+/**
+ * Problems found while decoding the document, empty for comparisons
+ */
+diagnostics: Array<Diagnostic>, };
@@ -0,0 +1,154 @@
+//! Bookmarks of the main document, named ranges of CPs that the SttbfBkmk, PlcfBkf and PlcfBkl
+//! describe together
+use std::io::{self, Read, Seek};
+
+use super::{
+    model::*,
+    office_art::read_plcf,
+    stories::{read_cps, story_text},
+    sttb::read_sttb,
+};
+
+/// Hidden bookmarks that Word adds by itself and what they are for
+const HIDDEN_BOOKMARKS: [(&str, &str); 5] = [
+    ("_Toc", "table of contents entry"),
+    ("_Ref", "cross-reference target"),
+    ("_GoBack", "last edit position of Word 2010 and later"),
+    ("_Hlk", "hyperlink target"),
+    ("_PictureBullets", "picture bullets"),
+];
+
+/// Reads the bookmarks of the main document, the n-th name of the SttbfBkmk belongs to the n-th
+/// start of the PlcfBkf, whose FBKF points to its end in the PlcfBkl
+pub fn read_bookmarks<R: Read + Seek>(
+    table_stream: &mut R,
+    fib: &Fib,
+    main_text: &TextDoc,
+) -> io::Result<Vec<Bookmark>> {
+    let names = read_sttb(table_stream, (fib.fcSttbfbkmk, fib.lcbSttbfbkmk))?;
+    let Some(starts) = read_plcf::<FBKF, _>(table_stream, (fib.fcPlcfbkf, fib.lcbPlcfbkf))? else {
+        return Ok(vec![]);
+    };
+    let ends = read_cps(table_stream, (fib.fcPlcfbkl, fib.lcbPlcfbkl))?;
+
+    let bookmarks = names
+        .into_iter()
+        .zip(starts.rgfc.iter().zip(&starts.rgstruct))
+        .map(|((name, _), (&start_cp, fbkf))| {
+            let end_cp = ends.get(fbkf.ibkl as usize).copied();
+            // fCol marks bookmarks of table columns itcFirst to itcLim
+            let columns = (fbkf.bkc & 0x8000 != 0)
+                .then_some(((fbkf.bkc & 0x7F) as u8, ((fbkf.bkc >> 8) & 0x7F) as u8));
+            let hidden_kind = HIDDEN_BOOKMARKS
+                .iter()
+                .find(|(prefix, _)| name.starts_with(prefix))
+                .map(|(_, kind)| kind.to_string());
+
+            Bookmark {
+                hidden: name.starts_with('_'),
+                hidden_kind,
+                start_cp,
+                end_cp,
+                column_first: columns.map(|(first, _)| first),
+                column_lim: columns.map(|(_, lim)| lim),
+                text: end_cp
+                    .map(|end_cp| story_text(main_text, start_cp, end_cp))
+                    .unwrap_or_default(),
+                name,
+            }
+        })
+        .collect();
+
+    Ok(bookmarks)
+}
+
+/// The hidden bookmarks that tell which version of Word last saved the document
+pub fn bookmark_diagnostics(bookmarks: &[Bookmark]) -> Vec<Diagnostic> {
+    bookmarks
+        .iter()
+        .filter(|bookmark| bookmark.name == "_GoBack")
+        .map(|bookmark| {
+            let message = format!(
+                "_GoBack bookmark at CP {}, the document was last saved by Word 2010 or later",
+                bookmark.start_cp
+            );
+            Diagnostic::new(Severity::Info, "bookmark_go_back", message)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_bookmarks() {
+        let xst = |text: &str| {
+            let mut xst = (text.len() as u16).to_le_bytes().to_vec();
+            xst.extend(text.encode_utf16().flat_map(u16::to_le_bytes));
+            xst
+        };
+        let mut table_stream = vec![0xFF, 0xFF, 3, 0, 0, 0];
+        for name in ["intro", "_GoBack", "_Toc1"] {
+            table_stream.extend(xst(name));
+        }
+        let sttbf_bkmk = (0, table_stream.len() as u32);
+
+        // the second bookmark covers the columns 1 to 3 of a table
+        let fc_plcf_bkf = table_stream.len();
+        for cp in [0i32, 4, 9, 12] {
+            table_stream.extend(cp.to_le_bytes());
+        }
+        for (ibkl, bkc) in [(1u16, 0u16), (0, 0x8000 | 3 << 8 | 1), (2, 0)] {
+            table_stream.extend(ibkl.to_le_bytes());
+            table_stream.extend(bkc.to_le_bytes());
+        }
+        let fc_plcf_bkl = table_stream.len();
+        for cp in [4i32, 5, 12, 12] {
+            table_stream.extend(cp.to_le_bytes());
+        }
+
+        let fib = Fib {
+            fcSttbfbkmk: sttbf_bkmk.0,
+            lcbSttbfbkmk: sttbf_bkmk.1,
+            fcPlcfbkf: fc_plcf_bkf as i32,
+            lcbPlcfbkf: (fc_plcf_bkl - fc_plcf_bkf) as u32,
+            fcPlcfbkl: fc_plcf_bkl as i32,
+            lcbPlcfbkl: (table_stream.len() - fc_plcf_bkl) as u32,
+            ..Default::default()
+        };
+        let main_text = TextDoc::from(b"Some\x0Dtext of the table\r");
+
+        let bookmarks = read_bookmarks(&mut Cursor::new(table_stream), &fib, &main_text).unwrap();
+        let summary = bookmarks
+            .iter()
+            .map(|bookmark| {
+                (
+                    bookmark.name.as_str(),
+                    bookmark.hidden,
+                    bookmark.start_cp,
+                    bookmark.end_cp,
+                    bookmark.column_first.zip(bookmark.column_lim),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            [
+                ("intro", false, 0, Some(5), None),
+                ("_GoBack", true, 4, Some(4), Some((1, 3))),
+                ("_Toc1", true, 9, Some(12), None),
+            ]
+        );
+        assert_eq!(bookmarks[0].text, "Some{0x0D}");
+        assert_eq!(
+            bookmarks[2].hidden_kind.as_deref(),
+            Some("table of contents entry")
+        );
+
+        let diagnostics = bookmark_diagnostics(&bookmarks);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, "bookmark_go_back");
+    }
+}
@@ -0,0 +1,817 @@
+//! The raw layout of the compound file as described in [MS-CFB]. `cfb` only exposes streams and
+//! storages, so the header, the FAT and the directory sectors are read from the bytes of the file
+use std::{collections::HashSet, io};
+
+use byteorder::{ByteOrder, LittleEndian};
+
+use super::model::{Diagnostic, DirectoryEntry, PhysicalStructure, Severity};
+
+/// Stream name of the physical structures of the compound file itself
+pub const CONTAINER: &str = "<container>";
+
+/// Marks a free sector in the FAT
+const FREESECT: u32 = 0xFFFF_FFFF;
+/// Marks the last sector of a chain in the FAT
+const ENDOFCHAIN: u32 = 0xFFFF_FFFE;
+/// Marks a sector used by the FAT itself
+const FATSECT: u32 = 0xFFFF_FFFD;
+/// Marks a sector used by the DIFAT
+const DIFSECT: u32 = 0xFFFF_FFFC;
+/// The special values of the FAT start above this sector number
+const MAXREGSECT: u32 = 0xFFFF_FFFA;
+/// Marks a missing sibling or child in the directory
+const NOSTREAM: u32 = 0xFFFF_FFFF;
+
+const HEADER_SIGNATURE: [u8; 8] = [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+const HEADER_DIFAT_ENTRIES: usize = 109;
+const DIRECTORY_ENTRY_SIZE: usize = 128;
+
+/// The fields of the compound file header that locate the other structures
+struct Header {
+    major_version: u16,
+    sector_shift: u16,
+    mini_sector_shift: u16,
+    fat_sectors: u32,
+    first_directory_sector: u32,
+    mini_stream_cutoff: u32,
+    first_mini_fat_sector: u32,
+    mini_fat_sectors: u32,
+    first_difat_sector: u32,
+    difat_sectors: u32,
+    /// The first 109 FAT sectors, the others are listed in the DIFAT sectors
+    difat: Vec<u32>,
+}
+
+impl Header {
+    fn sector_size(&self) -> usize {
+        1 << self.sector_shift
+    }
+
+    fn mini_sector_size(&self) -> usize {
+        1 << self.mini_sector_shift
+    }
+}
+
+fn read_header(bytes: &[u8]) -> io::Result<Header> {
+    if bytes.len() < 512 || bytes[0..8] != HEADER_SIGNATURE {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "not a compound file, the header signature is missing",
+        ));
+    }
+
+    let sector_shift = LittleEndian::read_u16(&bytes[0x1E..]);
+    if !(7..=16).contains(&sector_shift) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid sector shift {sector_shift} in the compound file header"),
+        ));
+    }
+
+    Ok(Header {
+        major_version: LittleEndian::read_u16(&bytes[0x1A..]),
+        sector_shift,
+        mini_sector_shift: LittleEndian::read_u16(&bytes[0x20..]).min(sector_shift),
+        fat_sectors: LittleEndian::read_u32(&bytes[0x2C..]),
+        first_directory_sector: LittleEndian::read_u32(&bytes[0x30..]),
+        mini_stream_cutoff: LittleEndian::read_u32(&bytes[0x38..]),
+        first_mini_fat_sector: LittleEndian::read_u32(&bytes[0x3C..]),
+        mini_fat_sectors: LittleEndian::read_u32(&bytes[0x40..]),
+        first_difat_sector: LittleEndian::read_u32(&bytes[0x44..]),
+        difat_sectors: LittleEndian::read_u32(&bytes[0x48..]),
+        difat: (0..HEADER_DIFAT_ENTRIES)
+            .map(|i| LittleEndian::read_u32(&bytes[0x4C + i * 4..]))
+            .collect(),
+    })
+}
+
+/// The bytes of sector `sid`, the header takes up the first sector. `None` past the end of the
+/// file, a truncated last sector is returned as it is
+fn sector<'a>(bytes: &'a [u8], header: &Header, sid: u32) -> Option<&'a [u8]> {
+    let start = (sid as usize + 1).checked_mul(header.sector_size())?;
+    let end = (start + header.sector_size()).min(bytes.len());
+    bytes.get(start..end)
+}
+
+/// The FAT sectors listed in the header and the DIFAT sectors, and the DIFAT sectors themselves
+fn fat_and_difat_sectors(bytes: &[u8], header: &Header) -> (Vec<u32>, Vec<u32>) {
+    let mut fat_sectors = header.difat.clone();
+    let mut difat_sectors = vec![];
+
+    // every DIFAT sector ends with the next DIFAT sector
+    let mut difat_sector = header.first_difat_sector;
+    for _ in 0..header.difat_sectors {
+        if difat_sectors.contains(&difat_sector) {
+            break;
+        }
+        let Some(data) = sector(bytes, header, difat_sector) else {
+            break;
+        };
+        difat_sectors.push(difat_sector);
+        let entries = data
+            .chunks_exact(4)
+            .map(LittleEndian::read_u32)
+            .collect::<Vec<_>>();
+        let Some((&next, entries)) = entries.split_last() else {
+            break;
+        };
+        fat_sectors.extend(entries);
+        difat_sector = next;
+    }
+    fat_sectors.retain(|&sid| sid <= MAXREGSECT);
+
+    (fat_sectors, difat_sectors)
+}
+
+/// The FAT of the file, the concatenated entries of its FAT sectors
+fn read_fat(bytes: &[u8], header: &Header, fat_sectors: &[u32]) -> Vec<u32> {
+    fat_sectors
+        .iter()
+        .filter_map(|&sid| sector(bytes, header, sid))
+        .flat_map(|data| data.chunks_exact(4).map(LittleEndian::read_u32))
+        .collect()
+}
+
+/// The sectors of the chain starting at `start` in `table`, the FAT or the MiniFAT, with the
+/// reason the chain ended early if it does not end in ENDOFCHAIN. `count` is the number of
+/// sectors that exist
+fn walk_chain(table: &[u32], start: u32, count: usize) -> (Vec<u32>, Option<String>) {
+    let mut sectors = vec![];
+    let mut visited = HashSet::new();
+    let mut sid = start;
+    loop {
+        let anomaly = match sid {
+            ENDOFCHAIN => return (sectors, None),
+            FREESECT => "a free sector".to_string(),
+            FATSECT => "a FAT sector".to_string(),
+            DIFSECT => "a DIFAT sector".to_string(),
+            _ if sid > MAXREGSECT => format!("the reserved value {sid:#X}"),
+            _ if sid as usize >= count.min(table.len()) => {
+                format!("sector {sid}, past the last sector")
+            }
+            _ if !visited.insert(sid) => format!("sector {sid} a second time, it loops"),
+            _ => {
+                sectors.push(sid);
+                sid = table[sid as usize];
+                continue;
+            }
+        };
+        return (sectors, Some(format!("the chain leads to {anomaly}")));
+    }
+}
+
+/// Reads the entries of the directory and the path of those that are linked into the tree of the
+/// root storage. Unallocated entries are only kept if they still have a name
+pub fn read_directory(bytes: &[u8]) -> io::Result<Vec<DirectoryEntry>> {
+    let header = read_header(bytes)?;
+    let (fat_sectors, _) = fat_and_difat_sectors(bytes, &header);
+    let fat = read_fat(bytes, &header, &fat_sectors);
+
+    let (directory_sectors, _) = walk_chain(&fat, header.first_directory_sector, fat.len());
+    Ok(directory_entries(bytes, &header, &directory_sectors))
+}
+
+fn directory_entries(
+    bytes: &[u8],
+    header: &Header,
+    directory_sectors: &[u32],
+) -> Vec<DirectoryEntry> {
+    let mut entries = directory_sectors
+        .iter()
+        .map_while(|&sid| sector(bytes, header, sid))
+        .flat_map(|data| data.chunks_exact(DIRECTORY_ENTRY_SIZE))
+        .enumerate()
+        .map(|(sid, data)| directory_entry_from_bytes(sid as u32, data, header.major_version))
+        .filter(|entry| entry.object_type != "unallocated" || !entry.name.is_empty())
+        .collect::<Vec<_>>();
+
+    set_paths(&mut entries);
+    entries
+}
+
+fn directory_entry_from_bytes(sid: u32, data: &[u8], major_version: u16) -> DirectoryEntry {
+    // the length of the name includes its terminating null character
+    let name_length = (LittleEndian::read_u16(&data[64..]) as usize).min(64);
+    let name_units = data[..name_length.saturating_sub(2)]
+        .chunks_exact(2)
+        .map(LittleEndian::read_u16)
+        .collect::<Vec<_>>();
+    let link = |offset: usize| {
+        Some(LittleEndian::read_u32(&data[offset..])).filter(|&sid| sid != NOSTREAM)
+    };
+    let size = LittleEndian::read_u64(&data[120..]);
+
+    DirectoryEntry {
+        sid,
+        name: String::from_utf16_lossy(&name_units),
+        path: None,
+        object_type: match data[66] {
+            1 => "storage",
+            2 => "stream",
+            5 => "root",
+            _ => "unallocated",
+        }
+        .to_string(),
+        color: if data[67] == 0 { "red" } else { "black" }.to_string(),
+        left_sibling: link(68),
+        right_sibling: link(72),
+        child: link(76),
+        clsid: clsid_to_string(&data[80..96]),
+        state_bits: LittleEndian::read_u32(&data[96..]),
+        creation_time: LittleEndian::read_u64(&data[100..]),
+        modified_time: LittleEndian::read_u64(&data[108..]),
+        starting_sector: LittleEndian::read_u32(&data[116..]),
+        // version 3 files may leave garbage in the high 32 bits of the size
+        size: if major_version == 3 {
+            size & 0xFFFF_FFFF
+        } else {
+            size
+        },
+    }
+}
+
+/// Walks the red-black trees of the storages from the root entry and sets the path of every
+/// entry that is reached
+fn set_paths(entries: &mut [DirectoryEntry]) {
+    if entries
+        .first()
+        .map(|root| (root.sid, root.object_type.as_str()))
+        != Some((0, "root"))
+    {
+        return;
+    }
+    entries[0].path = Some("/".to_string());
+
+    // entries with their parent path, each entry is visited once even if the links loop
+    let mut pending = entries[0]
+        .child
+        .map(|child| vec![(child, String::new())])
+        .unwrap_or_default();
+    while let Some((sid, parent_path)) = pending.pop() {
+        let Some(entry) = entries.iter_mut().find(|entry| entry.sid == sid) else {
+            continue;
+        };
+        if entry.path.is_some() || entry.object_type == "unallocated" {
+            continue;
+        }
+
+        let path = format!("{parent_path}/{}", entry.name);
+        entry.path = Some(path.clone());
+        pending.extend(
+            [entry.left_sibling, entry.right_sibling]
+                .into_iter()
+                .flatten()
+                .map(|sibling| (sibling, parent_path.clone())),
+        );
+        if let Some(child) = entry.child.filter(|_| entry.object_type == "storage") {
+            pending.push((child, path));
+        }
+    }
+}
+
+/// Formats a CLSID the way GUIDs are written, the first three fields are little-endian
+fn clsid_to_string(clsid: &[u8]) -> String {
+    format!(
+        "{:08x}-{:04x}-{:04x}-{}-{}",
+        LittleEndian::read_u32(&clsid[0..]),
+        LittleEndian::read_u16(&clsid[4..]),
+        LittleEndian::read_u16(&clsid[6..]),
+        hex::encode(&clsid[8..10]),
+        hex::encode(&clsid[10..16]),
+    )
+}
+
+/// The sectors of the file or the mini sectors of the mini stream, with their allocation table
+struct Sectors {
+    /// `Sector` or `MiniSector`, for the names of the structures
+    unit: &'static str,
+    /// `sector` or `mini sector`, for the descriptions
+    label: &'static str,
+    size: u64,
+    /// The FAT or the MiniFAT
+    table: Vec<u32>,
+    /// Where each sector is in the file, a truncated last sector included
+    ranges: Vec<(u64, u64)>,
+    /// The chain each sector belongs to
+    owners: Vec<Option<String>>,
+}
+
+impl Sectors {
+    fn new(
+        (unit, label): (&'static str, &'static str),
+        size: u64,
+        table: Vec<u32>,
+        ranges: Vec<(u64, u64)>,
+    ) -> Self {
+        let owners = vec![None; ranges.len()];
+        Sectors {
+            unit,
+            label,
+            size,
+            table,
+            ranges,
+            owners,
+        }
+    }
+}
+
+/// The regions of the compound file found so far
+struct Container<'a> {
+    bytes: &'a [u8],
+    structures: Vec<PhysicalStructure>,
+    anomalies: Vec<PhysicalStructure>,
+}
+
+/// Reads the header, the FAT and the MiniFAT, walks every chain of the compound file and returns
+/// the regions of the file as structures of the `<container>` stream: the header, the DIFAT,
+/// FAT, directory and MiniFAT sectors, the slack past the size of every stream, orphaned
+/// sectors, free sectors that still hold data and chain anomalies
+pub fn container_structures(bytes: &[u8]) -> io::Result<Vec<PhysicalStructure>> {
+    let header = read_header(bytes)?;
+    let sector_size = header.sector_size() as u64;
+    let file_len = bytes.len() as u64;
+    let mut container = Container {
+        bytes,
+        structures: vec![],
+        anomalies: vec![],
+    };
+
+    let header_description = header_description(&header);
+    container.push_ranges(
+        vec![(0, sector_size.min(file_len))],
+        "Header",
+        &header_description,
+    );
+
+    let (fat_sectors, difat_sectors) = fat_and_difat_sectors(bytes, &header);
+    let sector_count = file_len.saturating_sub(1) / sector_size;
+    let ranges = (1..=sector_count)
+        .map(|i| (i * sector_size, ((i + 1) * sector_size).min(file_len)))
+        .collect();
+    let mut sectors = Sectors::new(
+        ("Sector", "sector"),
+        sector_size,
+        read_fat(bytes, &header, &fat_sectors),
+        ranges,
+    );
+
+    for (owner, owned_sectors, marker, description) in [
+        (
+            "DIFAT",
+            &difat_sectors,
+            DIFSECT,
+            "sectors listing the FAT sectors past the first 109",
+        ),
+        (
+            "FAT",
+            &fat_sectors,
+            FATSECT,
+            "sectors of the file allocation table",
+        ),
+    ] {
+        container.claim(&mut sectors, owned_sectors, owner);
+        for &sid in owned_sectors {
+            if sectors.table.get(sid as usize) != Some(&marker) {
+                let range = sectors.ranges.get(sid as usize).copied();
+                container.anomaly(
+                    range,
+                    owner,
+                    &format!("sector {sid} is not marked in the FAT"),
+                );
+            }
+        }
+        let ranges = sector_ranges(&sectors, owned_sectors);
+        container.push_ranges(ranges, owner, description);
+    }
+    if fat_sectors.len() != header.fat_sectors as usize {
+        container.anomaly(
+            None,
+            "FAT",
+            &format!(
+                "the header counts {} FAT sectors, the DIFAT lists {}",
+                header.fat_sectors,
+                fat_sectors.len()
+            ),
+        );
+    }
+
+    let directory_sectors =
+        container.walk(&mut sectors, header.first_directory_sector, "Directory");
+    let ranges = sector_ranges(&sectors, &directory_sectors);
+    container.push_ranges(ranges, "Directory", "sectors of the directory entries");
+
+    let mini_fat_sectors = match header.first_mini_fat_sector {
+        ENDOFCHAIN => vec![],
+        start => container.walk(&mut sectors, start, "MiniFAT"),
+    };
+    if mini_fat_sectors.len() != header.mini_fat_sectors as usize {
+        container.anomaly(
+            None,
+            "MiniFAT",
+            &format!(
+                "the header counts {} MiniFAT sectors, the chain has {}",
+                header.mini_fat_sectors,
+                mini_fat_sectors.len()
+            ),
+        );
+    }
+    let ranges = sector_ranges(&sectors, &mini_fat_sectors);
+    container.push_ranges(
+        ranges,
+        "MiniFAT",
+        "sectors of the mini stream allocation table",
+    );
+    let mini_fat = mini_fat_sectors
+        .iter()
+        .filter_map(|&sid| sector(bytes, &header, sid))
+        .flat_map(|data| data.chunks_exact(4).map(LittleEndian::read_u32))
+        .collect::<Vec<_>>();
+
+    // the root entry holds the mini stream, in which the streams below the cutoff are stored
+    let entries = directory_entries(bytes, &header, &directory_sectors);
+    let mut mini_sectors = Sectors::new(
+        ("MiniSector", "mini sector"),
+        header.mini_sector_size() as u64,
+        mini_fat,
+        vec![],
+    );
+    if let Some(root) = entries.first().filter(|root| root.object_type == "root") {
+        let mini_stream_sectors =
+            container.stream(&mut sectors, root.starting_sector, root.size, "mini stream");
+        let mini_sector_size = mini_sectors.size;
+        mini_sectors.ranges = (0..root.size.div_ceil(mini_sector_size))
+            .map_while(|i| {
+                let offset = i * mini_sector_size;
+                let sid = *mini_stream_sectors.get((offset / sector_size) as usize)?;
+                let start = (sid as u64 + 1) * sector_size + offset % sector_size;
+                Some((start, (start + mini_sector_size).min(file_len)))
+            })
+            .collect();
+        mini_sectors.owners = vec![None; mini_sectors.ranges.len()];
+    }
+
+    for entry in entries.iter().filter(|entry| entry.object_type == "stream") {
+        let owner = entry.path.clone().unwrap_or_else(|| entry.name.clone());
+        if entry.size < header.mini_stream_cutoff as u64 {
+            container.stream(&mut mini_sectors, entry.starting_sector, entry.size, &owner);
+        } else {
+            container.stream(&mut sectors, entry.starting_sector, entry.size, &owner);
+        }
+    }
+
+    container.unused(&sectors);
+    container.unused(&mini_sectors);
+
+    let mut structures = container.structures;
+    structures.extend(container.anomalies);
+    Ok(structures)
+}
+
+impl Container<'_> {
+    /// The bytes of the file in `start..end`, as far as they are in the file
+    fn structure(&self, start: u64, end: u64) -> PhysicalStructure {
+        let len = self.bytes.len() as u64;
+        let range = start.min(len) as usize..end.clamp(start, len) as usize;
+        PhysicalStructure {
+            stream_name: CONTAINER.to_string(),
+            structure_name: None,
+            bytes: self.bytes[range].to_vec(),
+            start_index: start as i64,
+            end_index: end as i64,
+            description: None,
+            diagnostic: None,
+        }
+    }
+
+    fn push_ranges(&mut self, ranges: Vec<(u64, u64)>, name: &str, description: &str) {
+        for (start, end) in merge_ranges(ranges) {
+            let structure = self
+                .structure(start, end)
+                .description(description)
+                .structure_name(name);
+            self.structures.push(structure);
+        }
+    }
+
+    /// Records a chain anomaly at the file range of the sector where it shows, empty if there is
+    /// no such sector
+    fn anomaly(&mut self, range: Option<(u64, u64)>, owner: &str, message: &str) {
+        let (start, end) = range.unwrap_or_default();
+        let structure = self
+            .structure(start, end)
+            .description(&format!("{owner}: {message}"))
+            .structure_name("ChainAnomaly");
+        self.anomalies.push(structure);
+    }
+
+    /// Marks `owned_sectors` as used by `owner`, a sector used by two chains is an anomaly
+    fn claim(&mut self, sectors: &mut Sectors, owned_sectors: &[u32], owner: &str) {
+        for &sid in owned_sectors {
+            let Some(sector_owner) = sectors.owners.get_mut(sid as usize) else {
+                continue;
+            };
+            match sector_owner {
+                Some(other) => {
+                    let message = format!("{} {sid} is also used by {other}", sectors.label);
+                    let range = sectors.ranges[sid as usize];
+                    self.anomaly(Some(range), owner, &message);
+                }
+                None => *sector_owner = Some(owner.to_string()),
+            }
+        }
+    }
+
+    /// Walks the chain starting at `start` and claims its sectors for `owner`
+    fn walk(&mut self, sectors: &mut Sectors, start: u32, owner: &str) -> Vec<u32> {
+        let (chain, anomaly) = walk_chain(&sectors.table, start, sectors.ranges.len());
+        if let Some(anomaly) = anomaly {
+            let range = chain.last().map(|&sid| sectors.ranges[sid as usize]);
+            self.anomaly(range, owner, &anomaly);
+        }
+        self.claim(sectors, &chain, owner);
+        chain
+    }
+
+    /// Walks the chain of a stream of `size` bytes, checks its length against the size and
+    /// records the slack past the size
+    fn stream(&mut self, sectors: &mut Sectors, start: u32, size: u64, owner: &str) -> Vec<u32> {
+        if size == 0 {
+            return vec![];
+        }
+
+        let chain = self.walk(sectors, start, owner);
+        let needed = size.div_ceil(sectors.size) as usize;
+        if chain.len() != needed {
+            let range = chain
+                .get(needed)
+                .or(chain.last())
+                .map(|&sid| sectors.ranges[sid as usize]);
+            let message = format!(
+                "the chain has {} {}s, {needed} hold its {size} bytes",
+                chain.len(),
+                sectors.label
+            );
+            self.anomaly(range, owner, &message);
+        }
+
+        let slack = chain
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &sid)| {
+                let (start, end) = sectors.ranges[sid as usize];
+                let used = size.saturating_sub(i as u64 * sectors.size);
+                (start + used < end).then_some((start + used, end))
+            })
+            .collect();
+        self.push_ranges(
+            slack,
+            "StreamSlack",
+            &format!("slack of {owner} past its {size} bytes"),
+        );
+
+        chain
+    }
+
+    /// Records the sectors that no chain uses although the table allocates them, and the free
+    /// sectors that still hold data
+    fn unused(&mut self, sectors: &Sectors) {
+        let mut orphaned = vec![];
+        let mut free_data = vec![];
+        for (sid, &(start, end)) in sectors.ranges.iter().enumerate() {
+            if sectors.owners[sid].is_some() {
+                continue;
+            }
+            match sectors.table.get(sid) {
+                Some(&FREESECT) => {
+                    if self.bytes[start as usize..end as usize]
+                        .iter()
+                        .any(|&byte| byte != 0)
+                    {
+                        free_data.push((start, end));
+                    }
+                }
+                _ => orphaned.push((start, end)),
+            }
+        }
+
+        let (unit, label) = (sectors.unit, sectors.label);
+        let description = format!("{label}s that are allocated but not part of any chain");
+        self.push_ranges(orphaned, &format!("Orphaned{unit}s"), &description);
+        let description = format!("free {label}s that still hold data");
+        self.push_ranges(free_data, &format!("Free{unit}Data"), &description);
+    }
+}
+
+/// The file ranges of `owned_sectors`
+fn sector_ranges(sectors: &Sectors, owned_sectors: &[u32]) -> Vec<(u64, u64)> {
+    owned_sectors
+        .iter()
+        .filter_map(|&sid| sectors.ranges.get(sid as usize).copied())
+        .collect()
+}
+
+/// Joins the ranges that follow each other, `ranges` are kept in their order
+fn merge_ranges(ranges: Vec<(u64, u64)>) -> Vec<(u64, u64)> {
+    let mut merged: Vec<(u64, u64)> = vec![];
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if last.1 == start => last.1 = end,
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+fn header_description(header: &Header) -> String {
+    let sector_name = |sid: u32| match sid {
+        ENDOFCHAIN => "ENDOFCHAIN".to_string(),
+        FREESECT => "FREESECT".to_string(),
+        sid => sid.to_string(),
+    };
+    format!(
+        "compound file version {}, {}-byte sectors, {}-byte mini sectors, streams below {} bytes \
+         in the mini stream, {} FAT sectors, directory at sector {}, MiniFAT at sector {} ({} \
+         sectors), DIFAT at sector {} ({} sectors)",
+        header.major_version,
+        header.sector_size(),
+        header.mini_sector_size(),
+        header.mini_stream_cutoff,
+        header.fat_sectors,
+        sector_name(header.first_directory_sector),
+        sector_name(header.first_mini_fat_sector),
+        header.mini_fat_sectors,
+        sector_name(header.first_difat_sector),
+        header.difat_sectors,
+    )
+}
+
+/// Warnings for the chain anomalies and the orphaned sectors among the container structures
+pub fn container_diagnostics(structures: &[PhysicalStructure]) -> Vec<Diagnostic> {
+    structures
+        .iter()
+        .filter_map(|structure| {
+            let code = match structure.structure_name.as_deref()? {
+                "ChainAnomaly" => "cfb_chain_anomaly",
+                "OrphanedSectors" | "OrphanedMiniSectors" => "cfb_orphaned_sectors",
+                _ => return None,
+            };
+            let message = structure.description.clone().unwrap_or_default();
+            let diagnostic = Diagnostic::new(Severity::Warning, code, message).range(
+                CONTAINER,
+                structure.start_index as u64,
+                structure.end_index as u64,
+            );
+            Some(diagnostic)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Write};
+
+    use cfb::CompoundFile;
+
+    #[test]
+    fn test_directory() {
+        let mut cfb = CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+        cfb.create_storage_all("/ObjectPool/_1234").unwrap();
+        cfb.set_state_bits("/ObjectPool", 0x42).unwrap();
+        for (path, size) in [
+            ("/WordDocument", 5000),
+            ("/1Table", 10),
+            ("/ObjectPool/_1234/CONTENTS", 3),
+        ] {
+            cfb.create_stream(path)
+                .unwrap()
+                .write_all(&vec![0; size])
+                .unwrap();
+        }
+        cfb.flush().unwrap();
+        let mut bytes = cfb.into_inner().into_inner();
+
+        // the CLSID of a Word document, written into the directory entry of the storage
+        let name = "_1234"
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect::<Vec<_>>();
+        let entry_start = bytes
+            .windows(name.len())
+            .position(|window| window == name)
+            .unwrap();
+        bytes[entry_start + 80..entry_start + 96].copy_from_slice(&[
+            0x06, 0x09, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x46,
+        ]);
+
+        let directory = read_directory(&bytes).unwrap();
+        let cfb = CompoundFile::open(Cursor::new(bytes)).unwrap();
+        assert_eq!(directory.len(), cfb.walk().count());
+        for entry in cfb.walk() {
+            let path = entry.path().to_string_lossy().to_string();
+            let directory_entry = directory
+                .iter()
+                .find(|directory_entry| directory_entry.path.as_ref() == Some(&path))
+                .unwrap();
+            assert_eq!(directory_entry.name, entry.name());
+            assert_eq!(directory_entry.size, entry.len());
+            assert_eq!(directory_entry.clsid, entry.clsid().to_string());
+            assert_eq!(directory_entry.state_bits, entry.state_bits());
+        }
+
+        let root = &directory[0];
+        assert_eq!((root.sid, root.object_type.as_str()), (0, "root"));
+        let object = directory
+            .iter()
+            .find(|entry| entry.name == "_1234")
+            .unwrap();
+        assert_eq!(object.object_type, "storage");
+        assert_eq!(object.clsid, "00020906-0000-0000-c000-000000000046");
+        assert_ne!(object.creation_time, 0);
+        assert!(object.child.is_some());
+
+        assert!(read_directory(b"not a compound file").is_err());
+    }
+
+    #[test]
+    fn test_container() {
+        let mut cfb =
+            CompoundFile::create_with_version(cfb::Version::V3, Cursor::new(Vec::new())).unwrap();
+        for (path, size) in [("/WordDocument", 5000), ("/1Table", 100)] {
+            cfb.create_stream(path)
+                .unwrap()
+                .write_all(&vec![0x11; size])
+                .unwrap();
+        }
+        cfb.flush().unwrap();
+        let mut bytes = cfb.into_inner().into_inner();
+
+        let names = |structures: &[PhysicalStructure]| {
+            structures
+                .iter()
+                .map(|structure| structure.structure_name.clone().unwrap())
+                .collect::<Vec<_>>()
+        };
+        let structures = container_structures(&bytes).unwrap();
+        assert!(structures
+            .iter()
+            .all(|structure| structure.stream_name == "<container>"));
+        assert_eq!(names(&structures)[..2], ["Header", "FAT"]);
+        assert!(!names(&structures).contains(&"ChainAnomaly".to_string()));
+        // 5000 bytes take up 10 sectors of 512 bytes
+        let slack = structures
+            .iter()
+            .find(|structure| {
+                structure.description.as_deref()
+                    == Some("slack of /WordDocument past its 5000 bytes")
+            })
+            .unwrap();
+        assert_eq!(slack.end_index - slack.start_index, 120);
+        assert_eq!(container_diagnostics(&structures), Vec::<Diagnostic>::new());
+
+        // a sector appended to the file is free in the FAT but holds data
+        let sector_count = bytes.len() / 512 - 1;
+        bytes.extend([0xAA; 512]);
+        let structures = container_structures(&bytes).unwrap();
+        let free_data = structures.last().unwrap();
+        assert_eq!(free_data.structure_name.as_deref(), Some("FreeSectorData"));
+        assert_eq!(free_data.start_index, (sector_count as i64 + 1) * 512);
+
+        // allocating it without a chain leaves it orphaned, and looping the WordDocument chain
+        // back to its start is an anomaly
+        let fat_start =
+            (u32::from_le_bytes(bytes[0x4C..0x50].try_into().unwrap()) as usize + 1) * 512;
+        let set_fat_entry = |bytes: &mut Vec<u8>, sid: usize, value: u32| {
+            bytes[fat_start + sid * 4..fat_start + sid * 4 + 4]
+                .copy_from_slice(&value.to_le_bytes())
+        };
+        set_fat_entry(&mut bytes, sector_count, 0xFFFF_FFFE);
+        let word_document = read_directory(&bytes)
+            .unwrap()
+            .into_iter()
+            .find(|entry| entry.name == "WordDocument")
+            .unwrap();
+        let first_sector = word_document.starting_sector as usize;
+        set_fat_entry(&mut bytes, first_sector + 9, first_sector as u32);
+
+        let structures = container_structures(&bytes).unwrap();
+        let codes = container_diagnostics(&structures)
+            .into_iter()
+            .map(|diagnostic| (diagnostic.code, diagnostic.message))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            [
+                (
+                    "cfb_orphaned_sectors".to_string(),
+                    "sectors that are allocated but not part of any chain".to_string()
+                ),
+                (
+                    "cfb_chain_anomaly".to_string(),
+                    format!("/WordDocument: the chain leads to sector {first_sector} a second time, it loops")
+                ),
+            ]
+        );
+    }
+}
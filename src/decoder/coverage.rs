@@ -0,0 +1,164 @@
+use super::model::{ByteRange, ClaimedRange, PhysicalStructure, StreamCoverage};
+
+/// The name a structure is reported under in gaps and overlaps
+fn structure_label(structure: &PhysicalStructure) -> String {
+    structure
+        .structure_name
+        .clone()
+        .or_else(|| structure.description.clone())
+        .unwrap_or_else(|| "unnamed structure".to_string())
+}
+
+/// Computes which bytes of a stream of `stream_len` bytes are claimed by `structures`.
+/// Structures with a negative or empty range (e.g. fc/lcb pairs that are not set) are ignored
+pub fn compute_stream_coverage(
+    stream_name: &str,
+    stream_len: u64,
+    structures: &[&PhysicalStructure],
+) -> StreamCoverage {
+    let mut ranges = vec![];
+    let mut out_of_bounds = vec![];
+
+    for structure in structures {
+        if structure.start_index < 0 || structure.end_index <= structure.start_index {
+            continue;
+        }
+        let (start, end) = (structure.start_index as u64, structure.end_index as u64);
+        if end > stream_len {
+            out_of_bounds.push(ClaimedRange {
+                start,
+                end,
+                structures: vec![structure_label(structure)],
+            });
+        }
+        if start < stream_len {
+            ranges.push((start, end.min(stream_len), structure_label(structure)));
+        }
+    }
+
+    // every start and end of a range splits the stream into segments that are claimed by the
+    // same set of structures
+    let mut boundaries = vec![0, stream_len];
+    for (start, end, _) in &ranges {
+        boundaries.push(*start);
+        boundaries.push(*end);
+    }
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    let mut covered_bytes = 0;
+    let mut gaps: Vec<ByteRange> = vec![];
+    let mut overlaps: Vec<ClaimedRange> = vec![];
+
+    for segment in boundaries.windows(2) {
+        let (start, end) = (segment[0], segment[1]);
+        let claimed_by = ranges
+            .iter()
+            .filter(|(range_start, range_end, _)| *range_start <= start && end <= *range_end)
+            .map(|(_, _, label)| label.clone())
+            .collect::<Vec<_>>();
+
+        match claimed_by.len() {
+            0 => match gaps.last_mut() {
+                Some(gap) if gap.end == start => gap.end = end,
+                _ => gaps.push(ByteRange { start, end }),
+            },
+            1 => covered_bytes += end - start,
+            _ => {
+                covered_bytes += end - start;
+                match overlaps.last_mut() {
+                    Some(overlap) if overlap.end == start && overlap.structures == claimed_by => {
+                        overlap.end = end
+                    }
+                    _ => overlaps.push(ClaimedRange {
+                        start,
+                        end,
+                        structures: claimed_by,
+                    }),
+                }
+            }
+        }
+    }
+
+    let percent_explained = if stream_len == 0 {
+        100.0
+    } else {
+        covered_bytes as f64 * 100.0 / stream_len as f64
+    };
+
+    StreamCoverage {
+        stream_name: stream_name.to_string(),
+        stream_len,
+        covered_bytes,
+        percent_explained,
+        gaps,
+        overlaps,
+        out_of_bounds,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::{from_reader::tests::fib_bytes, tests::document, WordDocument};
+
+    #[test]
+    fn test_stream_coverage() {
+        let structure = |start: i64, end: i64, name: &str| PhysicalStructure {
+            stream_name: "WordDocument".to_string(),
+            structure_name: Some(name.to_string()),
+            bytes: vec![],
+            start_index: start,
+            end_index: end,
+            description: None,
+            diagnostic: None,
+        };
+        let fib = structure(0, 10, "Fib");
+        let text = structure(8, 20, "Text");
+        let unset = structure(-1, 0, "unset");
+        let trailer = structure(30, 50, "Trailer");
+
+        let coverage =
+            compute_stream_coverage("WordDocument", 40, &[&fib, &text, &unset, &trailer]);
+
+        assert_eq!(coverage.covered_bytes, 30);
+        assert_eq!(coverage.percent_explained, 75.0);
+        assert_eq!(coverage.gaps, vec![ByteRange { start: 20, end: 30 }]);
+        assert_eq!(
+            coverage.overlaps,
+            vec![ClaimedRange {
+                start: 8,
+                end: 10,
+                structures: vec!["Fib".to_string(), "Text".to_string()],
+            }]
+        );
+        assert_eq!(coverage.out_of_bounds.len(), 1);
+        assert_eq!(coverage.out_of_bounds[0].end, 50);
+    }
+
+    #[test]
+    fn test_out_of_bounds_pair_coverage() {
+        // fcDggInfo/lcbDggInfo point past the end of the table stream
+        let bytes = document(fib_bytes(0xC1, 0x5D, &[]), b"Text\r", &[(50, 0x100, 0x10)]);
+        let coverage = WordDocument::read_bytes(bytes)
+            .unwrap()
+            .get_coverage()
+            .unwrap();
+
+        let table_stream = coverage
+            .iter()
+            .find(|coverage| coverage.stream_name == "0Table")
+            .unwrap();
+        assert_eq!(
+            table_stream.out_of_bounds,
+            vec![ClaimedRange {
+                start: 0x100,
+                end: 0x110,
+                structures: vec!["Office Drawing object table data".to_string()],
+            }]
+        );
+        // only the stylesheet is in the stream
+        assert_eq!(table_stream.covered_bytes, 14);
+        assert!(table_stream.gaps.is_empty());
+    }
+}
@@ -0,0 +1,717 @@
+//! Detection and decryption of password protected documents. Word documents are either XOR
+//! obfuscated or RC4 encrypted, see [MS-DOC] 2.2.6 and [MS-OFFCRYPTO] 2.3
+use std::io::{self, Cursor, Read, Seek, SeekFrom, Write};
+
+use byteorder::{ByteOrder, LittleEndian, ReadBytesExt};
+use cfb::CompoundFile;
+use md5::{Digest, Md5};
+use sha1::Sha1;
+
+use super::model::{Bytes, Encryption, EncryptionType, CB_RG_FC_LCB_97, NFIB_WORD_6};
+
+/// Bytes at the start of the WordDocument stream that are never encrypted
+const UNENCRYPTED_FIB_LEN: usize = 0x44;
+/// Word 6.0/95 leave less of their FIB unencrypted
+const UNENCRYPTED_FIB_LEN_WORD_6: usize = 0x34;
+/// RC4 is restarted with a new key every block
+const RC4_BLOCK_SIZE: usize = 0x200;
+/// Large enough for the FIB of every version, the rest of the FIB of an encrypted document is
+/// read as zeros
+const FIB_LEN: usize = 0x400;
+
+/// Used to fill up passwords shorter than 15 characters for XOR obfuscation
+const XOR_PAD: [u8; 15] = [
+    0xBB, 0xFF, 0xFF, 0xBA, 0xFF, 0xFF, 0xB9, 0x80, 0x00, 0xBE, 0x0F, 0x00, 0xBF, 0x0F, 0x00,
+];
+/// Initial XOR key by password length
+const XOR_INITIAL_CODE: [u16; 15] = [
+    0xE1F0, 0x1D0F, 0xCC9C, 0x84C0, 0x110C, 0x0E10, 0xF1CE, 0x313E, 0x1872, 0xE139, 0xD40F, 0x84F9,
+    0x280C, 0xA96A, 0x4EC3,
+];
+/// Mixed into the XOR key for every set bit of the password
+const XOR_MATRIX: [u16; 105] = [
+    0xAEFC, 0x4DD9, 0x9BB2, 0x2745, 0x4E8A, 0x9D14, 0x2A09, 0x7B61, 0xF6C2, 0xFDA5, 0xEB6B, 0xC6F7,
+    0x9DCF, 0x2BBF, 0x4563, 0x8AC6, 0x05AD, 0x0B5A, 0x16B4, 0x2D68, 0x5AD0, 0x0375, 0x06EA, 0x0DD4,
+    0x1BA8, 0x3750, 0x6EA0, 0xDD40, 0xD849, 0xA0B3, 0x5147, 0xA28E, 0x553D, 0xAA7A, 0x44D5, 0x6F45,
+    0xDE8A, 0xAD35, 0x4A4B, 0x9496, 0x390D, 0x721A, 0xEB23, 0xC667, 0x9CEF, 0x29FF, 0x53FE, 0xA7FC,
+    0x5FD9, 0x47D3, 0x8FA6, 0x0F6D, 0x1EDA, 0x3DB4, 0x7B68, 0xF6D0, 0xB861, 0x60E3, 0xC1C6, 0x93AD,
+    0x377B, 0x6EF6, 0xDDEC, 0x45A0, 0x8B40, 0x06A1, 0x0D42, 0x1A84, 0x3508, 0x6A10, 0xAA51, 0x4483,
+    0x8906, 0x022D, 0x045A, 0x08B4, 0x1168, 0x76B4, 0xED68, 0xCAF1, 0x85C3, 0x1BA7, 0x374E, 0x6E9C,
+    0x3730, 0x6E60, 0xDCC0, 0xA9A1, 0x4363, 0x86C6, 0x1DAD, 0x3331, 0x6662, 0xCCC4, 0x89A9, 0x0373,
+    0x06E6, 0x0DCC, 0x1021, 0x2042, 0x4084, 0x8108, 0x1231, 0x2462, 0x48C4,
+];
+
+/// Reads how the document is protected from the FIB and, for RC4, the EncryptionHeader at the
+/// start of the table stream. `None` when the document is not encrypted
+pub fn read_encryption<F: Read + Seek>(
+    cfb: &mut CompoundFile<F>,
+) -> io::Result<Option<Encryption>> {
+    let fib_base = FibBase::read(cfb)?;
+    if !fib_base.f_encrypted {
+        return Ok(None);
+    }
+
+    if fib_base.f_obfuscated || fib_base.is_word_6() {
+        return Ok(Some(Encryption {
+            encryptionType: EncryptionType::XorObfuscation,
+            cbHeader: 0,
+            vMajor: None,
+            vMinor: None,
+            AlgID: None,
+            AlgIDHash: None,
+            KeySize: None,
+            ProviderType: None,
+            CSPName: None,
+            Salt: None,
+            EncryptedVerifier: None,
+            EncryptedVerifierHash: None,
+            decrypted: false,
+        }));
+    }
+
+    // lKey holds the size of the EncryptionHeader for RC4
+    let mut table_stream = cfb.open_stream(fib_base.table_stream_name())?;
+    if fib_base.l_key as u64 > table_stream.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "lKey {} is larger than the {} stream ({} bytes)",
+                fib_base.l_key,
+                fib_base.table_stream_name(),
+                table_stream.len()
+            ),
+        ));
+    }
+    let mut header = vec![0; fib_base.l_key as usize];
+    table_stream.read_exact(&mut header)?;
+    encryption_header_from_bytes(&header).map(Some)
+}
+
+/// The bytes of the FIB that are never encrypted, followed by zeros in place of the rest of the
+/// FIB, so that the FIB of a document can be read without its password
+pub fn unencrypted_fib<F: Read + Seek>(cfb: &mut CompoundFile<F>) -> io::Result<Vec<u8>> {
+    let fib_base = FibBase::read(cfb)?;
+    let mut fib = vec![0; FIB_LEN];
+    cfb.open_stream("WordDocument")?
+        .read_exact(&mut fib[..fib_base.unencrypted_fib_len()])?;
+
+    // Csw and Clw are never encrypted but Cfclcb is, the Word 97 pairs are read as zeros
+    if !fib_base.is_word_6() {
+        let csw = LittleEndian::read_u16(&fib[0x20..]) as usize;
+        let clw_offset = 0x22 + csw * 2;
+        let cfclcb_offset = fib
+            .get(clw_offset..clw_offset + 2)
+            .map(|clw| clw_offset + 2 + LittleEndian::read_u16(clw) as usize * 4);
+        if let Some(cfclcb) = cfclcb_offset.and_then(|offset| fib.get_mut(offset..offset + 2)) {
+            cfclcb.copy_from_slice(&CB_RG_FC_LCB_97.to_le_bytes());
+        }
+    }
+    Ok(fib)
+}
+
+/// Decrypts the WordDocument, table and Data streams of the document in place, so that they
+/// can be decoded like those of any other document. Fails if `password` is wrong
+pub fn decrypt<F: Read + Write + Seek>(
+    cfb: &mut CompoundFile<F>,
+    encryption: &Encryption,
+    password: &str,
+) -> io::Result<()> {
+    let fib_base = FibBase::read(cfb)?;
+    let cipher = Cipher::new(encryption, fib_base.l_key, password)?;
+
+    let mut streams = vec![("WordDocument", fib_base.unencrypted_fib_len())];
+    // Word 6.0/95 files have no table stream
+    if !fib_base.is_word_6() {
+        streams.push((fib_base.table_stream_name(), encryption.cbHeader as usize));
+    }
+    if cfb.is_stream("Data") {
+        streams.push(("Data", 0));
+    }
+
+    for (stream_name, unencrypted_len) in streams {
+        let mut stream = cfb.open_stream(stream_name)?;
+        let mut bytes = Vec::new();
+        stream.read_to_end(&mut bytes)?;
+
+        let unencrypted_len = unencrypted_len.min(bytes.len());
+        let unencrypted = bytes[..unencrypted_len].to_vec();
+        cipher.decrypt(&mut bytes);
+        bytes[..unencrypted_len].copy_from_slice(&unencrypted);
+
+        stream.seek(SeekFrom::Start(0))?;
+        stream.write_all(&bytes)?;
+    }
+
+    Ok(())
+}
+
+/// The few FIB fields needed before the document is decrypted, they are never encrypted
+struct FibBase {
+    n_fib: u16,
+    f_encrypted: bool,
+    f_which_tbl_stm: bool,
+    f_obfuscated: bool,
+    l_key: u32,
+}
+
+impl FibBase {
+    fn read<F: Read + Seek>(cfb: &mut CompoundFile<F>) -> io::Result<Self> {
+        let mut word_doc_stream = cfb.open_stream("WordDocument")?;
+        word_doc_stream.seek(SeekFrom::Start(0x02))?;
+        let n_fib = word_doc_stream.read_u16::<LittleEndian>()?;
+        word_doc_stream.seek(SeekFrom::Start(0x0A))?;
+        let bitfield = word_doc_stream.read_u16::<LittleEndian>()?;
+        word_doc_stream.seek(SeekFrom::Start(0x0E))?;
+        let l_key = word_doc_stream.read_u32::<LittleEndian>()?;
+
+        Ok(FibBase {
+            n_fib,
+            f_encrypted: (bitfield & 0x0100) == 0x0100,
+            f_which_tbl_stm: (bitfield & 0x0200) == 0x0200,
+            f_obfuscated: (bitfield & 0x8000) == 0x8000,
+            l_key,
+        })
+    }
+
+    fn is_word_6(&self) -> bool {
+        NFIB_WORD_6.contains(&self.n_fib)
+    }
+
+    fn unencrypted_fib_len(&self) -> usize {
+        if self.is_word_6() {
+            UNENCRYPTED_FIB_LEN_WORD_6
+        } else {
+            UNENCRYPTED_FIB_LEN
+        }
+    }
+
+    fn table_stream_name(&self) -> &'static str {
+        if self.f_which_tbl_stm {
+            "1Table"
+        } else {
+            "0Table"
+        }
+    }
+}
+
+/// Reads the RC4 or RC4 CryptoAPI EncryptionHeader (with its EncryptionVerifier)
+#[allow(non_snake_case)]
+fn encryption_header_from_bytes(header: &[u8]) -> io::Result<Encryption> {
+    let cbHeader = header.len() as u32;
+    let mut reader = Cursor::new(header);
+    let vMajor = reader.read_u16::<LittleEndian>()?;
+    let vMinor = reader.read_u16::<LittleEndian>()?;
+
+    let read_bytes = |reader: &mut Cursor<&[u8]>, len: usize| -> io::Result<Bytes> {
+        let mut bytes = vec![0; len];
+        reader.read_exact(&mut bytes)?;
+        Ok(Bytes::new(bytes))
+    };
+
+    match (vMajor, vMinor) {
+        (1, 1) => Ok(Encryption {
+            encryptionType: EncryptionType::Rc4,
+            cbHeader,
+            vMajor: Some(vMajor),
+            vMinor: Some(vMinor),
+            AlgID: None,
+            AlgIDHash: None,
+            KeySize: None,
+            ProviderType: None,
+            CSPName: None,
+            Salt: Some(read_bytes(&mut reader, 16)?),
+            EncryptedVerifier: Some(read_bytes(&mut reader, 16)?),
+            EncryptedVerifierHash: Some(read_bytes(&mut reader, 16)?),
+            decrypted: false,
+        }),
+        (2..=4, 2) => {
+            let _flags = reader.read_u32::<LittleEndian>()?;
+            let header_size = reader.read_u32::<LittleEndian>()?;
+            let header_end = reader.position() + header_size as u64;
+
+            let _flags = reader.read_u32::<LittleEndian>()?;
+            let _size_extra = reader.read_u32::<LittleEndian>()?;
+            let AlgID = reader.read_u32::<LittleEndian>()?;
+            let AlgIDHash = reader.read_u32::<LittleEndian>()?;
+            let KeySize = reader.read_u32::<LittleEndian>()?;
+            // 0 stands for 40 bits, RC4 CryptoAPI keys are 40 to 128 bits long
+            if KeySize != 0 && !(40..=128).contains(&KeySize) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("unsupported RC4 CryptoAPI KeySize {KeySize}"),
+                ));
+            }
+            let ProviderType = reader.read_u32::<LittleEndian>()?;
+            let _reserved = reader.read_u64::<LittleEndian>()?;
+
+            // the null terminated name of the cryptographic provider fills the rest of the header
+            let mut csp_name = vec![0; header_end.saturating_sub(reader.position()) as usize / 2];
+            reader.read_u16_into::<LittleEndian>(&mut csp_name)?;
+            let CSPName = String::from_utf16_lossy(&csp_name)
+                .trim_end_matches('\0')
+                .to_string();
+
+            let salt_size = reader.read_u32::<LittleEndian>()?;
+            let Salt = read_bytes(&mut reader, salt_size as usize)?;
+            let EncryptedVerifier = read_bytes(&mut reader, 16)?;
+            let verifier_hash_size = reader.read_u32::<LittleEndian>()?;
+            let EncryptedVerifierHash = read_bytes(&mut reader, verifier_hash_size as usize)?;
+
+            Ok(Encryption {
+                encryptionType: EncryptionType::Rc4CryptoApi,
+                cbHeader,
+                vMajor: Some(vMajor),
+                vMinor: Some(vMinor),
+                AlgID: Some(AlgID),
+                AlgIDHash: Some(AlgIDHash),
+                KeySize: Some(KeySize),
+                ProviderType: Some(ProviderType),
+                CSPName: Some(CSPName),
+                Salt: Some(Salt),
+                EncryptedVerifier: Some(EncryptedVerifier),
+                EncryptedVerifierHash: Some(EncryptedVerifierHash),
+                decrypted: false,
+            })
+        }
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unsupported encryption version {vMajor}.{vMinor}"),
+        )),
+    }
+}
+
+/// The keys derived from a password, checked against the verifier stored in the document
+enum Cipher {
+    /// The 16 byte XOR array, applied by stream offset
+    Xor([u8; 16]),
+    /// First 5 bytes of the hash of the password and salt, hashed with the block number for the
+    /// key of each block
+    Rc4([u8; 5]),
+    /// Hash of the salt and password, hashed with the block number for the key of each block
+    Rc4CryptoApi { hash: [u8; 20], key_len: usize },
+}
+
+impl Cipher {
+    fn new(encryption: &Encryption, l_key: u32, password: &str) -> io::Result<Self> {
+        let wrong_password = || io::Error::new(io::ErrorKind::InvalidInput, "wrong password");
+        let bytes_of = |bytes: &Option<Bytes>| -> io::Result<Vec<u8>> {
+            bytes
+                .as_ref()
+                .map(|bytes| bytes.bytes.clone())
+                .ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, "missing encryption verifier")
+                })
+        };
+
+        let cipher = match encryption.encryptionType {
+            EncryptionType::XorObfuscation => {
+                let password = xor_password(password);
+                // lKey holds the XOR key in its high and the password verifier in its low word
+                if xor_verifier(&password) != l_key as u16 {
+                    return Err(wrong_password());
+                }
+                Cipher::Xor(xor_array(&password))
+            }
+            EncryptionType::Rc4 => {
+                let salt = bytes_of(&encryption.Salt)?;
+                let password_hash = Md5::digest(utf_16_le(password));
+                let mut hasher = Md5::new();
+                for _ in 0..16 {
+                    hasher.update(&password_hash[..5]);
+                    hasher.update(&salt);
+                }
+                let mut hash = [0; 5];
+                hash.copy_from_slice(&hasher.finalize()[..5]);
+                Cipher::Rc4(hash)
+            }
+            EncryptionType::Rc4CryptoApi => {
+                let salt = bytes_of(&encryption.Salt)?;
+                let hash = Sha1::new()
+                    .chain_update(&salt)
+                    .chain_update(utf_16_le(password))
+                    .finalize()
+                    .into();
+                // a KeySize of 0 means 40 bits
+                let key_len = match encryption.KeySize.unwrap_or(0) {
+                    0 => 5,
+                    key_size => (key_size / 8) as usize,
+                };
+                Cipher::Rc4CryptoApi { hash, key_len }
+            }
+        };
+
+        if encryption.encryptionType != EncryptionType::XorObfuscation {
+            // the verifier and its hash are encrypted with the key of block 0, one after the other
+            let mut verifier = bytes_of(&encryption.EncryptedVerifier)?;
+            let verifier_len = verifier.len();
+            verifier.extend(bytes_of(&encryption.EncryptedVerifierHash)?);
+            Rc4::new(&cipher.block_key(0)).apply(&mut verifier);
+
+            let (verifier, verifier_hash) = verifier.split_at(verifier_len);
+            let expected_hash = match cipher {
+                Cipher::Rc4CryptoApi { .. } => Sha1::digest(verifier).to_vec(),
+                _ => Md5::digest(verifier).to_vec(),
+            };
+            if !verifier_hash.starts_with(&expected_hash) {
+                return Err(wrong_password());
+            }
+        }
+
+        Ok(cipher)
+    }
+
+    /// The RC4 key of the `block`th block of a stream
+    fn block_key(&self, block: u32) -> Vec<u8> {
+        match self {
+            Cipher::Xor(_) => vec![],
+            Cipher::Rc4(hash) => Md5::new()
+                .chain_update(hash)
+                .chain_update(block.to_le_bytes())
+                .finalize()
+                .to_vec(),
+            Cipher::Rc4CryptoApi { hash, key_len } => {
+                let mut key = Sha1::new()
+                    .chain_update(hash)
+                    .chain_update(block.to_le_bytes())
+                    .finalize()[..*key_len]
+                    .to_vec();
+                // 40 bit keys are padded to 128 bits
+                if *key_len == 5 {
+                    key.resize(16, 0);
+                }
+                key
+            }
+        }
+    }
+
+    /// Decrypts a whole stream, `bytes` starting at offset 0
+    fn decrypt(&self, bytes: &mut [u8]) {
+        match self {
+            Cipher::Xor(xor_array) => {
+                for (index, byte) in bytes.iter_mut().enumerate() {
+                    let key = xor_array[index % 16];
+                    // zeros and bytes equal to the key are left as they are
+                    if *byte != 0 && *byte != key {
+                        *byte ^= key;
+                    }
+                }
+            }
+            _ => {
+                for (block, chunk) in bytes.chunks_mut(RC4_BLOCK_SIZE).enumerate() {
+                    Rc4::new(&self.block_key(block as u32)).apply(chunk);
+                }
+            }
+        }
+    }
+}
+
+/// The RC4 stream cipher
+pub(super) struct Rc4 {
+    state: [u8; 256],
+    i: u8,
+    j: u8,
+}
+
+impl Rc4 {
+    pub(super) fn new(key: &[u8]) -> Self {
+        let mut state = [0; 256];
+        for (index, value) in state.iter_mut().enumerate() {
+            *value = index as u8;
+        }
+        let mut j: u8 = 0;
+        for i in 0..256 {
+            j = j.wrapping_add(state[i]).wrapping_add(key[i % key.len()]);
+            state.swap(i, j as usize);
+        }
+        Rc4 { state, i: 0, j: 0 }
+    }
+
+    pub(super) fn apply(&mut self, bytes: &mut [u8]) {
+        for byte in bytes {
+            self.i = self.i.wrapping_add(1);
+            self.j = self.j.wrapping_add(self.state[self.i as usize]);
+            self.state.swap(self.i as usize, self.j as usize);
+            let index = self.state[self.i as usize].wrapping_add(self.state[self.j as usize]);
+            *byte ^= self.state[index as usize];
+        }
+    }
+}
+
+fn utf_16_le(password: &str) -> Vec<u8> {
+    password.encode_utf16().flat_map(u16::to_le_bytes).collect()
+}
+
+/// XOR obfuscation uses one byte per character, the low byte unless it is 0. At most 15
+/// characters are used
+fn xor_password(password: &str) -> Vec<u8> {
+    password
+        .encode_utf16()
+        .take(15)
+        .map(|char| {
+            let [low, high] = char.to_le_bytes();
+            if low != 0 {
+                low
+            } else {
+                high
+            }
+        })
+        .collect()
+}
+
+/// CreateXorKey_Method1 of [MS-OFFCRYPTO]
+fn xor_key(password: &[u8]) -> u16 {
+    if password.is_empty() {
+        return 0;
+    }
+    let mut key = XOR_INITIAL_CODE[password.len() - 1];
+    let mut element = XOR_MATRIX.len();
+    for char in password.iter().rev() {
+        let mut char = *char;
+        for _ in 0..7 {
+            element -= 1;
+            if char & 0x40 != 0 {
+                key ^= XOR_MATRIX[element];
+            }
+            char <<= 1;
+        }
+    }
+    key
+}
+
+/// CreatePasswordVerifier_Method1 of [MS-OFFCRYPTO]
+fn xor_verifier(password: &[u8]) -> u16 {
+    let mut verifier: u16 = 0;
+    let length = [password.len() as u8];
+    for char in password.iter().rev().chain(length.iter()) {
+        let high_bit = (verifier & 0x4000) >> 14;
+        verifier = (((verifier << 1) & 0x7FFF) | high_bit) ^ *char as u16;
+    }
+    verifier ^ 0xCE4B
+}
+
+/// CreateXorArray_Method1 of [MS-OFFCRYPTO], the password filled up with `XOR_PAD`, each byte
+/// XORed with a byte of the XOR key and rotated right by one bit
+fn xor_array(password: &[u8]) -> [u8; 16] {
+    let [key_low, key_high] = xor_key(password).to_le_bytes();
+    let mut xor_array = [0; 16];
+    for (index, value) in xor_array.iter_mut().enumerate() {
+        let char = match index.checked_sub(password.len()) {
+            None => password[index],
+            Some(pad_index) => XOR_PAD[pad_index.min(XOR_PAD.len() - 1)],
+        };
+        let key = if index % 2 == 0 { key_low } else { key_high };
+        *value = (char ^ key).rotate_right(1);
+    }
+    xor_array
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encryption() {
+        let read_stream = |cfb: &mut CompoundFile<Cursor<Vec<u8>>>, name: &str| {
+            let mut bytes = vec![];
+            cfb.open_stream(name)
+                .unwrap()
+                .read_to_end(&mut bytes)
+                .unwrap();
+            bytes
+        };
+
+        // an XOR obfuscated document, lKey holds the password verifier of "password"
+        let mut word_document = vec![0; 0x80];
+        word_document[0x0A..0x0C].copy_from_slice(&0x8100u16.to_le_bytes());
+        word_document[0x0E..0x12].copy_from_slice(&0x83AFu32.to_le_bytes());
+        word_document[0x44..0x50].copy_from_slice(b"Hello world\r");
+        let mut cfb = CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+        cfb.create_stream("WordDocument")
+            .unwrap()
+            .write_all(&word_document)
+            .unwrap();
+        cfb.create_stream("0Table")
+            .unwrap()
+            .write_all(&[1, 2, 3])
+            .unwrap();
+
+        let encryption = read_encryption(&mut cfb).unwrap().unwrap();
+        assert_eq!(encryption.encryptionType, EncryptionType::XorObfuscation);
+        assert!(decrypt(&mut cfb, &encryption, "wrong").is_err());
+        // XOR obfuscation is its own inverse, the start of the FIB is left as it is
+        decrypt(&mut cfb, &encryption, "password").unwrap();
+        let obfuscated = read_stream(&mut cfb, "WordDocument");
+        assert_eq!(obfuscated[..0x44], word_document[..0x44]);
+        assert_ne!(obfuscated[0x44..0x50], word_document[0x44..0x50]);
+        decrypt(&mut cfb, &encryption, "password").unwrap();
+        assert_eq!(read_stream(&mut cfb, "WordDocument"), word_document);
+
+        // RC4 CryptoAPI is described by the EncryptionHeader at the start of the table stream
+        let mut header = vec![4, 0, 2, 0, 4, 0, 0, 0, 40, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0];
+        for value in [0x6801u32, 0x8004, 128, 1, 0, 0] {
+            header.extend(value.to_le_bytes());
+        }
+        header.extend("CSP\0".encode_utf16().flat_map(u16::to_le_bytes));
+        header.extend(16u32.to_le_bytes());
+        header.extend([1; 16 + 16]);
+        header.extend(20u32.to_le_bytes());
+        header.extend([2; 20]);
+        let mut word_document = vec![0; 0x80];
+        word_document[0x0A..0x0C].copy_from_slice(&0x0300u16.to_le_bytes());
+        word_document[0x0E..0x12].copy_from_slice(&(header.len() as u32).to_le_bytes());
+        let mut cfb = CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+        cfb.create_stream("WordDocument")
+            .unwrap()
+            .write_all(&word_document)
+            .unwrap();
+        cfb.create_stream("1Table")
+            .unwrap()
+            .write_all(&header)
+            .unwrap();
+
+        let encryption = read_encryption(&mut cfb).unwrap().unwrap();
+        assert_eq!(encryption.encryptionType, EncryptionType::Rc4CryptoApi);
+        assert_eq!((encryption.vMajor, encryption.vMinor), (Some(4), Some(2)));
+        assert_eq!(encryption.AlgID, Some(0x6801));
+        assert_eq!(encryption.KeySize, Some(128));
+        assert_eq!(encryption.CSPName.as_deref(), Some("CSP"));
+        assert_eq!(encryption.cbHeader, header.len() as u32);
+        assert!(decrypt(&mut cfb, &encryption, "password").is_err());
+
+        // a KeySize outside of 40 to 128 bits and an lKey past the end of the table stream
+        for (key_size, l_key) in [(200u32, header.len()), (8, header.len()), (128, 0x1000)] {
+            let mut header = header.clone();
+            header[28..32].copy_from_slice(&key_size.to_le_bytes());
+            word_document[0x0E..0x12].copy_from_slice(&(l_key as u32).to_le_bytes());
+            let mut cfb = CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+            cfb.create_stream("WordDocument")
+                .unwrap()
+                .write_all(&word_document)
+                .unwrap();
+            cfb.create_stream("1Table")
+                .unwrap()
+                .write_all(&header)
+                .unwrap();
+            let err = read_encryption(&mut cfb).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        }
+    }
+
+    #[test]
+    fn test_decrypt_known_answers() {
+        let mut plaintext = *b"Plaintext";
+        Rc4::new(b"Key").apply(&mut plaintext);
+        assert_eq!(
+            plaintext,
+            [0xBB, 0xF3, 0x16, 0xE8, 0xD9, 0x40, 0xAF, 0x0A, 0xD3]
+        );
+
+        // "Hello world\r" at 0x44 of the WordDocument stream and "table" after the header,
+        // encrypted with the password "password", the salt 0x10..0x20 and the verifier
+        // 0xA0..0xB0 as in [MS-OFFCRYPTO] 2.3.6.2 and 2.3.5.2, by a separate implementation
+        let salt = (0x10..0x20).collect::<Vec<u8>>();
+        let mut rc4_header = vec![1, 0, 1, 0];
+        rc4_header.extend(&salt);
+        rc4_header.extend([
+            0xE7, 0xAD, 0xF5, 0xE9, 0xD8, 0x4C, 0xCE, 0xE5, 0x9D, 0xFD, 0xF4, 0xF4, 0xEB, 0x66,
+            0xDE, 0x54,
+        ]);
+        rc4_header.extend([
+            0xBD, 0x34, 0x51, 0x00, 0x82, 0x2D, 0xF8, 0xA5, 0x50, 0xFE, 0xD6, 0x98, 0x12, 0x52,
+            0x50, 0x5D,
+        ]);
+        let mut crypto_api_header =
+            vec![4, 0, 2, 0, 4, 0, 0, 0, 40, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0];
+        for value in [0x6801u32, 0x8004, 128, 1, 0, 0] {
+            crypto_api_header.extend(value.to_le_bytes());
+        }
+        crypto_api_header.extend("CSP\0".encode_utf16().flat_map(u16::to_le_bytes));
+        crypto_api_header.extend(16u32.to_le_bytes());
+        crypto_api_header.extend(&salt);
+        crypto_api_header.extend([
+            0xDB, 0x06, 0x68, 0x00, 0x86, 0xB3, 0x5B, 0xB9, 0xC4, 0xC2, 0x09, 0x6B, 0x95, 0xF1,
+            0xCC, 0x4A,
+        ]);
+        crypto_api_header.extend(20u32.to_le_bytes());
+        crypto_api_header.extend([
+            0x83, 0x38, 0x5E, 0xB7, 0x38, 0x6A, 0x00, 0x48, 0x4D, 0x36, 0x3A, 0x45, 0x4A, 0x75,
+            0x00, 0x4C, 0x6F, 0xF7, 0x8B, 0x06,
+        ]);
+
+        let documents = [
+            (
+                EncryptionType::Rc4,
+                rc4_header,
+                [
+                    0x30, 0x5B, 0x4E, 0x62, 0x00, 0x30, 0x55, 0xC1, 0xB8, 0xD1, 0x18, 0x67,
+                ],
+                [0xD0, 0x6C, 0x8A, 0x4F, 0xF4],
+            ),
+            (
+                EncryptionType::Rc4CryptoApi,
+                crypto_api_header,
+                [
+                    0xAD, 0x60, 0x63, 0x3F, 0xAA, 0xB8, 0xD7, 0x50, 0xD3, 0xEF, 0x43, 0xE7,
+                ],
+                [0xE2, 0x3E, 0x70, 0xD1, 0x81],
+            ),
+        ];
+        for (encryption_type, header, word_document_text, table_text) in documents {
+            let mut word_document = vec![0; 0x44];
+            word_document[0x0A..0x0C].copy_from_slice(&0x0300u16.to_le_bytes());
+            word_document[0x0E..0x12].copy_from_slice(&(header.len() as u32).to_le_bytes());
+            let fib_base = word_document.clone();
+            word_document.extend(word_document_text);
+            let mut table = header.clone();
+            table.extend(table_text);
+            let mut cfb = CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+            cfb.create_stream("WordDocument")
+                .unwrap()
+                .write_all(&word_document)
+                .unwrap();
+            cfb.create_stream("1Table")
+                .unwrap()
+                .write_all(&table)
+                .unwrap();
+
+            let encryption = read_encryption(&mut cfb).unwrap().unwrap();
+            assert_eq!(encryption.encryptionType, encryption_type);
+            assert!(decrypt(&mut cfb, &encryption, "Password").is_err());
+            decrypt(&mut cfb, &encryption, "password").unwrap();
+
+            let mut decrypted = vec![];
+            let mut stream = cfb.open_stream("WordDocument").unwrap();
+            stream.read_to_end(&mut decrypted).unwrap();
+            assert_eq!(decrypted[..0x44], fib_base);
+            assert_eq!(&decrypted[0x44..], b"Hello world\r");
+            let mut decrypted = vec![];
+            let mut stream = cfb.open_stream("1Table").unwrap();
+            stream.read_to_end(&mut decrypted).unwrap();
+            assert_eq!(decrypted[..header.len()], header);
+            assert_eq!(&decrypted[header.len()..], b"table");
+        }
+    }
+
+    /// An XOR obfuscated Word 97 document with the password "password", its FIB is all 0xFF
+    /// past the part that is never encrypted
+    pub(crate) fn obfuscated_document() -> Vec<u8> {
+        let mut word_document = vec![0; 0x80];
+        word_document[0..2].copy_from_slice(&0xA5ECu16.to_le_bytes());
+        word_document[2..4].copy_from_slice(&0xC1u16.to_le_bytes());
+        word_document[0x0A..0x0C].copy_from_slice(&0x8100u16.to_le_bytes());
+        word_document[0x0E..0x12].copy_from_slice(&0x83AFu32.to_le_bytes());
+        word_document[0x20..0x22].copy_from_slice(&14u16.to_le_bytes());
+        word_document[0x3E..0x40].copy_from_slice(&22u16.to_le_bytes());
+        word_document[0x44..0x80].fill(0xFF);
+        let mut cfb = CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+        cfb.create_stream("WordDocument")
+            .unwrap()
+            .write_all(&word_document)
+            .unwrap();
+        cfb.create_stream("0Table")
+            .unwrap()
+            .write_all(&[1, 2, 3])
+            .unwrap();
+        cfb.into_inner().into_inner()
+    }
+}
@@ -0,0 +1,78 @@
+//! The parts of .docx files that documents are compared against, read from their zip container
+use std::io::{self, Cursor, Read};
+
+use zip::{result::ZipError, ZipArchive};
+
+/// Signature of a local file header, the start of a zip file
+const LOCAL_FILE_HEADER: u32 = 0x0403_4B50;
+
+/// Whether `bytes` are a zip file like .docx, rather than a compound file
+pub fn is_zip(bytes: &[u8]) -> bool {
+    bytes.starts_with(&LOCAL_FILE_HEADER.to_le_bytes())
+}
+
+/// The rsids of the `w:rsids` list of `word/settings.xml`, as 8 hexadecimal digits
+pub fn docx_rsids(bytes: &[u8]) -> io::Result<Vec<String>> {
+    let Some(settings) = zip_entry(bytes, "word/settings.xml")? else {
+        return Ok(vec![]);
+    };
+    let settings = String::from_utf8_lossy(&settings);
+
+    let Some(start) = settings.find("<w:rsids>") else {
+        return Ok(vec![]);
+    };
+    let end = settings[start..]
+        .find("</w:rsids>")
+        .map_or(settings.len(), |end| start + end);
+    Ok(settings[start..end]
+        .split("w:val=\"")
+        .skip(1)
+        .filter_map(|value| value.split('"').next())
+        .map(str::to_uppercase)
+        .collect())
+}
+
+/// The uncompressed bytes of the entry `name` of a zip file, `None` if it has no such entry
+pub fn zip_entry(bytes: &[u8], name: &str) -> io::Result<Option<Vec<u8>>> {
+    let mut archive = ZipArchive::new(Cursor::new(bytes))?;
+    let mut entry = match archive.by_name(name) {
+        Ok(entry) => entry,
+        Err(ZipError::FileNotFound) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    let mut uncompressed = vec![];
+    entry.read_to_end(&mut uncompressed)?;
+    Ok(Some(uncompressed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_docx_rsids() {
+        // a .docx with its settings deflated like Word saves them
+        let settings = br#"<w:settings><w:rsids><w:rsidRoot w:val="00a12b3c"/><w:rsid w:val="00C0FFEE"/></w:rsids><w:rsid w:val="0000DEAD"/></w:settings>"#;
+        let mut writer = zip::ZipWriter::new(Cursor::new(vec![]));
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+        for (name, content) in [
+            ("[Content_Types].xml", &b"<Types/>"[..]),
+            ("word/document.xml", b"<w:document/>"),
+            ("word/settings.xml", settings),
+        ] {
+            writer.start_file(name, options).unwrap();
+            writer.write_all(content).unwrap();
+        }
+        let zip = writer.finish().unwrap().into_inner();
+        assert!(is_zip(&zip));
+        assert_eq!(docx_rsids(&zip).unwrap(), ["00A12B3C", "00C0FFEE"]);
+        assert_eq!(
+            zip_entry(&zip, "word/document.xml").unwrap().unwrap(),
+            b"<w:document/>"
+        );
+        assert!(zip_entry(&zip, "word/missing.xml").unwrap().is_none());
+        assert!(docx_rsids(b"PK\x03\x04 not really a zip").is_err());
+    }
+}
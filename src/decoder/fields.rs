@@ -0,0 +1,313 @@
+//! Fields of every subdocument, which the PlcFld of the subdocument marks with the CPs of their
+//! field begin (0x13), separator (0x14) and end (0x15) characters
+use std::io::{self, Read, Seek};
+
+use super::{model::*, office_art::read_plcf};
+
+const FIELD_BEGIN: u8 = 0x13;
+const FIELD_SEPARATOR: u8 = 0x14;
+const FIELD_END: u8 = 0x15;
+
+/// The names of the field types, the flt of the field begin
+const FIELD_TYPES: [(u8, &str); 84] = [
+    (3, "REF"),
+    (5, "FTNREF"),
+    (6, "SET"),
+    (7, "IF"),
+    (8, "INDEX"),
+    (9, "TC"),
+    (10, "STYLEREF"),
+    (11, "RD"),
+    (12, "SEQ"),
+    (13, "TOC"),
+    (14, "INFO"),
+    (15, "TITLE"),
+    (16, "SUBJECT"),
+    (17, "AUTHOR"),
+    (18, "KEYWORDS"),
+    (19, "COMMENTS"),
+    (20, "LASTSAVEDBY"),
+    (21, "CREATEDATE"),
+    (22, "SAVEDATE"),
+    (23, "PRINTDATE"),
+    (24, "REVNUM"),
+    (25, "EDITTIME"),
+    (26, "NUMPAGES"),
+    (27, "NUMWORDS"),
+    (28, "NUMCHARS"),
+    (29, "FILENAME"),
+    (30, "TEMPLATE"),
+    (31, "DATE"),
+    (32, "TIME"),
+    (33, "PAGE"),
+    (34, "="),
+    (35, "QUOTE"),
+    (36, "INCLUDE"),
+    (37, "PAGEREF"),
+    (38, "ASK"),
+    (39, "FILLIN"),
+    (40, "DATA"),
+    (41, "NEXT"),
+    (42, "NEXTIF"),
+    (43, "SKIPIF"),
+    (44, "MERGEREC"),
+    (45, "DDE"),
+    (46, "DDEAUTO"),
+    (47, "GLOSSARY"),
+    (48, "PRINT"),
+    (49, "EQ"),
+    (50, "GOTOBUTTON"),
+    (51, "MACROBUTTON"),
+    (52, "AUTONUMOUT"),
+    (53, "AUTONUMLGL"),
+    (54, "AUTONUM"),
+    (55, "IMPORT"),
+    (56, "LINK"),
+    (57, "SYMBOL"),
+    (58, "EMBED"),
+    (59, "MERGEFIELD"),
+    (60, "USERNAME"),
+    (61, "USERINITIALS"),
+    (62, "USERADDRESS"),
+    (63, "BARCODE"),
+    (64, "DOCVARIABLE"),
+    (65, "SECTION"),
+    (66, "SECTIONPAGES"),
+    (67, "INCLUDEPICTURE"),
+    (68, "INCLUDETEXT"),
+    (69, "FILESIZE"),
+    (70, "FORMTEXT"),
+    (71, "FORMCHECKBOX"),
+    (72, "NOTEREF"),
+    (73, "TOA"),
+    (74, "TA"),
+    (75, "MERGESEQ"),
+    (77, "AUTOTEXT"),
+    (78, "COMPARE"),
+    (79, "ADDIN"),
+    (81, "FORMDROPDOWN"),
+    (82, "ADVANCE"),
+    (83, "DOCPROPERTY"),
+    (85, "CONTROL"),
+    (86, "HYPERLINK"),
+    (87, "AUTOTEXTLIST"),
+    (88, "LISTNUM"),
+    (89, "HTMLCONTROL"),
+    (90, "BIDIOUTLINE"),
+];
+
+/// Field types that run code or pull in content from outside the document
+const EXTERNAL_FIELD_TYPES: [&str; 7] = [
+    "DDE",
+    "DDEAUTO",
+    "INCLUDE",
+    "INCLUDEPICTURE",
+    "INCLUDETEXT",
+    "IMPORT",
+    "LINK",
+];
+
+/// Reads the fields of every subdocument, the fields that are nested in another field are its
+/// children
+pub fn read_fields<R: Read + Seek>(
+    table_stream: &mut R,
+    fib: &Fib,
+    text: &Text,
+) -> io::Result<Vec<Field>> {
+    let stories = [
+        (
+            "main",
+            (fib.fcPlcffldMom, fib.lcbPlcffldMom),
+            &text.main_text,
+        ),
+        (
+            "header",
+            (fib.fcPlcffldHdr, fib.lcbPlcffldHdr),
+            &text.header_text,
+        ),
+        (
+            "footnote",
+            (fib.fcPlcffldFtn, fib.lcbPlcffldFtn),
+            &text.footnote_text,
+        ),
+        (
+            "annotation",
+            (fib.fcPlcffldAtn, fib.lcbPlcffldAtn),
+            &text.annotation_text,
+        ),
+        (
+            "endnote",
+            (fib.fcPlcffldEdn, fib.lcbPlcffldEdn),
+            &text.endnote_text,
+        ),
+        (
+            "textbox",
+            (fib.fcPlcffldTxbx, fib.lcbPlcffldTxbx),
+            &text.textbox_text,
+        ),
+        (
+            "header textbox",
+            (fib.fcPlcffldHdrTxbx, fib.lcbPlcffldHdrTxbx),
+            &text.header_textbox_text,
+        ),
+    ];
+
+    let mut fields = vec![];
+    for (story, plc_fld, story_text) in stories {
+        if let Some(plc_fld) = read_plcf::<FLD, _>(table_stream, plc_fld)? {
+            fields.extend(field_tree(story, &plc_fld, story_text.bytes()));
+        }
+    }
+    Ok(fields)
+}
+
+/// Builds the fields of a subdocument from the field characters of its PlcFld, fields that are
+/// not ended run to the end of the subdocument
+pub fn field_tree(story: &str, plc_fld: &PLCF<FLD>, text: &[u8]) -> Vec<Field> {
+    let mut roots = vec![];
+    let mut open: Vec<Field> = vec![];
+
+    for (&cp, fld) in plc_fld.rgfc.iter().zip(&plc_fld.rgstruct) {
+        match fld.ch & 0x1F {
+            FIELD_BEGIN => open.push(Field {
+                story: story.to_string(),
+                flt: fld.grffld,
+                field_type: FIELD_TYPES
+                    .iter()
+                    .find(|(flt, _)| *flt == fld.grffld)
+                    .map(|(_, name)| name.to_string()),
+                begin_cp: cp,
+                separator_cp: None,
+                end_cp: None,
+                depth: open.len() as u32,
+                locked: false,
+                result_dirty: false,
+                result_edited: false,
+                instruction: String::new(),
+                result: String::new(),
+                children: vec![],
+            }),
+            FIELD_SEPARATOR => {
+                if let Some(field) = open.last_mut() {
+                    field.separator_cp = Some(cp);
+                }
+            }
+            FIELD_END => {
+                let Some(mut field) = open.pop() else {
+                    continue;
+                };
+                field.end_cp = Some(cp);
+                field.result_dirty = fld.grffld & 0x04 != 0;
+                field.result_edited = fld.grffld & 0x08 != 0;
+                field.locked = fld.grffld & 0x10 != 0;
+                close(field, text, &mut open, &mut roots);
+            }
+            _ => {}
+        }
+    }
+    while let Some(field) = open.pop() {
+        close(field, text, &mut open, &mut roots);
+    }
+
+    roots
+}
+
+/// Sets the instruction and result of `field` and adds it to the field it is nested in
+fn close(mut field: Field, text: &[u8], open: &mut [Field], roots: &mut Vec<Field>) {
+    let end = field.end_cp.unwrap_or(text.len() as i32);
+    let instruction_end = field.separator_cp.unwrap_or(end);
+    field.instruction = text_of(text, field.begin_cp + 1, instruction_end)
+        .trim()
+        .to_string();
+    if let Some(separator_cp) = field.separator_cp {
+        field.result = text_of(text, separator_cp + 1, end);
+    }
+
+    match open.last_mut() {
+        Some(parent) => parent.children.push(field),
+        None => roots.push(field),
+    }
+}
+
+/// The CPs `start` to `end` of `text` with every byte read as a character, like the instructions
+/// of the fields of the ObjectPool
+fn text_of(text: &[u8], start: i32, end: i32) -> String {
+    text.get(start.max(0) as usize..end.max(0) as usize)
+        .unwrap_or_default()
+        .iter()
+        .map(|&byte| byte as char)
+        .collect()
+}
+
+/// Flags the fields, nested ones included, that run code or pull in content from outside the
+/// document
+pub fn field_diagnostics(fields: &[Field]) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    for field in fields {
+        let keyword = field
+            .instruction
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_uppercase();
+        if EXTERNAL_FIELD_TYPES.contains(&keyword.as_str()) {
+            let severity = if keyword.starts_with("DDE") {
+                Severity::Warning
+            } else {
+                Severity::Info
+            };
+            let message = format!(
+                "{keyword} field at CP {} of the {} document: {}",
+                field.begin_cp, field.story, field.instruction
+            );
+            diagnostics.push(Diagnostic::new(severity, "field_external", message));
+        }
+        diagnostics.extend(field_diagnostics(&field.children));
+    }
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fields() {
+        // a HYPERLINK whose result holds a PAGE field and a DDEAUTO that is not ended
+        let text = b"\x13 HYPERLINK \"http://a\" \x14p. \x13PAGE\x142\x15\x15 \x13DDEAUTO c";
+        let fld = |ch: u8, grffld: u8| FLD { ch, grffld };
+        let plc_fld = PLCF {
+            rgfc: vec![0, 23, 27, 32, 34, 35, 37, 47],
+            rgstruct: vec![
+                fld(0x13, 86),
+                fld(0x14, 0),
+                fld(0x13, 33),
+                fld(0x14, 0),
+                fld(0x15, 0x40),
+                fld(0x15, 0x18),
+                fld(0x13, 46),
+            ],
+        };
+
+        let fields = field_tree("main", &plc_fld, text);
+        assert_eq!(fields.len(), 2);
+        let hyperlink = &fields[0];
+        assert_eq!(hyperlink.field_type.as_deref(), Some("HYPERLINK"));
+        assert_eq!(hyperlink.instruction, "HYPERLINK \"http://a\"");
+        assert_eq!(hyperlink.result, "p. \x13PAGE\x142\x15");
+        assert_eq!((hyperlink.end_cp, hyperlink.locked), (Some(35), true));
+        assert_eq!(hyperlink.children.len(), 1);
+        let page = &hyperlink.children[0];
+        assert_eq!((page.depth, page.instruction.as_str()), (1, "PAGE"));
+        assert_eq!(page.result, "2");
+        assert_eq!(
+            (fields[1].end_cp, fields[1].instruction.as_str()),
+            (None, "DDEAUTO c")
+        );
+
+        let diagnostics = field_diagnostics(&fields);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(diagnostics[0].code, "field_external");
+    }
+}
@@ -107,3 +107,147 @@ impl FromCStruct for RgIdOffset {
         }
     }
 }
+
+impl FromCStruct for PnFkpChpx {
+    fn c_size() -> usize {
+        4
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), 4);
+        let pn = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) & 0x003F_FFFF;
+
+        PnFkpChpx { pn }
+    }
+}
+
+impl FromCStruct for FRD {
+    fn c_size() -> usize {
+        2
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), 2);
+        let n_auto = i16::from_le_bytes([bytes[0], bytes[1]]);
+
+        FRD { nAuto: n_auto }
+    }
+}
+
+impl FromCStruct for ATRDPre10 {
+    fn c_size() -> usize {
+        30
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), 30);
+        // an Xst of at most 9 characters, padded to 20 bytes
+        let cch = u16::from_le_bytes([bytes[0], bytes[1]]).min(9) as usize;
+        let initials = bytes[2..2 + cch * 2]
+            .chunks_exact(2)
+            .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+            .collect::<Vec<_>>();
+        let ibst = i16::from_le_bytes([bytes[20], bytes[21]]);
+        let l_tag_bkmk = i32::from_le_bytes([bytes[26], bytes[27], bytes[28], bytes[29]]);
+
+        ATRDPre10 {
+            xstUsrInitl: String::from_utf16_lossy(&initials),
+            ibst,
+            lTagBkmk: l_tag_bkmk,
+        }
+    }
+}
+
+impl FromCStruct for FLD {
+    fn c_size() -> usize {
+        2
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), 2);
+
+        FLD {
+            ch: bytes[0],
+            grffld: bytes[1],
+        }
+    }
+}
+
+impl FromCStruct for FBKF {
+    fn c_size() -> usize {
+        4
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), 4);
+
+        FBKF {
+            ibkl: u16::from_le_bytes([bytes[0], bytes[1]]),
+            bkc: u16::from_le_bytes([bytes[2], bytes[3]]),
+        }
+    }
+}
+
+impl FromCStruct for FSPA {
+    fn c_size() -> usize {
+        26
+    }
+
+    #[allow(non_snake_case)]
+    fn from_bytes(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), 26);
+        let i32_at = |offset: usize| {
+            i32::from_le_bytes([
+                bytes[offset],
+                bytes[offset + 1],
+                bytes[offset + 2],
+                bytes[offset + 3],
+            ])
+        };
+        let bitfield = u16::from_le_bytes([bytes[20], bytes[21]]);
+
+        FSPA {
+            spid: i32_at(0),
+            xaLeft: i32_at(4),
+            yaTop: i32_at(8),
+            xaRight: i32_at(12),
+            yaBottom: i32_at(16),
+            fHdr: bitfield & 0x0001 == 0x0001,
+            bx: ((bitfield >> 1) & 0x3) as u8,
+            by: ((bitfield >> 3) & 0x3) as u8,
+            wr: ((bitfield >> 5) & 0xF) as u8,
+            wrk: ((bitfield >> 9) & 0xF) as u8,
+            fRcaSimple: bitfield & 0x2000 == 0x2000,
+            fBelowText: bitfield & 0x4000 == 0x4000,
+            fAnchorLock: bitfield & 0x8000 == 0x8000,
+            cTxbx: i32_at(22),
+        }
+    }
+}
+
+impl FromCStruct for FTXBXS {
+    fn c_size() -> usize {
+        22
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), 22);
+        let i32_at = |offset: usize| {
+            i32::from_le_bytes([
+                bytes[offset],
+                bytes[offset + 1],
+                bytes[offset + 2],
+                bytes[offset + 3],
+            ])
+        };
+
+        // itxbxsDest at 10 must be ignored
+        FTXBXS {
+            reusable01: i32_at(0),
+            reusable02: i32_at(4),
+            fReusable: i16::from_le_bytes([bytes[8], bytes[9]]),
+            lid: i32_at(14),
+            txidUndo: i32_at(18),
+        }
+    }
+}
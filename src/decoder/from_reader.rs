@@ -10,6 +10,9 @@ pub trait FromReader: Sized {
     fn from_reader<R: Read + Seek>(reader: &mut R) -> io::Result<Self>;
 }
 
+/// The fc/lcb pairs of the FIB with a description of what they point at
+pub type FcLcbPairs = Vec<(i32, u32, String)>;
+
 impl FromReader for Fib {
     fn from_reader<R: Read + Seek>(reader: &mut R) -> io::Result<Self> {
         Ok(fib_from_read_impl(reader)?.0)
@@ -19,7 +22,7 @@ impl FromReader for Fib {
 #[allow(non_snake_case)]
 pub fn fib_from_read_impl<R: Read + Seek>(
     reader: &mut R,
-) -> io::Result<(Fib, Vec<(i32, u32, String)>)> {
+) -> io::Result<(Fib, FcLcbPairs)> {
     reader.seek(SeekFrom::Start(0))?;
 
     let wIdent = Bytes::from_u16(reader.read_u16::<LittleEndian>()?);
@@ -69,421 +72,421 @@ pub fn fib_from_read_impl<R: Read + Seek>(
     let fcMac = reader.read_i32::<LittleEndian>()?;
 
     let Csw = reader.read_u16::<LittleEndian>()?;
-
-    let wMagicCreated = Bytes::from_u16(reader.read_u16::<LittleEndian>()?);
-    let wMagicRevised = Bytes::from_u16(reader.read_u16::<LittleEndian>()?);
-    let wMagicCreatedPrivate = Bytes::from_u16(reader.read_u16::<LittleEndian>()?);
-    let wMagicRevisedPrivate = Bytes::from_u16(reader.read_u16::<LittleEndian>()?);
-
-    let pnFbpChpFirst_W6 = reader.read_i16::<LittleEndian>()?;
-    let pnChpFirst_W6 = reader.read_i16::<LittleEndian>()?;
-    let cpnBteChp_W6 = reader.read_i16::<LittleEndian>()?;
-    let pnFbpPapFirst_W6 = reader.read_i16::<LittleEndian>()?;
-    let pnPapFirst_W6 = reader.read_i16::<LittleEndian>()?;
-    let cpnBtePap_W6 = reader.read_i16::<LittleEndian>()?;
-    let pnFbpLvcFirst_W6 = reader.read_i16::<LittleEndian>()?;
-    let pnLvcFirst_W6 = reader.read_i16::<LittleEndian>()?;
-    let cpnBteLvc_W6 = reader.read_i16::<LittleEndian>()?;
-    let LidFE = reader.read_i16::<LittleEndian>()?;
+    let mut fib_rg_w = read_block(reader, Csw as usize * 2)?;
+
+    let wMagicCreated = Bytes::from_u16(fib_rg_w.read_u16::<LittleEndian>()?);
+    let wMagicRevised = Bytes::from_u16(fib_rg_w.read_u16::<LittleEndian>()?);
+    let wMagicCreatedPrivate = Bytes::from_u16(fib_rg_w.read_u16::<LittleEndian>()?);
+    let wMagicRevisedPrivate = Bytes::from_u16(fib_rg_w.read_u16::<LittleEndian>()?);
+
+    let pnFbpChpFirst_W6 = fib_rg_w.read_i16::<LittleEndian>()?;
+    let pnChpFirst_W6 = fib_rg_w.read_i16::<LittleEndian>()?;
+    let cpnBteChp_W6 = fib_rg_w.read_i16::<LittleEndian>()?;
+    let pnFbpPapFirst_W6 = fib_rg_w.read_i16::<LittleEndian>()?;
+    let pnPapFirst_W6 = fib_rg_w.read_i16::<LittleEndian>()?;
+    let cpnBtePap_W6 = fib_rg_w.read_i16::<LittleEndian>()?;
+    let pnFbpLvcFirst_W6 = fib_rg_w.read_i16::<LittleEndian>()?;
+    let pnLvcFirst_W6 = fib_rg_w.read_i16::<LittleEndian>()?;
+    let cpnBteLvc_W6 = fib_rg_w.read_i16::<LittleEndian>()?;
+    let LidFE = fib_rg_w.read_i16::<LittleEndian>()?;
 
     let Clw = reader.read_u16::<LittleEndian>()?;
-    let cbMac = reader.read_i32::<LittleEndian>()?;
-    let lProductCreated = reader.read_i32::<LittleEndian>()?;
-    let lProductRevised = reader.read_i32::<LittleEndian>()?;
-    assert_eq!(reader.seek(SeekFrom::Current(0))?, 0x004C);
-    reader.seek(SeekFrom::Start(0x004C))?;
-    let ccpText = reader.read_i32::<LittleEndian>()?;
-    let ccpFtn = reader.read_i32::<LittleEndian>()?;
-    let ccpHdd = reader.read_i32::<LittleEndian>()?;
-    let ccpMcr = reader.read_i32::<LittleEndian>()?;
-    let ccpAtn = reader.read_i32::<LittleEndian>()?;
-    let ccpEdn = reader.read_i32::<LittleEndian>()?;
-    let ccpTxbx = reader.read_i32::<LittleEndian>()?;
-    let ccpHrdTxbx = reader.read_i32::<LittleEndian>()?;
-    let pnFbpChpFirst = reader.read_i32::<LittleEndian>()?;
-
-    let pnChpFirst = reader.read_i32::<LittleEndian>()?;
-    let cpnBteChp = reader.read_i32::<LittleEndian>()?;
-    let pnFbpPapFirst = reader.read_i32::<LittleEndian>()?;
-    let pnPapFirst = reader.read_i32::<LittleEndian>()?;
-    let cpnBtePap = reader.read_i32::<LittleEndian>()?;
-
-    let pnFbpLvcFirst = reader.read_i32::<LittleEndian>()?;
-    let pnLvcFirst = reader.read_i32::<LittleEndian>()?;
-    let cpnBteLvc = reader.read_i32::<LittleEndian>()?;
-    let fcIslandFirst = reader.read_i32::<LittleEndian>()?;
-    let fcIslandLim = reader.read_i32::<LittleEndian>()?;
+    let mut fib_rg_lw = read_block(reader, Clw as usize * 4)?;
+    let cbMac = fib_rg_lw.read_i32::<LittleEndian>()?;
+    let lProductCreated = fib_rg_lw.read_i32::<LittleEndian>()?;
+    let lProductRevised = fib_rg_lw.read_i32::<LittleEndian>()?;
+    let ccpText = fib_rg_lw.read_i32::<LittleEndian>()?;
+    let ccpFtn = fib_rg_lw.read_i32::<LittleEndian>()?;
+    let ccpHdd = fib_rg_lw.read_i32::<LittleEndian>()?;
+    let ccpMcr = fib_rg_lw.read_i32::<LittleEndian>()?;
+    let ccpAtn = fib_rg_lw.read_i32::<LittleEndian>()?;
+    let ccpEdn = fib_rg_lw.read_i32::<LittleEndian>()?;
+    let ccpTxbx = fib_rg_lw.read_i32::<LittleEndian>()?;
+    let ccpHrdTxbx = fib_rg_lw.read_i32::<LittleEndian>()?;
+    let pnFbpChpFirst = fib_rg_lw.read_i32::<LittleEndian>()?;
+
+    let pnChpFirst = fib_rg_lw.read_i32::<LittleEndian>()?;
+    let cpnBteChp = fib_rg_lw.read_i32::<LittleEndian>()?;
+    let pnFbpPapFirst = fib_rg_lw.read_i32::<LittleEndian>()?;
+    let pnPapFirst = fib_rg_lw.read_i32::<LittleEndian>()?;
+    let cpnBtePap = fib_rg_lw.read_i32::<LittleEndian>()?;
+
+    let pnFbpLvcFirst = fib_rg_lw.read_i32::<LittleEndian>()?;
+    let pnLvcFirst = fib_rg_lw.read_i32::<LittleEndian>()?;
+    let cpnBteLvc = fib_rg_lw.read_i32::<LittleEndian>()?;
+    let fcIslandFirst = fib_rg_lw.read_i32::<LittleEndian>()?;
+    let fcIslandLim = fib_rg_lw.read_i32::<LittleEndian>()?;
 
     // reading those pesky pairs now
     let Cfclcb = reader.read_u16::<LittleEndian>()?;
     let mut pairs: Vec<(i32, u32, String)> = Vec::with_capacity(Cfclcb as usize);
+    let mut fib_rg_fc_lcb = read_block(reader, Cfclcb as usize * 8)?;
 
-    assert_eq!(reader.seek(SeekFrom::Current(0))?, 0x009A);
-    let fcStshfOrig = reader.read_i32::<LittleEndian>()?;
-    let lcbStshfOrig = reader.read_u32::<LittleEndian>()?;
+    let fcStshfOrig = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbStshfOrig = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcStshfOrig, lcbStshfOrig, "Original STSH structure".into()));
 
-    let fcStshf = reader.read_i32::<LittleEndian>()?;
-    let lcbStshf = reader.read_u32::<LittleEndian>()?;
+    let fcStshf = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbStshf = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcStshf, lcbStshf, "STSH structure".into()));
 
-    let fcPlcffndRef = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcffndRef = reader.read_u32::<LittleEndian>()?;
+    let fcPlcffndRef = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcffndRef = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcffndRef,
         lcbPlcffndRef,
         "Footnote reference PLCF of FRD structures".into(),
     ));
 
-    let fcPlcffndTxt = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcffndTxt = reader.read_u32::<LittleEndian>()?;
+    let fcPlcffndTxt = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcffndTxt = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcPlcffndTxt, lcbPlcffndTxt, "Footnote text PLC".into()));
 
-    let fcPlcfandRef = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfandRef = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfandRef = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfandRef = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcfandRef,
         lcbPlcfandRef,
         "annotation reference ATRDPre10 PLC".into(),
     ));
 
-    let fcPlcfandTxt = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfandTxt = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfandTxt = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfandTxt = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcPlcfandTxt, lcbPlcfandTxt, "annotation text PLC".into()));
 
-    let fcPlcfsed = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfsed = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfsed = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfsed = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcPlcfsed, lcbPlcfsed, "section descriptor SED PLC".into()));
 
-    let fcPlcpad = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcpad = reader.read_u32::<LittleEndian>()?;
+    let fcPlcpad = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcpad = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcPlcpad, lcbPlcpad, "No longer used".into()));
 
-    let fcPlcfphe = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfphe = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfphe = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfphe = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcPlcfphe, lcbPlcfphe, "paragraph height PHE PLC".into()));
 
-    let fcSttbfglsy = reader.read_i32::<LittleEndian>()?;
-    let lcbSttbfglsy = reader.read_u32::<LittleEndian>()?;
+    let fcSttbfglsy = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbSttbfglsy = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcSttbfglsy,
         lcbSttbfglsy,
         "glossary string table[arr of c-strings]".into(),
     ));
 
-    let fcPlcfglsy = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfglsy = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfglsy = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfglsy = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcPlcfglsy, lcbPlcfglsy, "glossary PLC".into()));
 
-    let fcPlcfhdd = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfhdd = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfhdd = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfhdd = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcPlcfhdd, lcbPlcfhdd, "header HDD PLC".into()));
 
-    let fcPlcfbteChpx = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfbteChpx = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfbteChpx = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfbteChpx = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcfbteChpx,
         lcbPlcfbteChpx,
         "character property bin table PLC".into(),
     ));
 
-    assert_eq!(reader.seek(SeekFrom::Current(0))?, 0x0102);
-    let fcPlcfbtePapx = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfbtePapx = reader.read_u32::<LittleEndian>()?;
+    assert_eq!(fib_rg_fc_lcb.position(), 0x0102 - 0x009A);
+    let fcPlcfbtePapx = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfbtePapx = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcfbtePapx,
         lcbPlcfbtePapx,
         "paragraph property bin table PLC".into(),
     ));
 
-    let fcPlcfsea = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfsea = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfsea = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfsea = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcPlcfsea, lcbPlcfsea, "PLC reserved for private use".into()));
 
-    let fcsttbfffn = reader.read_i32::<LittleEndian>()?;
-    let lcbsttbfffn = reader.read_u32::<LittleEndian>()?;
+    let fcsttbfffn = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbsttbfffn = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcsttbfffn,
         lcbsttbfffn,
         "Font information STTBF(FFN)".into(),
     ));
 
-    let fcPlcffldMom = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcffldMom = reader.read_u32::<LittleEndian>()?;
+    let fcPlcffldMom = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcffldMom = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcffldMom,
         lcbPlcffldMom,
         "FLD PLC of field positions".into(),
     ));
 
-    let fcPlcffldHdr = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcffldHdr = reader.read_u32::<LittleEndian>()?;
+    let fcPlcffldHdr = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcffldHdr = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcffldHdr,
         lcbPlcffldHdr,
         "FLD PLC of field positions in header subdocument".into(),
     ));
 
-    let fcPlcffldFtn = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcffldFtn = reader.read_u32::<LittleEndian>()?;
+    let fcPlcffldFtn = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcffldFtn = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcffldFtn,
         lcbPlcffldFtn,
         "FLD PLC of field positions in the footnote subdocument".into(),
     ));
 
-    let fcPlcffldAtn = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcffldAtn = reader.read_u32::<LittleEndian>()?;
+    let fcPlcffldAtn = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcffldAtn = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcffldAtn,
         lcbPlcffldAtn,
         "FCD PLC of field positions in the annotation subdocument".into(),
     ));
 
-    let fcPlcffldMcr = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcffldMcr = reader.read_u32::<LittleEndian>()?;
+    let fcPlcffldMcr = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcffldMcr = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcPlcffldMcr, lcbPlcffldMcr, "No longer used".into()));
 
-    let fcSttbfbkmk = reader.read_i32::<LittleEndian>()?;
-    let lcbSttbfbkmk = reader.read_u32::<LittleEndian>()?;
+    let fcSttbfbkmk = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbSttbfbkmk = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcSttbfbkmk, lcbSttbfbkmk, "bookmark names STTBF".into()));
 
-    let fcPlcfbkf = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfbkf = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfbkf = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfbkf = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcfbkf,
         lcbPlcfbkf,
         "PLCF for CP offsets of bookmarks".into(),
     ));
 
-    let fcPlcfbkl = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfbkl = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfbkl = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfbkl = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcfbkl,
         lcbPlcfbkl,
         "PLCF for ending CP offsets of bookmarks".into(),
     ));
 
-    let fcCmds = reader.read_i32::<LittleEndian>()?;
-    let lcbCmds = reader.read_u32::<LittleEndian>()?;
+    let fcCmds = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbCmds = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcCmds, lcbCmds, "command macros".into()));
 
-    let fcPlcmcr = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcmcr = reader.read_u32::<LittleEndian>()?;
+    let fcPlcmcr = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcmcr = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcPlcmcr, lcbPlcmcr, "No longer used".into()));
 
-    let fcSttbfmcr = reader.read_i32::<LittleEndian>()?;
-    let lcbSttbfmcr = reader.read_u32::<LittleEndian>()?;
+    let fcSttbfmcr = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbSttbfmcr = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcSttbfmcr, lcbSttbfmcr, "No longer used".into()));
 
-    let fcPrDrvr = reader.read_i32::<LittleEndian>()?;
-    let lcbPrDrvr = reader.read_u32::<LittleEndian>()?;
+    let fcPrDrvr = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPrDrvr = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcPrDrvr, lcbPrDrvr, "Printer driver information".into()));
 
-    let fcPrEnvPort = reader.read_i32::<LittleEndian>()?;
-    let lcbPrEnvPort = reader.read_u32::<LittleEndian>()?;
+    let fcPrEnvPort = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPrEnvPort = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPrEnvPort,
         lcbPrEnvPort,
         "Print environment information(landscape)".into(),
     ));
-    let fcPrEnvLand = reader.read_i32::<LittleEndian>()?;
-    let lcbPrEnvLand = reader.read_u32::<LittleEndian>()?;
+    let fcPrEnvLand = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPrEnvLand = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPrEnvLand,
         lcbPrEnvLand,
         "Print environment information(portrait)".into(),
     ));
-    let fcWss = reader.read_i32::<LittleEndian>()?;
-    let lcbWss = reader.read_u32::<LittleEndian>()?;
+    let fcWss = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbWss = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcWss, lcbWss, "Window Save state data Structure".into()));
-    let fcDop = reader.read_i32::<LittleEndian>()?;
-    let lcbDop = reader.read_u32::<LittleEndian>()?;
+    let fcDop = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbDop = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcDop, lcbDop, "Document property data".into()));
-    let fcSttbfAssoc = reader.read_i32::<LittleEndian>()?;
-    let lcbSttbfAssoc = reader.read_u32::<LittleEndian>()?;
+    let fcSttbfAssoc = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbSttbfAssoc = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcSttbfAssoc,
         lcbSttbfAssoc,
         "associated strings STTBF".into(),
     ));
-    let fcClx = reader.read_i32::<LittleEndian>()?;
-    let lcbClx = reader.read_i32::<LittleEndian>()?;
+    let fcClx = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbClx = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
     pairs.push((fcClx, lcbClx as u32, "Complex File Information".into()));
-    let fcPlcfpgdFtn = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfpgdFtn = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfpgdFtn = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfpgdFtn = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcPlcfpgdFtn, lcbPlcfpgdFtn, "Not Used".into()));
-    let fcAutosaveSource = reader.read_i32::<LittleEndian>()?;
-    let lcbAutosaveSource = reader.read_u32::<LittleEndian>()?;
+    let fcAutosaveSource = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbAutosaveSource = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcAutosaveSource,
         lcbAutosaveSource,
         "original file name(for autosave)".into(),
     ));
-    let fcGrpXstAtnOwners = reader.read_i32::<LittleEndian>()?;
-    let lcbGrpXstAtnOwners = reader.read_u32::<LittleEndian>()?;
+    let fcGrpXstAtnOwners = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbGrpXstAtnOwners = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcGrpXstAtnOwners,
         lcbGrpXstAtnOwners,
         "group of extended strings for annotation owners".into(),
     ));
-    let fcSttbfAtnBkmk = reader.read_i32::<LittleEndian>()?;
-    let lcbSttbfAtnBkmk = reader.read_u32::<LittleEndian>()?;
+    let fcSttbfAtnBkmk = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbSttbfAtnBkmk = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcSttbfAtnBkmk,
         lcbSttbfAtnBkmk,
         "STTBF for bookmark names for annotation subdocument".into(),
     ));
-    let fcPlcdoaMom = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcdoaMom = reader.read_u32::<LittleEndian>()?;
+    let fcPlcdoaMom = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcdoaMom = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcPlcdoaMom, lcbPlcdoaMom, "No longer used".into()));
-    let fcPlcdoaHdr = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcdoaHdr = reader.read_u32::<LittleEndian>()?;
+    let fcPlcdoaHdr = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcdoaHdr = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcPlcdoaHdr, lcbPlcdoaHdr, "No longer used".into()));
-    let fcPlcspaMom = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcspaMom = reader.read_u32::<LittleEndian>()?;
+    let fcPlcspaMom = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcspaMom = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcspaMom,
         lcbPlcspaMom,
         "FPSA PLC for main document (drawing objects)".into(),
     ));
-    let fcPlcspaHdr = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcspaHdr = reader.read_u32::<LittleEndian>()?;
+    let fcPlcspaHdr = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcspaHdr = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcspaHdr,
         lcbPlcspaHdr,
         "FPSA PLC for header subdocument (drawing objects)".into(),
     ));
-    let fcPlcfAtnbkf = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfAtnbkf = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfAtnbkf = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfAtnbkf = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcfAtnbkf,
         lcbPlcfAtnbkf,
         "BKF PLC for annotation subdocument".into(),
     ));
-    let fcPlcfAtnbkl = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfAtnbkl = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfAtnbkl = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfAtnbkl = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcfAtnbkl,
         lcbPlcfAtnbkl,
         "BKL PLC for annotation subdocument".into(),
     ));
-    let fcPms = reader.read_i32::<LittleEndian>()?;
-    let lcbPms = reader.read_u32::<LittleEndian>()?;
+    let fcPms = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPms = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPms,
         lcbPms,
         "PMS(Print merge state) information block".into(),
     ));
-    let fcFormFldSttbs = reader.read_i32::<LittleEndian>()?;
-    let lcbFormFldSttbs = reader.read_u32::<LittleEndian>()?;
+    let fcFormFldSttbs = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbFormFldSttbs = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcFormFldSttbs, lcbFormFldSttbs, "Form Field STTBF".into()));
-    let fcPlcfendRef = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfendRef = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfendRef = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfendRef = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcPlcfendRef, lcbPlcfendRef, "PLCF FRD structures".into()));
-    let fcPlcfendTxt = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfendTxt = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfendTxt = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfendTxt = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcfendTxt,
         lcbPlcfendTxt,
         "offset of endnote text of endnote subdocument".into(),
     ));
-    let fcPlcffldEdn = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcffldEdn = reader.read_u32::<LittleEndian>()?;
+    let fcPlcffldEdn = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcffldEdn = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcffldEdn,
         lcbPlcffldEdn,
         "FLD PLC of field positions in endnote subdocument".into(),
     ));
-    let fcPlcfpgdEdn = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfpgdEdn = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfpgdEdn = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfpgdEdn = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcPlcfpgdEdn, lcbPlcfpgdEdn, "Not Used".into()));
-    let fcDggInfo = reader.read_i32::<LittleEndian>()?;
-    let lcbDggInfo = reader.read_u32::<LittleEndian>()?;
+    let fcDggInfo = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbDggInfo = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcDggInfo,
         lcbDggInfo,
         "Office Drawing object table data".into(),
     ));
-    let fcSttbfRMark = reader.read_i32::<LittleEndian>()?;
-    let lcbSttbfRMark = reader.read_u32::<LittleEndian>()?;
+    let fcSttbfRMark = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbSttbfRMark = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcSttbfRMark,
         lcbSttbfRMark,
         "STTBF for revision author abbreviations".into(),
     ));
-    let fcSttbfCaption = reader.read_i32::<LittleEndian>()?;
-    let lcbSttbfCaption = reader.read_u32::<LittleEndian>()?;
+    let fcSttbfCaption = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbSttbfCaption = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcSttbfCaption,
         lcbSttbfCaption,
         "STTBF for caption names".into(),
     ));
-    let fcSttbfAutoCaption = reader.read_i32::<LittleEndian>()?;
-    let lcbSttbfAutoCaption = reader.read_u32::<LittleEndian>()?;
+    let fcSttbfAutoCaption = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbSttbfAutoCaption = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcSttbfAutoCaption,
         lcbSttbfAutoCaption,
         "STTBF for auto caption names".into(),
     ));
-    let fcPlcfWkb = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfWkb = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfWkb = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfWkb = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcPlcfWkb, lcbPlcfWkb, "PLCF WKP".into()));
-    let fcPlcfSpl = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfSpl = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfSpl = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfSpl = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcfSpl,
         lcbPlcfSpl,
         "PLCF SLPS (spell check state sttructure)".into(),
     ));
-    let fcPlcftxbxTxt = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcftxbxTxt = reader.read_u32::<LittleEndian>()?;
+    let fcPlcftxbxTxt = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcftxbxTxt = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcftxbxTxt,
         lcbPlcftxbxTxt,
         "PLCF for textbox text".into(),
     ));
-    let fcPlcffldTxbx = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcffldTxbx = reader.read_u32::<LittleEndian>()?;
+    let fcPlcffldTxbx = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcffldTxbx = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcffldTxbx,
         lcbPlcffldTxbx,
         "PLCF for textbox text for textbox subdocument".into(),
     ));
-    let fcPlcfhdrtxbxTxt = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfhdrtxbxTxt = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfhdrtxbxTxt = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfhdrtxbxTxt = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcfhdrtxbxTxt,
         lcbPlcfhdrtxbxTxt,
         "PLCF for textbox text for header textbox subdocument".into(),
     ));
-    let fcPlcffldHdrTxbx = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcffldHdrTxbx = reader.read_u32::<LittleEndian>()?;
+    let fcPlcffldHdrTxbx = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcffldHdrTxbx = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcffldHdrTxbx,
         lcbPlcffldHdrTxbx,
         "FLD PLCF for textbox text for header textbox subdocument".into(),
     ));
-    let fcStwUser = reader.read_i32::<LittleEndian>()?;
-    let lcbStwUser = reader.read_u32::<LittleEndian>()?;
+    let fcStwUser = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbStwUser = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcStwUser, lcbStwUser, "Macro user storage".into()));
-    let fcSttbTtmbd = reader.read_i32::<LittleEndian>()?;
-    let lcbSttbTtmbd = reader.read_u32::<LittleEndian>()?;
+    let fcSttbTtmbd = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbSttbTtmbd = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcSttbTtmbd,
         lcbSttbTtmbd,
         "emebedded true type font data".into(),
     ));
-    let fcCookieData = reader.read_i32::<LittleEndian>()?;
-    let lcbCookieData = reader.read_u32::<LittleEndian>()?;
+    let fcCookieData = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbCookieData = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcCookieData, lcbCookieData, "NLCheck error handle".into()));
 
     let mut fcpgdold_buff: [u8; 16] = [0; 16];
-    reader.read_exact(&mut fcpgdold_buff)?;
+    fib_rg_fc_lcb.read_exact(&mut fcpgdold_buff)?;
     let fcPgdMotherOldOld = FCPGDOLD::from_bytes(&fcpgdold_buff);
-    reader.read_exact(&mut fcpgdold_buff)?;
+    fib_rg_fc_lcb.read_exact(&mut fcpgdold_buff)?;
     let fcpgdFtnOldOld = FCPGDOLD::from_bytes(&fcpgdold_buff);
-    reader.read_exact(&mut fcpgdold_buff)?;
+    fib_rg_fc_lcb.read_exact(&mut fcpgdold_buff)?;
     let fcpgdEdnOldOld = FCPGDOLD::from_bytes(&fcpgdold_buff);
 
     pairs.push((
@@ -519,459 +522,163 @@ pub fn fib_from_read_impl<R: Read + Seek>(
         "PLF of break descriptors for endnote text".into(),
     ));
 
-    let fcSttbfIntlFld = reader.read_i32::<LittleEndian>()?;
-    let lcbSttbfIntlFld = reader.read_u32::<LittleEndian>()?;
+    let fcSttbfIntlFld = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbSttbfIntlFld = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcSttbfIntlFld,
         lcbSttbfIntlFld,
         "STTBF of field keywords".into(),
     ));
-    let fcRouteSlip = reader.read_i32::<LittleEndian>()?;
-    let lcbRouteSlip = reader.read_u32::<LittleEndian>()?;
+    let fcRouteSlip = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbRouteSlip = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcRouteSlip, lcbRouteSlip, "a mailer routing slip".into()));
-    let fcSttbSavedBy = reader.read_i32::<LittleEndian>()?;
-    let lcbSttbSavedBy = reader.read_u32::<LittleEndian>()?;
+    let fcSttbSavedBy = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbSttbSavedBy = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcSttbSavedBy,
         lcbSttbSavedBy,
         "STTBF of document savers; (user, location) pairs".into(),
     ));
-    let fcSttbFnm = reader.read_i32::<LittleEndian>()?;
-    let lcbSttbFnm = reader.read_u32::<LittleEndian>()?;
+    let fcSttbFnm = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbSttbFnm = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcSttbFnm,
         lcbSttbFnm,
         "STTBF of file names refered to by doc".into(),
     ));
-    let fcPlcfLst = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfLst = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfLst = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfLst = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcfLst,
         lcbPlcfLst,
         "list format intformation table".into(),
     ));
-    let fcPlfLfo = reader.read_i32::<LittleEndian>()?;
-    let lcbPlfLfo = reader.read_u32::<LittleEndian>()?;
+    let fcPlfLfo = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlfLfo = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcPlfLfo, lcbPlfLfo, "list format override table".into()));
-    let fcPlcftxbxBkd = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcftxbxBkd = reader.read_u32::<LittleEndian>()?;
+    let fcPlcftxbxBkd = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcftxbxBkd = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcftxbxBkd,
         lcbPlcftxbxBkd,
         "PLCF BKD of text box descriptors".into(),
     ));
-    let fcPlcftxbxHdrBkd = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcftxbxHdrBkd = reader.read_u32::<LittleEndian>()?;
+    let fcPlcftxbxHdrBkd = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcftxbxHdrBkd = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcftxbxHdrBkd,
         lcbPlcftxbxHdrBkd,
         "PLCF BKD of text box descriptors in header".into(),
     ));
-    let fcDocUndoWord9 = reader.read_i32::<LittleEndian>()?;
-    let lcbDocUndoWord9 = reader.read_u32::<LittleEndian>()?;
+    let fcDocUndoWord9 = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbDocUndoWord9 = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcDocUndoWord9,
         lcbDocUndoWord9,
         "undo / versioning data pre word10".into(),
     ));
-    let fcRgbUse = reader.read_i32::<LittleEndian>()?;
-    let lcbRgbUse = reader.read_u32::<LittleEndian>()?;
+    let fcRgbUse = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbRgbUse = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcRgbUse, lcbRgbUse, "undo / versioning data".into()));
-    let fcUsp = reader.read_i32::<LittleEndian>()?;
-    let lcbUsp = reader.read_u32::<LittleEndian>()?;
+    let fcUsp = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbUsp = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcUsp, lcbUsp, "undo / versioning data".into()));
-    let fcUskf = reader.read_i32::<LittleEndian>()?;
-    let lcbUskf = reader.read_u32::<LittleEndian>()?;
+    let fcUskf = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbUskf = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcUskf, lcbUskf, "undo / versioning data".into()));
-    let fcPlcupcRgbUse = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcupcRgbUse = reader.read_u32::<LittleEndian>()?;
+    let fcPlcupcRgbUse = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcupcRgbUse = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcupcRgbUse,
         lcbPlcupcRgbUse,
         "undo / versioning data".into(),
     ));
-    let fcPlcupcUsp = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcupcUsp = reader.read_u32::<LittleEndian>()?;
+    let fcPlcupcUsp = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcupcUsp = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcPlcupcUsp, lcbPlcupcUsp, "undo / versioning data".into()));
-    let fcSttbGlsyStyle = reader.read_i32::<LittleEndian>()?;
-    let lcbSttbGlsyStyle = reader.read_u32::<LittleEndian>()?;
+    let fcSttbGlsyStyle = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbSttbGlsyStyle = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcSttbGlsyStyle,
         lcbSttbGlsyStyle,
         "STTBF of style names for glossary entries".into(),
     ));
-    let fcPlgosl = reader.read_i32::<LittleEndian>()?;
-    let lcbPlgosl = reader.read_u32::<LittleEndian>()?;
+    let fcPlgosl = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlgosl = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcPlgosl, lcbPlgosl, "grammar option PL".into()));
-    let fcPlcocx = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcocx = reader.read_u32::<LittleEndian>()?;
+    let fcPlcocx = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcocx = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcPlcocx, lcbPlcocx, "ocx data".into()));
-    let fcPlcfBteLvc = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfBteLvc = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfBteLvc = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfBteLvc = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcfBteLvc,
         lcbPlcfBteLvc,
         "character property bin table PLC".into(),
     ));
-    let dwLowDateTime = reader.read_u32::<LittleEndian>()?;
-    let dwHighDateTime = reader.read_u32::<LittleEndian>()?;
-    let fcPlcfLvcPre10 = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfLvcPre10 = reader.read_u32::<LittleEndian>()?;
+    let dwLowDateTime = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
+    let dwHighDateTime = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
+    let fcPlcfLvcPre10 = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfLvcPre10 = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcfLvcPre10,
         lcbPlcfLvcPre10,
         "PLCF for LVC pre Word 10".into(),
     ));
-    let fcPlcfAsumy = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfAsumy = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfAsumy = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfAsumy = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcPlcfAsumy, lcbPlcfAsumy, "autosummary ASUMY PLCF".into()));
-    let fcPlcfGram = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfGram = reader.read_u32::<LittleEndian>()?;
+    let fcPlcfGram = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbPlcfGram = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcPlcfGram,
         lcbPlcfGram,
         "PLCF SPLS for grammar check state".into(),
     ));
-    let fcSttbListNames = reader.read_i32::<LittleEndian>()?;
-    let lcbSttbListNames = reader.read_u32::<LittleEndian>()?;
+    let fcSttbListNames = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbSttbListNames = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((
         fcSttbListNames,
         lcbSttbListNames,
         "list names string table".into(),
     ));
-    let fcSttbfUssr = reader.read_i32::<LittleEndian>()?;
-    let lcbSttbfUssr = reader.read_u32::<LittleEndian>()?;
+    let fcSttbfUssr = fib_rg_fc_lcb.read_i32::<LittleEndian>()?;
+    let lcbSttbfUssr = fib_rg_fc_lcb.read_u32::<LittleEndian>()?;
     pairs.push((fcSttbfUssr, lcbSttbfUssr, "undo / versioning data".into()));
-    let fcPlcfTch = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfTch = reader.read_u32::<LittleEndian>()?;
-    pairs.push((fcPlcfTch, lcbPlcfTch, "internal cache".into()));
-    let fcRmdfThreading = reader.read_i32::<LittleEndian>()?;
-    let lcbRmdfThreading = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcRmdfThreading,
-        lcbRmdfThreading,
-        "revision mark data(unused)".into(),
-    ));
-    let fcMid = reader.read_i32::<LittleEndian>()?;
-    let lcbMid = reader.read_u32::<LittleEndian>()?;
-    pairs.push((fcMid, lcbMid, "Message ID".into()));
-    let fcSttbRgtplc = reader.read_i32::<LittleEndian>()?;
-    let lcbSttbRgtplc = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcSttbRgtplc,
-        lcbSttbRgtplc,
-        "list gallery data (tplcs)".into(),
-    ));
-    let fcMsoEnvelope = reader.read_i32::<LittleEndian>()?;
-    let lcbMsoEnvelope = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcMsoEnvelope,
-        lcbMsoEnvelope,
-        "email header information".into(),
-    ));
-    let fcPlcfLad = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfLad = reader.read_u32::<LittleEndian>()?;
-    pairs.push((fcPlcfLad, lcbPlcfLad, "language autodetect results".into()));
-    let fcRgDofr = reader.read_i32::<LittleEndian>()?;
-    let lcbRgDofr = reader.read_u32::<LittleEndian>()?;
-    pairs.push((fcRgDofr, lcbRgDofr, "miscellaneous document data".into()));
-    let fcPlcosl = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcosl = reader.read_u32::<LittleEndian>()?;
-    pairs.push((fcPlcosl, lcbPlcosl, "NLCheck grammar option state".into()));
-    let fcPlcfCookieOld = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfCookieOld = reader.read_u32::<LittleEndian>()?;
+    assert_eq!(fib_rg_fc_lcb.position(), CB_RG_FC_LCB_97 as u64 * 8);
+
+    let fibRgFcLcb2000 = if Cfclcb >= CB_RG_FC_LCB_2000 {
+        let (block, block_pairs) = fib_rg_fc_lcb_2000_from_read_impl(&mut fib_rg_fc_lcb)?;
+        pairs.extend(block_pairs);
+        Some(block)
+    } else {
+        None
+    };
+    let fibRgFcLcb2002 = if Cfclcb >= CB_RG_FC_LCB_2002 {
+        let (block, block_pairs) = fib_rg_fc_lcb_2002_from_read_impl(&mut fib_rg_fc_lcb)?;
+        pairs.extend(block_pairs);
+        Some(block)
+    } else {
+        None
+    };
+    let fibRgFcLcb2003 = if Cfclcb >= CB_RG_FC_LCB_2003 {
+        let (block, block_pairs) = fib_rg_fc_lcb_2003_from_read_impl(&mut fib_rg_fc_lcb)?;
+        pairs.extend(block_pairs);
+        Some(block)
+    } else {
+        None
+    };
+    let fibRgFcLcb2007 = if Cfclcb >= CB_RG_FC_LCB_2007 {
+        let (block, block_pairs) = fib_rg_fc_lcb_2007_from_read_impl(&mut fib_rg_fc_lcb)?;
+        pairs.extend(block_pairs);
+        Some(block)
+    } else {
+        None
+    };
 
-    let mut fcpgdold_buff: [u8; 16] = [0; 16];
-    reader.read_exact(&mut fcpgdold_buff)?;
-    let fcPgdMotherOld = FCPGDOLD::from_bytes(&fcpgdold_buff);
-    reader.read_exact(&mut fcpgdold_buff)?;
-    let fcpgdFtnOld = FCPGDOLD::from_bytes(&fcpgdold_buff);
-    reader.read_exact(&mut fcpgdold_buff)?;
-    let fcpgdEdnOld = FCPGDOLD::from_bytes(&fcpgdold_buff);
-
-    let fcUnused1 = reader.read_i32::<LittleEndian>()?;
-    let lcbUnused1 = reader.read_u32::<LittleEndian>()?;
-    pairs.push((fcUnused1, lcbUnused1, "Unused".into()));
-    let fcPlcfPgp = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfPgp = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPlcfPgp,
-        lcbPlcfPgp,
-        "paragraph group properties(HTML DIV)".into(),
-    ));
-    let fcPlcfuim = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfuim = reader.read_u32::<LittleEndian>()?;
-    pairs.push((fcPlcfuim, lcbPlcfuim, "UIM property data".into()));
-    let fcPlfguidUim = reader.read_i32::<LittleEndian>()?;
-    let lcbPlfguidUim = reader.read_u32::<LittleEndian>()?;
-    pairs.push((fcPlfguidUim, lcbPlfguidUim, "UIM table of GUIDs".into()));
-    let fcAtrdExtra = reader.read_i32::<LittleEndian>()?;
-    let lcbAtrdExtra = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcAtrdExtra,
-        lcbAtrdExtra,
-        "plex of ATRDPost10 structures".into(),
-    ));
-    let fcPlrsid = reader.read_i32::<LittleEndian>()?;
-    let lcbPlrsid = reader.read_u32::<LittleEndian>()?;
-    pairs.push((fcPlrsid, lcbPlrsid, "PLCF of RSID structures".into()));
-    let fcSttbfBkmkFactoid = reader.read_i32::<LittleEndian>()?;
-    let lcbSttbfBkmkFactoid = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcSttbfBkmkFactoid,
-        lcbSttbfBkmkFactoid,
-        "Smart tag bookmark STTB".into(),
-    ));
-    let fcPlcfBkfFactoid = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfBkfFactoid = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPlcfBkfFactoid,
-        lcbPlcfBkfFactoid,
-        "smart tak bookmark plc of cpFirsts".into(),
-    ));
-    let fcPlcfcookie = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfcookie = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPlcfcookie,
-        lcbPlcfcookie,
-        "internal data for grammar features".into(),
-    ));
-    let fcPlcfBklFactoid = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfBklFactoid = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPlcfBklFactoid,
-        lcbPlcfBklFactoid,
-        "smart tag bookmark plc of cpLims".into(),
-    ));
-    let fcFactoidData = reader.read_i32::<LittleEndian>()?;
-    let lcbFactoidData = reader.read_u32::<LittleEndian>()?;
-    pairs.push((fcFactoidData, lcbFactoidData, "smart tag data".into()));
-    let fcDocUndo = reader.read_i32::<LittleEndian>()?;
-    let lcbDocUndo = reader.read_u32::<LittleEndian>()?;
-    pairs.push((fcDocUndo, lcbDocUndo, "undo / versioning data".into()));
-    let fcSttbfBkmkFcc = reader.read_i32::<LittleEndian>()?;
-    let lcbSttbfBkmkFcc = reader.read_u32::<LittleEndian>()?;
-    pairs.push((fcSttbfBkmkFcc, lcbSttbfBkmkFcc, "FCC bookmark STTB".into()));
-    let fcPlcfBkfFcc = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfBkfFcc = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPlcfBkfFcc,
-        lcbPlcfBkfFcc,
-        "fcc bookmark plc of cpLims".into(),
-    ));
-    let fcPlcfBklFcc = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfBklFcc = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPlcfBklFcc,
-        lcbPlcfBklFcc,
-        "fcc bookmark plc of cpLims".into(),
-    ));
-    let fcSttbfbkmkBPRepairs = reader.read_i32::<LittleEndian>()?;
-    let lcbSttbfbkmkBPRepairs = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcSttbfbkmkBPRepairs,
-        lcbSttbfbkmkBPRepairs,
-        "plc of cpFirsts for file repair feature".into(),
-    ));
-    let fcPlcfbkfBPRepairs = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfbkfBPRepairs = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPlcfbkfBPRepairs,
-        lcbPlcfbkfBPRepairs,
-        "plc of cpLims for file repair feature".into(),
-    ));
-    let fcPmsNew = reader.read_i32::<LittleEndian>()?;
-    let lcbPmsNew = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPmsNew,
-        lcbPmsNew,
-        "PMS satate for new merge state information".into(),
-    ));
-    let fcODSO = reader.read_i32::<LittleEndian>()?;
-    let lcbODSO = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcODSO,
-        lcbODSO,
-        "IMsoODSO / IMsoMailmerge Information".into(),
-    ));
-    let fcPlcfpmiOldXP = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfpmiOldXP = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPlcfpmiOldXP,
-        lcbPlcfpmiOldXP,
-        "Paragraph Mark Information(Old View)".into(),
-    ));
-    let fcPlcfpmiNewXP = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfpmiNewXP = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPlcfpmiNewXP,
-        lcbPlcfpmiNewXP,
-        "Paragraph Mark Information(New View)".into(),
-    ));
-    let fcPlcfpmiMixedXP = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfpmiMixedXP = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPlcfpmiMixedXP,
-        lcbPlcfpmiMixedXP,
-        "Paragraph Mark Information(Mixed View)".into(),
-    ));
-    let fcEncryptedProps = reader.read_i32::<LittleEndian>()?;
-    let lcbEncryptedProps = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcEncryptedProps,
-        lcbEncryptedProps,
-        "Internal Encrypted Document Properties".into(),
-    ));
-    let fcPlcffactoid = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcffactoid = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPlcffactoid,
-        lcbPlcffactoid,
-        "background factoid checking state".into(),
-    ));
-    let fcPlcflvcOldXP = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcflvcOldXP = reader.read_u32::<LittleEndian>()?;
-    pairs.push((fcPlcflvcOldXP, lcbPlcflvcOldXP, "LVS PLC(Old View)".into()));
-    let fcPlcflvcNewXP = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcflvcNewXP = reader.read_u32::<LittleEndian>()?;
-    pairs.push((fcPlcflvcNewXP, lcbPlcflvcNewXP, "LVS PLC(New View)".into()));
-    let fcPlcflvcMixedXP = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcflvcMixedXP = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPlcflvcMixedXP,
-        lcbPlcflvcMixedXP,
-        "LVS PLC(Mixed View)".into(),
-    ));
-    let fcHplxsdr = reader.read_i32::<LittleEndian>()?;
-    let lcbHplxsdr = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcHplxsdr,
-        lcbHplxsdr,
-        "XML Schema definition References".into(),
-    ));
-    let fcSttbfBkmkSdt = reader.read_i32::<LittleEndian>()?;
-    let lcbSttbfBkmkSdt = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcSttbfBkmkSdt,
-        lcbSttbfBkmkSdt,
-        "XML bookmark information".into(),
-    ));
-    let fcPlcfBkfSdt = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfBkfSdt = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPlcfBkfSdt,
-        lcbPlcfBkfSdt,
-        "SDT bookmark plc of cpFirsts".into(),
-    ));
-    let fcPlcBlkSdt = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcBlkSdt = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPlcBlkSdt,
-        lcbPlcBlkSdt,
-        "SDT bookmark plc of cpLims".into(),
-    ));
-    let fcCustomXForm = reader.read_i32::<LittleEndian>()?;
-    let lcbCustomXForm = reader.read_u32::<LittleEndian>()?;
-    pairs.push((fcCustomXForm, lcbCustomXForm, "Custom XML Transform".into()));
-    let fcSttbfBkmkProt = reader.read_i32::<LittleEndian>()?;
-    let lcbSttbfBkmkProt = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcSttbfBkmkProt,
-        lcbSttbfBkmkProt,
-        "Range Protection Bookmark STTB".into(),
-    ));
-    let fcPlcfBkfProt = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfBkfProt = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPlcfBkfProt,
-        lcbPlcfBkfProt,
-        "Range Protection bookmark plc of cpFirsts".into(),
-    ));
-    let fcPlcfBklProt = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfBklProt = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPlcfBklProt,
-        lcbPlcfBklProt,
-        "Range Protection bookmark plc of cpLims".into(),
-    ));
-    let fcSttbProtUser = reader.read_i32::<LittleEndian>()?;
-    let lcbSttbProtUser = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcSttbProtUser,
-        lcbSttbProtUser,
-        "Range Protection User List STTB".into(),
-    ));
-    let fcPlcftpc = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcftpc = reader.read_u32::<LittleEndian>()?;
-    pairs.push((fcPlcftpc, lcbPlcftpc, "current text paragraph cache".into()));
-    let fcPlcfpmiOld = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfpmiOld = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPlcfpmiOld,
-        lcbPlcfpmiOld,
-        "Paragraph Mark Information(Old View)".into(),
-    ));
-    let fcPlcfpmiOldInline = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfpmiOldInline = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPlcfpmiOldInline,
-        lcbPlcfpmiOldInline,
-        "Paragraph Mark Information(Old Inline View)".into(),
-    ));
-    let fcPlcfpmiNew = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfpmiNew = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPlcfpmiNew,
-        lcbPlcfpmiNew,
-        "Paragraph Mark Information(New View)".into(),
-    ));
-    let fcPlcfpmiNewInline = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfpmiNewInline = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPlcfpmiNewInline,
-        lcbPlcfpmiNewInline,
-        "Paragraph Mark Information(New Inline View)".into(),
-    ));
-    let fcPlcfvcOld = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfvcOld = reader.read_u32::<LittleEndian>()?;
-    pairs.push((fcPlcfvcOld, lcbPlcfvcOld, "LVC PLC(Old View)".into()));
-    let fcPlcfvcOldInline = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcfvcOldInline = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPlcfvcOldInline,
-        lcbPlcfvcOldInline,
-        "LVC PLC(Old Inline View)".into(),
-    ));
-    let fcPlcflvcNew = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcflvcNew = reader.read_u32::<LittleEndian>()?;
-    pairs.push((fcPlcflvcNew, lcbPlcflvcNew, "LVC PLC(New View)".into()));
-    let fcPlcflvcNewInline = reader.read_i32::<LittleEndian>()?;
-    let lcbPlcflvcNewInline = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcPlcflvcNewInline,
-        lcbPlcflvcNewInline,
-        "LVC PLC(New Inline View)".into(),
-    ));
-
-    let mut fcpgdold_buff: [u8; 16] = [0; 16];
-    reader.read_exact(&mut fcpgdold_buff)?;
-    let fcpgdMother = FCPGDOLD::from_bytes(&fcpgdold_buff);
-    reader.read_exact(&mut fcpgdold_buff)?;
-    let fcpgdFtn = FCPGDOLD::from_bytes(&fcpgdold_buff);
-    reader.read_exact(&mut fcpgdold_buff)?;
-    let fcpgdEdn = FCPGDOLD::from_bytes(&fcpgdold_buff);
-
-    let fcAfd = reader.read_i32::<LittleEndian>()?;
-    let lcbAfd = reader.read_u32::<LittleEndian>()?;
-    pairs.push((
-        fcAfd,
-        lcbAfd,
-        "Internal revision mark view information".into(),
-    ));
-
-    // reader.seek(SeekFrom::Start(0x0102))?; // Skip to get to fcPlcfbtePapx
-    // let fcPlcfbtePapx = reader.read_i32::<LittleEndian>()?;
-    // let lcbPlcfbtePapx = reader.read_u32::<LittleEndian>()?;
+    // reader.seek(SeekFrom::Start(0x0102))?; // Skip to get to fcPlcfbtePapx
+    // let fcPlcfbtePapx = reader.read_i32::<LittleEndian>()?;
+    // let lcbPlcfbtePapx = reader.read_u32::<LittleEndian>()?;
 
     // reader.seek(SeekFrom::Start(0x00A2))?;
     // let fcStshf = reader.read_i32::<LittleEndian>()?;
@@ -988,8 +695,12 @@ pub fn fib_from_read_impl<R: Read + Seek>(
     // let lcbPlfLfo = reader.read_u32::<LittleEndian>()?;
 
     let cswNew = reader.read_u16::<LittleEndian>()?;
-    let actualNFib = reader.read_u16::<LittleEndian>()?;
-    let cQuickSavesNew = reader.read_u16::<LittleEndian>()?;
+    let mut fib_rg_csw_new = read_block(reader, cswNew as usize * 2)?;
+    let nFibNew = read_optional_u16(&mut fib_rg_csw_new)?;
+    let cQuickSavesNew = read_optional_u16(&mut fib_rg_csw_new)?;
+    let lidThemeOther = read_optional_u16(&mut fib_rg_csw_new)?;
+    let lidThemeFE = read_optional_u16(&mut fib_rg_csw_new)?;
+    let lidThemeCS = read_optional_u16(&mut fib_rg_csw_new)?;
 
     Ok((
         Fib {
@@ -1240,61 +951,990 @@ pub fn fib_from_read_impl<R: Read + Seek>(
             lcbSttbListNames,
             fcSttbfUssr,
             lcbSttbfUssr,
-            fcPlcfTch,
-            lcbPlcfTch,
-            fcRmdfThreading,
-            lcbRmdfThreading,
-            fcMid,
-            lcbMid,
-            fcSttbRgtplc,
-            lcbSttbRgtplc,
-            fcMsoEnvelope,
-            lcbMsoEnvelope,
-            fcPlcfLad,
-            lcbPlcfLad,
-            fcRgDofr,
-            lcbRgDofr,
-            fcPlcosl,
-            lcbPlcosl,
-            fcPlcfCookieOld,
-            lcbPlcfCookieOld,
-            fcPgdMotherOld,
-            fcpgdFtnOld,
-            fcpgdEdnOld,
-            fcUnused1,
-            lcbUnused1,
-            fcPlcfPgp,
-            lcbPlcfPgp,
-            fcPlcfuim,
-            lcbPlcfuim,
-            fcPlfguidUim,
-            lcbPlfguidUim,
-            fcAtrdExtra,
-            lcbAtrdExtra,
-            fcPlrsid,
-            lcbPlrsid,
-            fcSttbfBkmkFactoid,
-            lcbSttbfBkmkFactoid,
-            fcPlcfBkfFactoid,
-            lcbPlcfBkfFactoid,
-            fcPlcfcookie,
-            lcbPlcfcookie,
-            fcPlcfBklFactoid,
-            lcbPlcfBklFactoid,
-            fcFactoidData,
-            lcbFactoidData,
-            fcDocUndo,
-            lcbDocUndo,
-            fcSttbfBkmkFcc,
-            lcbSttbfBkmkFcc,
-            fcPlcfBkfFcc,
-            lcbPlcfBkfFcc,
+            fibRgFcLcb2000,
+            fibRgFcLcb2002,
+            fibRgFcLcb2003,
+            fibRgFcLcb2007,
+            cswNew,
+            nFibNew,
+            cQuickSavesNew,
+            lidThemeOther,
+            lidThemeFE,
+            lidThemeCS,
+        },
+        pairs,
+    ))
+}
+
+/// Reads the FIB of a Word 6.0 or Word 95 file, see `NFIB_WORD_6`
+#[allow(non_snake_case)]
+pub fn fib6_from_read_impl<R: Read + Seek>(reader: &mut R) -> io::Result<(Fib6, FcLcbPairs)> {
+    reader.seek(SeekFrom::Start(0))?;
+
+    let wIdent = Bytes::from_u16(reader.read_u16::<LittleEndian>()?);
+    let nFib = reader.read_u16::<LittleEndian>()?;
+    let nProduct = reader.read_u16::<LittleEndian>()?;
+    let Lid = reader.read_u16::<LittleEndian>()?;
+    let pnNext = reader.read_i16::<LittleEndian>()?;
+
+    let bitfield = reader.read_u16::<LittleEndian>()?;
+    let fDot = (bitfield & 0x0001) == 0x0001;
+    let fGlsy = (bitfield & 0x0002) == 0x0002;
+    let fComplex = (bitfield & 0x0004) == 0x0004;
+    let fHasPic = (bitfield & 0x0008) == 0x0008;
+    let cQuickSaves = ((bitfield & 0x00F0) >> 4) as u8;
+    let fEncrypted = (bitfield & 0x0100) == 0x0100;
+    let fReserved = (bitfield & 0x0200) == 0x0200;
+    let fReadOnlyRecommended = (bitfield & 0x0400) == 0x0400;
+    let fWriteReservation = (bitfield & 0x0800) == 0x0800;
+    let fExtChar = (bitfield & 0x1000) == 0x1000;
+
+    let nFibBack = reader.read_u16::<LittleEndian>()?;
+    let lKey = Bytes::from_u32(reader.read_u32::<LittleEndian>()?);
+    let Envr = reader.read_u8()?;
+    let fMac = (reader.read_u8()? & 0x01) == 0x01;
+    let Chs = reader.read_u16::<LittleEndian>()?;
+    let chsTables = reader.read_u16::<LittleEndian>()?;
+    let fcMin = reader.read_i32::<LittleEndian>()?;
+    let fcMac = reader.read_i32::<LittleEndian>()?;
+
+    let cbMac = reader.read_i32::<LittleEndian>()?;
+    let fcSpare0 = reader.read_i32::<LittleEndian>()?;
+    let fcSpare1 = reader.read_i32::<LittleEndian>()?;
+    let fcSpare2 = reader.read_i32::<LittleEndian>()?;
+    let fcSpare3 = reader.read_i32::<LittleEndian>()?;
+    let ccpText = reader.read_i32::<LittleEndian>()?;
+    let ccpFtn = reader.read_i32::<LittleEndian>()?;
+    let ccpHdr = reader.read_i32::<LittleEndian>()?;
+    let ccpMcr = reader.read_i32::<LittleEndian>()?;
+    let ccpAtn = reader.read_i32::<LittleEndian>()?;
+    let ccpEdn = reader.read_i32::<LittleEndian>()?;
+    let ccpTxbx = reader.read_i32::<LittleEndian>()?;
+    let ccpHrdTxbx = reader.read_i32::<LittleEndian>()?;
+    let ccpSpare2 = reader.read_i32::<LittleEndian>()?;
+
+    let mut pairs: FcLcbPairs = Vec::with_capacity(38 + 35);
+    assert_eq!(reader.stream_position()?, 0x0058);
+    let fcStshfOrig = reader.read_i32::<LittleEndian>()?;
+    let lcbStshfOrig = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcStshfOrig, lcbStshfOrig, "Original STSH structure".into()));
+    let fcStshf = reader.read_i32::<LittleEndian>()?;
+    let lcbStshf = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcStshf, lcbStshf, "STSH structure".into()));
+    let fcPlcffndRef = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcffndRef = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcffndRef,
+        lcbPlcffndRef,
+        "Footnote reference PLCF of FRD structures".into(),
+    ));
+    let fcPlcffndTxt = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcffndTxt = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcffndTxt, lcbPlcffndTxt, "Footnote text PLC".into()));
+    let fcPlcfandRef = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfandRef = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfandRef,
+        lcbPlcfandRef,
+        "annotation reference ATRDPre10 PLC".into(),
+    ));
+    let fcPlcfandTxt = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfandTxt = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcfandTxt, lcbPlcfandTxt, "annotation text PLC".into()));
+    let fcPlcfsed = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfsed = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcfsed, lcbPlcfsed, "section descriptor SED PLC".into()));
+    let fcPlcfpgd = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfpgd = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcfpgd, lcbPlcfpgd, "page descriptor PGD PLC".into()));
+    let fcPlcfphe = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfphe = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcfphe, lcbPlcfphe, "paragraph height PHE PLC".into()));
+    let fcSttbfglsy = reader.read_i32::<LittleEndian>()?;
+    let lcbSttbfglsy = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcSttbfglsy,
+        lcbSttbfglsy,
+        "glossary string table[arr of c-strings]".into(),
+    ));
+    let fcPlcfglsy = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfglsy = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcfglsy, lcbPlcfglsy, "glossary PLC".into()));
+    let fcPlcfhdd = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfhdd = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcfhdd, lcbPlcfhdd, "header HDD PLC".into()));
+    let fcPlcfbteChpx = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfbteChpx = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfbteChpx,
+        lcbPlcfbteChpx,
+        "character property bin table PLC".into(),
+    ));
+    let fcPlcfbtePapx = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfbtePapx = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfbtePapx,
+        lcbPlcfbtePapx,
+        "paragraph property bin table PLC".into(),
+    ));
+    let fcPlcfsea = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfsea = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcfsea, lcbPlcfsea, "PLC reserved for private use".into()));
+    let fcsttbfffn = reader.read_i32::<LittleEndian>()?;
+    let lcbsttbfffn = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcsttbfffn,
+        lcbsttbfffn,
+        "Font information STTBF(FFN)".into(),
+    ));
+    let fcPlcffldMom = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcffldMom = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcffldMom,
+        lcbPlcffldMom,
+        "FLD PLC of field positions".into(),
+    ));
+    let fcPlcffldHdr = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcffldHdr = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcffldHdr,
+        lcbPlcffldHdr,
+        "FLD PLC of field positions in header subdocument".into(),
+    ));
+    let fcPlcffldFtn = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcffldFtn = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcffldFtn,
+        lcbPlcffldFtn,
+        "FLD PLC of field positions in the footnote subdocument".into(),
+    ));
+    let fcPlcffldAtn = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcffldAtn = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcffldAtn,
+        lcbPlcffldAtn,
+        "FCD PLC of field positions in the annotation subdocument".into(),
+    ));
+    let fcPlcffldMcr = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcffldMcr = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcffldMcr, lcbPlcffldMcr, "No longer used".into()));
+    let fcSttbfbkmk = reader.read_i32::<LittleEndian>()?;
+    let lcbSttbfbkmk = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcSttbfbkmk, lcbSttbfbkmk, "bookmark names STTBF".into()));
+    let fcPlcfbkf = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfbkf = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfbkf,
+        lcbPlcfbkf,
+        "PLCF for CP offsets of bookmarks".into(),
+    ));
+    let fcPlcfbkl = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfbkl = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfbkl,
+        lcbPlcfbkl,
+        "PLCF for ending CP offsets of bookmarks".into(),
+    ));
+    let fcCmds = reader.read_i32::<LittleEndian>()?;
+    let lcbCmds = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcCmds, lcbCmds, "command macros".into()));
+    let fcPlcmcr = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcmcr = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcmcr, lcbPlcmcr, "No longer used".into()));
+    let fcSttbfmcr = reader.read_i32::<LittleEndian>()?;
+    let lcbSttbfmcr = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcSttbfmcr, lcbSttbfmcr, "No longer used".into()));
+    let fcPrDrvr = reader.read_i32::<LittleEndian>()?;
+    let lcbPrDrvr = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPrDrvr, lcbPrDrvr, "Printer driver information".into()));
+    let fcPrEnvPort = reader.read_i32::<LittleEndian>()?;
+    let lcbPrEnvPort = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPrEnvPort,
+        lcbPrEnvPort,
+        "Print environment information(landscape)".into(),
+    ));
+    let fcPrEnvLand = reader.read_i32::<LittleEndian>()?;
+    let lcbPrEnvLand = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPrEnvLand,
+        lcbPrEnvLand,
+        "Print environment information(portrait)".into(),
+    ));
+    let fcWss = reader.read_i32::<LittleEndian>()?;
+    let lcbWss = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcWss, lcbWss, "Window Save state data Structure".into()));
+    let fcDop = reader.read_i32::<LittleEndian>()?;
+    let lcbDop = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcDop, lcbDop, "Document property data".into()));
+    let fcSttbfAssoc = reader.read_i32::<LittleEndian>()?;
+    let lcbSttbfAssoc = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcSttbfAssoc,
+        lcbSttbfAssoc,
+        "associated strings STTBF".into(),
+    ));
+    let fcClx = reader.read_i32::<LittleEndian>()?;
+    let lcbClx = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcClx, lcbClx, "Complex File Information".into()));
+    let fcPlcfpgdFtn = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfpgdFtn = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcfpgdFtn, lcbPlcfpgdFtn, "Not Used".into()));
+    let fcAutosaveSource = reader.read_i32::<LittleEndian>()?;
+    let lcbAutosaveSource = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcAutosaveSource,
+        lcbAutosaveSource,
+        "original file name(for autosave)".into(),
+    ));
+    let fcGrpStAtnOwners = reader.read_i32::<LittleEndian>()?;
+    let lcbGrpStAtnOwners = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcGrpStAtnOwners,
+        lcbGrpStAtnOwners,
+        "group of strings of the annotation owners".into(),
+    ));
+    let fcSttbfAtnBkmk = reader.read_i32::<LittleEndian>()?;
+    let lcbSttbfAtnBkmk = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcSttbfAtnBkmk,
+        lcbSttbfAtnBkmk,
+        "STTBF for bookmark names for annotation subdocument".into(),
+    ));
+
+    assert_eq!(reader.stream_position()?, 0x0188);
+    let wSpare4Fib = reader.read_u16::<LittleEndian>()?;
+    let pnChpFirst = reader.read_u16::<LittleEndian>()?;
+    let pnPapFirst = reader.read_u16::<LittleEndian>()?;
+    let cpnBteChp = reader.read_u16::<LittleEndian>()?;
+    let cpnBtePap = reader.read_u16::<LittleEndian>()?;
+
+    let fcPlcdoaMom = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcdoaMom = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcdoaMom, lcbPlcdoaMom, "No longer used".into()));
+    let fcPlcdoaHdr = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcdoaHdr = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcdoaHdr, lcbPlcdoaHdr, "No longer used".into()));
+    let fcUnused1 = reader.read_i32::<LittleEndian>()?;
+    let lcbUnused1 = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcUnused1, lcbUnused1, "Unused".into()));
+    let fcUnused2 = reader.read_i32::<LittleEndian>()?;
+    let lcbUnused2 = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcUnused2, lcbUnused2, "Unused".into()));
+    let fcPlcfAtnbkf = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfAtnbkf = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfAtnbkf,
+        lcbPlcfAtnbkf,
+        "BKF PLC for annotation subdocument".into(),
+    ));
+    let fcPlcfAtnbkl = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfAtnbkl = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfAtnbkl,
+        lcbPlcfAtnbkl,
+        "BKL PLC for annotation subdocument".into(),
+    ));
+    let fcPms = reader.read_i32::<LittleEndian>()?;
+    let lcbPms = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPms,
+        lcbPms,
+        "PMS(Print merge state) information block".into(),
+    ));
+    let fcFormFldSttbs = reader.read_i32::<LittleEndian>()?;
+    let lcbFormFldSttbs = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcFormFldSttbs, lcbFormFldSttbs, "Form Field STTBF".into()));
+    let fcPlcfendRef = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfendRef = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcfendRef, lcbPlcfendRef, "PLCF FRD structures".into()));
+    let fcPlcfendTxt = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfendTxt = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfendTxt,
+        lcbPlcfendTxt,
+        "offset of endnote text of endnote subdocument".into(),
+    ));
+    let fcPlcffldEdn = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcffldEdn = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcffldEdn,
+        lcbPlcffldEdn,
+        "FLD PLC of field positions in endnote subdocument".into(),
+    ));
+    let fcPlcfpgdEdn = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfpgdEdn = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcfpgdEdn, lcbPlcfpgdEdn, "Not Used".into()));
+    let fcUnused3 = reader.read_i32::<LittleEndian>()?;
+    let lcbUnused3 = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcUnused3, lcbUnused3, "Unused".into()));
+    let fcSttbfRMark = reader.read_i32::<LittleEndian>()?;
+    let lcbSttbfRMark = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcSttbfRMark,
+        lcbSttbfRMark,
+        "STTBF for revision author abbreviations".into(),
+    ));
+    let fcSttbfCaption = reader.read_i32::<LittleEndian>()?;
+    let lcbSttbfCaption = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcSttbfCaption,
+        lcbSttbfCaption,
+        "STTBF for caption names".into(),
+    ));
+    let fcSttbfAutoCaption = reader.read_i32::<LittleEndian>()?;
+    let lcbSttbfAutoCaption = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcSttbfAutoCaption,
+        lcbSttbfAutoCaption,
+        "STTBF for auto caption names".into(),
+    ));
+    let fcPlcfWkb = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfWkb = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcfWkb, lcbPlcfWkb, "PLCF WKP".into()));
+    let fcUnused4 = reader.read_i32::<LittleEndian>()?;
+    let lcbUnused4 = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcUnused4, lcbUnused4, "Unused".into()));
+    let fcPlcftxbxTxt = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcftxbxTxt = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcftxbxTxt,
+        lcbPlcftxbxTxt,
+        "PLCF for textbox text".into(),
+    ));
+    let fcPlcffldTxbx = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcffldTxbx = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcffldTxbx,
+        lcbPlcffldTxbx,
+        "PLCF for textbox text for textbox subdocument".into(),
+    ));
+    let fcPlcfhdrtxbxTxt = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfhdrtxbxTxt = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfhdrtxbxTxt,
+        lcbPlcfhdrtxbxTxt,
+        "PLCF for textbox text for header textbox subdocument".into(),
+    ));
+    let fcPlcffldHdrTxbx = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcffldHdrTxbx = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcffldHdrTxbx,
+        lcbPlcffldHdrTxbx,
+        "FLD PLCF for textbox text for header textbox subdocument".into(),
+    ));
+    let fcStwUser = reader.read_i32::<LittleEndian>()?;
+    let lcbStwUser = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcStwUser, lcbStwUser, "Macro user storage".into()));
+    let fcSttbTtmbd = reader.read_i32::<LittleEndian>()?;
+    let lcbSttbTtmbd = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcSttbTtmbd,
+        lcbSttbTtmbd,
+        "emebedded true type font data".into(),
+    ));
+    let fcUnused5 = reader.read_i32::<LittleEndian>()?;
+    let lcbUnused5 = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcUnused5, lcbUnused5, "Unused".into()));
+    let fcPgdMother = reader.read_i32::<LittleEndian>()?;
+    let lcbPgdMother = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPgdMother,
+        lcbPgdMother,
+        "PLF of page descriptors for main document".into(),
+    ));
+    let fcBkdMother = reader.read_i32::<LittleEndian>()?;
+    let lcbBkdMother = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcBkdMother,
+        lcbBkdMother,
+        "PLF of break descriptors for main document".into(),
+    ));
+    let fcPgdFtn = reader.read_i32::<LittleEndian>()?;
+    let lcbPgdFtn = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPgdFtn,
+        lcbPgdFtn,
+        "PLF of page descriptors for footnote text".into(),
+    ));
+    let fcBkdFtn = reader.read_i32::<LittleEndian>()?;
+    let lcbBkdFtn = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcBkdFtn,
+        lcbBkdFtn,
+        "PLF of break descriptors for footnote text".into(),
+    ));
+    let fcPgdEdn = reader.read_i32::<LittleEndian>()?;
+    let lcbPgdEdn = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPgdEdn,
+        lcbPgdEdn,
+        "PLF of page descriptors for endnote text".into(),
+    ));
+    let fcBkdEdn = reader.read_i32::<LittleEndian>()?;
+    let lcbBkdEdn = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcBkdEdn,
+        lcbBkdEdn,
+        "PLF of break descriptors for endnote text".into(),
+    ));
+    let fcSttbfIntlFld = reader.read_i32::<LittleEndian>()?;
+    let lcbSttbfIntlFld = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcSttbfIntlFld,
+        lcbSttbfIntlFld,
+        "STTBF of field keywords".into(),
+    ));
+    let fcRouteSlip = reader.read_i32::<LittleEndian>()?;
+    let lcbRouteSlip = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcRouteSlip, lcbRouteSlip, "a mailer routing slip".into()));
+    let fcSttbSavedBy = reader.read_i32::<LittleEndian>()?;
+    let lcbSttbSavedBy = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcSttbSavedBy,
+        lcbSttbSavedBy,
+        "STTBF of document savers; (user, location) pairs".into(),
+    ));
+    let fcSttbFnm = reader.read_i32::<LittleEndian>()?;
+    let lcbSttbFnm = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcSttbFnm,
+        lcbSttbFnm,
+        "STTBF of file names refered to by doc".into(),
+    ));
+
+    Ok((
+        Fib6 {
+            wIdent,
+            nFib,
+            nProduct,
+            Lid,
+            pnNext,
+            fDot,
+            fGlsy,
+            fComplex,
+            fHasPic,
+            cQuickSaves,
+            fEncrypted,
+            fReserved,
+            fReadOnlyRecommended,
+            fWriteReservation,
+            fExtChar,
+            nFibBack,
+            lKey,
+            Envr,
+            fMac,
+            Chs,
+            chsTables,
+            fcMin,
+            fcMac,
+            cbMac,
+            fcSpare0,
+            fcSpare1,
+            fcSpare2,
+            fcSpare3,
+            ccpText,
+            ccpFtn,
+            ccpHdr,
+            ccpMcr,
+            ccpAtn,
+            ccpEdn,
+            ccpTxbx,
+            ccpHrdTxbx,
+            ccpSpare2,
+            fcStshfOrig,
+            lcbStshfOrig,
+            fcStshf,
+            lcbStshf,
+            fcPlcffndRef,
+            lcbPlcffndRef,
+            fcPlcffndTxt,
+            lcbPlcffndTxt,
+            fcPlcfandRef,
+            lcbPlcfandRef,
+            fcPlcfandTxt,
+            lcbPlcfandTxt,
+            fcPlcfsed,
+            lcbPlcfsed,
+            fcPlcfpgd,
+            lcbPlcfpgd,
+            fcPlcfphe,
+            lcbPlcfphe,
+            fcSttbfglsy,
+            lcbSttbfglsy,
+            fcPlcfglsy,
+            lcbPlcfglsy,
+            fcPlcfhdd,
+            lcbPlcfhdd,
+            fcPlcfbteChpx,
+            lcbPlcfbteChpx,
+            fcPlcfbtePapx,
+            lcbPlcfbtePapx,
+            fcPlcfsea,
+            lcbPlcfsea,
+            fcsttbfffn,
+            lcbsttbfffn,
+            fcPlcffldMom,
+            lcbPlcffldMom,
+            fcPlcffldHdr,
+            lcbPlcffldHdr,
+            fcPlcffldFtn,
+            lcbPlcffldFtn,
+            fcPlcffldAtn,
+            lcbPlcffldAtn,
+            fcPlcffldMcr,
+            lcbPlcffldMcr,
+            fcSttbfbkmk,
+            lcbSttbfbkmk,
+            fcPlcfbkf,
+            lcbPlcfbkf,
+            fcPlcfbkl,
+            lcbPlcfbkl,
+            fcCmds,
+            lcbCmds,
+            fcPlcmcr,
+            lcbPlcmcr,
+            fcSttbfmcr,
+            lcbSttbfmcr,
+            fcPrDrvr,
+            lcbPrDrvr,
+            fcPrEnvPort,
+            lcbPrEnvPort,
+            fcPrEnvLand,
+            lcbPrEnvLand,
+            fcWss,
+            lcbWss,
+            fcDop,
+            lcbDop,
+            fcSttbfAssoc,
+            lcbSttbfAssoc,
+            fcClx,
+            lcbClx,
+            fcPlcfpgdFtn,
+            lcbPlcfpgdFtn,
+            fcAutosaveSource,
+            lcbAutosaveSource,
+            fcGrpStAtnOwners,
+            lcbGrpStAtnOwners,
+            fcSttbfAtnBkmk,
+            lcbSttbfAtnBkmk,
+            wSpare4Fib,
+            pnChpFirst,
+            pnPapFirst,
+            cpnBteChp,
+            cpnBtePap,
+            fcPlcdoaMom,
+            lcbPlcdoaMom,
+            fcPlcdoaHdr,
+            lcbPlcdoaHdr,
+            fcUnused1,
+            lcbUnused1,
+            fcUnused2,
+            lcbUnused2,
+            fcPlcfAtnbkf,
+            lcbPlcfAtnbkf,
+            fcPlcfAtnbkl,
+            lcbPlcfAtnbkl,
+            fcPms,
+            lcbPms,
+            fcFormFldSttbs,
+            lcbFormFldSttbs,
+            fcPlcfendRef,
+            lcbPlcfendRef,
+            fcPlcfendTxt,
+            lcbPlcfendTxt,
+            fcPlcffldEdn,
+            lcbPlcffldEdn,
+            fcPlcfpgdEdn,
+            lcbPlcfpgdEdn,
+            fcUnused3,
+            lcbUnused3,
+            fcSttbfRMark,
+            lcbSttbfRMark,
+            fcSttbfCaption,
+            lcbSttbfCaption,
+            fcSttbfAutoCaption,
+            lcbSttbfAutoCaption,
+            fcPlcfWkb,
+            lcbPlcfWkb,
+            fcUnused4,
+            lcbUnused4,
+            fcPlcftxbxTxt,
+            lcbPlcftxbxTxt,
+            fcPlcffldTxbx,
+            lcbPlcffldTxbx,
+            fcPlcfhdrtxbxTxt,
+            lcbPlcfhdrtxbxTxt,
+            fcPlcffldHdrTxbx,
+            lcbPlcffldHdrTxbx,
+            fcStwUser,
+            lcbStwUser,
+            fcSttbTtmbd,
+            lcbSttbTtmbd,
+            fcUnused5,
+            lcbUnused5,
+            fcPgdMother,
+            lcbPgdMother,
+            fcBkdMother,
+            lcbBkdMother,
+            fcPgdFtn,
+            lcbPgdFtn,
+            fcBkdFtn,
+            lcbBkdFtn,
+            fcPgdEdn,
+            lcbPgdEdn,
+            fcBkdEdn,
+            lcbBkdEdn,
+            fcSttbfIntlFld,
+            lcbSttbfIntlFld,
+            fcRouteSlip,
+            lcbRouteSlip,
+            fcSttbSavedBy,
+            lcbSttbSavedBy,
+            fcSttbFnm,
+            lcbSttbFnm,
+        },
+        pairs,
+    ))
+}
+
+/// Reads the next `len` bytes of a block of the FIB, so its fields can't be read past its end
+fn read_block<R: Read>(reader: &mut R, len: usize) -> io::Result<Cursor<Vec<u8>>> {
+    let mut block = vec![0; len];
+    reader.read_exact(&mut block)?;
+    Ok(Cursor::new(block))
+}
+
+/// Reads the next u16 of `block`, `None` if the block ends before it
+fn read_optional_u16(block: &mut Cursor<Vec<u8>>) -> io::Result<Option<u16>> {
+    if block.position() + 2 > block.get_ref().len() as u64 {
+        return Ok(None);
+    }
+    Ok(Some(block.read_u16::<LittleEndian>()?))
+}
+
+#[allow(non_snake_case)]
+fn fib_rg_fc_lcb_2000_from_read_impl<R: Read>(
+    reader: &mut R,
+) -> io::Result<(FibRgFcLcb2000, FcLcbPairs)> {
+    let mut pairs = vec![];
+
+    let fcPlcfTch = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfTch = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcfTch, lcbPlcfTch, "internal cache".into()));
+    let fcRmdfThreading = reader.read_i32::<LittleEndian>()?;
+    let lcbRmdfThreading = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcRmdfThreading,
+        lcbRmdfThreading,
+        "revision mark data(unused)".into(),
+    ));
+    let fcMid = reader.read_i32::<LittleEndian>()?;
+    let lcbMid = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcMid, lcbMid, "Message ID".into()));
+    let fcSttbRgtplc = reader.read_i32::<LittleEndian>()?;
+    let lcbSttbRgtplc = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcSttbRgtplc,
+        lcbSttbRgtplc,
+        "list gallery data (tplcs)".into(),
+    ));
+    let fcMsoEnvelope = reader.read_i32::<LittleEndian>()?;
+    let lcbMsoEnvelope = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcMsoEnvelope,
+        lcbMsoEnvelope,
+        "email header information".into(),
+    ));
+    let fcPlcfLad = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfLad = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcfLad, lcbPlcfLad, "language autodetect results".into()));
+    let fcRgDofr = reader.read_i32::<LittleEndian>()?;
+    let lcbRgDofr = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcRgDofr, lcbRgDofr, "miscellaneous document data".into()));
+    let fcPlcosl = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcosl = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcosl, lcbPlcosl, "NLCheck grammar option state".into()));
+    let fcPlcfCookieOld = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfCookieOld = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfCookieOld,
+        lcbPlcfCookieOld,
+        "smart tag recognizer state pre Word 10".into(),
+    ));
+
+    let mut fcpgdold_buff: [u8; 16] = [0; 16];
+    reader.read_exact(&mut fcpgdold_buff)?;
+    let fcPgdMotherOld = FCPGDOLD::from_bytes(&fcpgdold_buff);
+    reader.read_exact(&mut fcpgdold_buff)?;
+    let fcpgdFtnOld = FCPGDOLD::from_bytes(&fcpgdold_buff);
+    reader.read_exact(&mut fcpgdold_buff)?;
+    let fcpgdEdnOld = FCPGDOLD::from_bytes(&fcpgdold_buff);
+
+    pairs.push((
+        fcPgdMotherOld.fcPgd,
+        fcPgdMotherOld.lcbPgd,
+        "PLF of page descriptors for main document".into(),
+    ));
+    pairs.push((
+        fcPgdMotherOld.fcBkd,
+        fcPgdMotherOld.lcbBkd,
+        "PLF of break descriptors for main document".into(),
+    ));
+    pairs.push((
+        fcpgdFtnOld.fcPgd,
+        fcpgdFtnOld.lcbPgd,
+        "PLF of page descriptors for footnote text".into(),
+    ));
+    pairs.push((
+        fcpgdFtnOld.fcBkd,
+        fcpgdFtnOld.lcbBkd,
+        "PLF of break descriptors for footnote text".into(),
+    ));
+    pairs.push((
+        fcpgdEdnOld.fcPgd,
+        fcpgdEdnOld.lcbPgd,
+        "PLF of page descriptors for endnote text".into(),
+    ));
+    pairs.push((
+        fcpgdEdnOld.fcBkd,
+        fcpgdEdnOld.lcbBkd,
+        "PLF of break descriptors for endnote text".into(),
+    ));
+
+    Ok((
+        FibRgFcLcb2000 {
+            fcPlcfTch,
+            lcbPlcfTch,
+            fcRmdfThreading,
+            lcbRmdfThreading,
+            fcMid,
+            lcbMid,
+            fcSttbRgtplc,
+            lcbSttbRgtplc,
+            fcMsoEnvelope,
+            lcbMsoEnvelope,
+            fcPlcfLad,
+            lcbPlcfLad,
+            fcRgDofr,
+            lcbRgDofr,
+            fcPlcosl,
+            lcbPlcosl,
+            fcPlcfCookieOld,
+            lcbPlcfCookieOld,
+            fcPgdMotherOld,
+            fcpgdFtnOld,
+            fcpgdEdnOld,
+        },
+        pairs,
+    ))
+}
+
+#[allow(non_snake_case)]
+fn fib_rg_fc_lcb_2002_from_read_impl<R: Read>(
+    reader: &mut R,
+) -> io::Result<(FibRgFcLcb2002, FcLcbPairs)> {
+    let mut pairs = vec![];
+
+    let fcUnused1 = reader.read_i32::<LittleEndian>()?;
+    let lcbUnused1 = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcUnused1, lcbUnused1, "Unused".into()));
+    let fcPlcfPgp = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfPgp = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfPgp,
+        lcbPlcfPgp,
+        "paragraph group properties(HTML DIV)".into(),
+    ));
+    let fcPlcfuim = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfuim = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcfuim, lcbPlcfuim, "UIM property data".into()));
+    let fcPlfguidUim = reader.read_i32::<LittleEndian>()?;
+    let lcbPlfguidUim = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlfguidUim, lcbPlfguidUim, "UIM table of GUIDs".into()));
+    let fcAtrdExtra = reader.read_i32::<LittleEndian>()?;
+    let lcbAtrdExtra = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcAtrdExtra,
+        lcbAtrdExtra,
+        "plex of ATRDPost10 structures".into(),
+    ));
+    let fcPlrsid = reader.read_i32::<LittleEndian>()?;
+    let lcbPlrsid = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlrsid, lcbPlrsid, "PLCF of RSID structures".into()));
+    let fcSttbfBkmkFactoid = reader.read_i32::<LittleEndian>()?;
+    let lcbSttbfBkmkFactoid = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcSttbfBkmkFactoid,
+        lcbSttbfBkmkFactoid,
+        "Smart tag bookmark STTB".into(),
+    ));
+    let fcPlcfBkfFactoid = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfBkfFactoid = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfBkfFactoid,
+        lcbPlcfBkfFactoid,
+        "smart tak bookmark plc of cpFirsts".into(),
+    ));
+    let fcPlcfcookie = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfcookie = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfcookie,
+        lcbPlcfcookie,
+        "internal data for grammar features".into(),
+    ));
+    let fcPlcfBklFactoid = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfBklFactoid = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfBklFactoid,
+        lcbPlcfBklFactoid,
+        "smart tag bookmark plc of cpLims".into(),
+    ));
+    let fcFactoidData = reader.read_i32::<LittleEndian>()?;
+    let lcbFactoidData = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcFactoidData, lcbFactoidData, "smart tag data".into()));
+    let fcDocUndo = reader.read_i32::<LittleEndian>()?;
+    let lcbDocUndo = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcDocUndo, lcbDocUndo, "undo / versioning data".into()));
+    let fcSttbfBkmkFcc = reader.read_i32::<LittleEndian>()?;
+    let lcbSttbfBkmkFcc = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcSttbfBkmkFcc, lcbSttbfBkmkFcc, "FCC bookmark STTB".into()));
+    let fcPlcfBkfFcc = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfBkfFcc = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfBkfFcc,
+        lcbPlcfBkfFcc,
+        "fcc bookmark plc of cpLims".into(),
+    ));
+    let fcPlcfBklFcc = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfBklFcc = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfBklFcc,
+        lcbPlcfBklFcc,
+        "fcc bookmark plc of cpLims".into(),
+    ));
+    let fcSttbfbkmkBPRepairs = reader.read_i32::<LittleEndian>()?;
+    let lcbSttbfbkmkBPRepairs = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcSttbfbkmkBPRepairs,
+        lcbSttbfbkmkBPRepairs,
+        "plc of cpFirsts for file repair feature".into(),
+    ));
+    let fcPlcfbkfBPRepairs = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfbkfBPRepairs = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfbkfBPRepairs,
+        lcbPlcfbkfBPRepairs,
+        "plc of cpLims for file repair feature".into(),
+    ));
+    let fcPlcfbklBPRepairs = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfbklBPRepairs = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfbklBPRepairs,
+        lcbPlcfbklBPRepairs,
+        "plc of cpLims for file repair feature".into(),
+    ));
+    let fcPmsNew = reader.read_i32::<LittleEndian>()?;
+    let lcbPmsNew = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPmsNew,
+        lcbPmsNew,
+        "PMS satate for new merge state information".into(),
+    ));
+    let fcODSO = reader.read_i32::<LittleEndian>()?;
+    let lcbODSO = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcODSO,
+        lcbODSO,
+        "IMsoODSO / IMsoMailmerge Information".into(),
+    ));
+    let fcPlcfpmiOldXP = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfpmiOldXP = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfpmiOldXP,
+        lcbPlcfpmiOldXP,
+        "Paragraph Mark Information(Old View)".into(),
+    ));
+    let fcPlcfpmiNewXP = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfpmiNewXP = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfpmiNewXP,
+        lcbPlcfpmiNewXP,
+        "Paragraph Mark Information(New View)".into(),
+    ));
+    let fcPlcfpmiMixedXP = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfpmiMixedXP = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfpmiMixedXP,
+        lcbPlcfpmiMixedXP,
+        "Paragraph Mark Information(Mixed View)".into(),
+    ));
+    let fcEncryptedProps = reader.read_i32::<LittleEndian>()?;
+    let lcbEncryptedProps = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcEncryptedProps,
+        lcbEncryptedProps,
+        "Internal Encrypted Document Properties".into(),
+    ));
+    let fcPlcffactoid = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcffactoid = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcffactoid,
+        lcbPlcffactoid,
+        "background factoid checking state".into(),
+    ));
+    let fcPlcflvcOldXP = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcflvcOldXP = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcflvcOldXP, lcbPlcflvcOldXP, "LVS PLC(Old View)".into()));
+    let fcPlcflvcNewXP = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcflvcNewXP = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcflvcNewXP, lcbPlcflvcNewXP, "LVS PLC(New View)".into()));
+    let fcPlcflvcMixedXP = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcflvcMixedXP = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcflvcMixedXP,
+        lcbPlcflvcMixedXP,
+        "LVS PLC(Mixed View)".into(),
+    ));
+
+    Ok((
+        FibRgFcLcb2002 {
+            fcUnused1,
+            lcbUnused1,
+            fcPlcfPgp,
+            lcbPlcfPgp,
+            fcPlcfuim,
+            lcbPlcfuim,
+            fcPlfguidUim,
+            lcbPlfguidUim,
+            fcAtrdExtra,
+            lcbAtrdExtra,
+            fcPlrsid,
+            lcbPlrsid,
+            fcSttbfBkmkFactoid,
+            lcbSttbfBkmkFactoid,
+            fcPlcfBkfFactoid,
+            lcbPlcfBkfFactoid,
+            fcPlcfcookie,
+            lcbPlcfcookie,
+            fcPlcfBklFactoid,
+            lcbPlcfBklFactoid,
+            fcFactoidData,
+            lcbFactoidData,
+            fcDocUndo,
+            lcbDocUndo,
+            fcSttbfBkmkFcc,
+            lcbSttbfBkmkFcc,
+            fcPlcfBkfFcc,
+            lcbPlcfBkfFcc,
             fcPlcfBklFcc,
             lcbPlcfBklFcc,
             fcSttbfbkmkBPRepairs,
             lcbSttbfbkmkBPRepairs,
             fcPlcfbkfBPRepairs,
             lcbPlcfbkfBPRepairs,
+            fcPlcfbklBPRepairs,
+            lcbPlcfbklBPRepairs,
             fcPmsNew,
             lcbPmsNew,
             fcODSO,
@@ -1315,6 +1955,201 @@ pub fn fib_from_read_impl<R: Read + Seek>(
             lcbPlcflvcNewXP,
             fcPlcflvcMixedXP,
             lcbPlcflvcMixedXP,
+        },
+        pairs,
+    ))
+}
+
+#[allow(non_snake_case)]
+fn fib_rg_fc_lcb_2003_from_read_impl<R: Read>(
+    reader: &mut R,
+) -> io::Result<(FibRgFcLcb2003, FcLcbPairs)> {
+    let mut pairs = vec![];
+
+    let fcHplxsdr = reader.read_i32::<LittleEndian>()?;
+    let lcbHplxsdr = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcHplxsdr,
+        lcbHplxsdr,
+        "XML Schema definition References".into(),
+    ));
+    let fcSttbfBkmkSdt = reader.read_i32::<LittleEndian>()?;
+    let lcbSttbfBkmkSdt = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcSttbfBkmkSdt,
+        lcbSttbfBkmkSdt,
+        "XML bookmark information".into(),
+    ));
+    let fcPlcfBkfSdt = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfBkfSdt = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfBkfSdt,
+        lcbPlcfBkfSdt,
+        "SDT bookmark plc of cpFirsts".into(),
+    ));
+    let fcPlcBlkSdt = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcBlkSdt = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcBlkSdt,
+        lcbPlcBlkSdt,
+        "SDT bookmark plc of cpLims".into(),
+    ));
+    let fcCustomXForm = reader.read_i32::<LittleEndian>()?;
+    let lcbCustomXForm = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcCustomXForm, lcbCustomXForm, "Custom XML Transform".into()));
+    let fcSttbfBkmkProt = reader.read_i32::<LittleEndian>()?;
+    let lcbSttbfBkmkProt = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcSttbfBkmkProt,
+        lcbSttbfBkmkProt,
+        "Range Protection Bookmark STTB".into(),
+    ));
+    let fcPlcfBkfProt = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfBkfProt = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfBkfProt,
+        lcbPlcfBkfProt,
+        "Range Protection bookmark plc of cpFirsts".into(),
+    ));
+    let fcPlcfBklProt = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfBklProt = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfBklProt,
+        lcbPlcfBklProt,
+        "Range Protection bookmark plc of cpLims".into(),
+    ));
+    let fcSttbProtUser = reader.read_i32::<LittleEndian>()?;
+    let lcbSttbProtUser = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcSttbProtUser,
+        lcbSttbProtUser,
+        "Range Protection User List STTB".into(),
+    ));
+    let fcPlcftpc = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcftpc = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcftpc, lcbPlcftpc, "current text paragraph cache".into()));
+    let fcPlcfpmiOld = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfpmiOld = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfpmiOld,
+        lcbPlcfpmiOld,
+        "Paragraph Mark Information(Old View)".into(),
+    ));
+    let fcPlcfpmiOldInline = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfpmiOldInline = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfpmiOldInline,
+        lcbPlcfpmiOldInline,
+        "Paragraph Mark Information(Old Inline View)".into(),
+    ));
+    let fcPlcfpmiNew = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfpmiNew = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfpmiNew,
+        lcbPlcfpmiNew,
+        "Paragraph Mark Information(New View)".into(),
+    ));
+    let fcPlcfpmiNewInline = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfpmiNewInline = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfpmiNewInline,
+        lcbPlcfpmiNewInline,
+        "Paragraph Mark Information(New Inline View)".into(),
+    ));
+    let fcPlcfvcOld = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfvcOld = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcfvcOld, lcbPlcfvcOld, "LVC PLC(Old View)".into()));
+    let fcPlcfvcOldInline = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfvcOldInline = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfvcOldInline,
+        lcbPlcfvcOldInline,
+        "LVC PLC(Old Inline View)".into(),
+    ));
+    let fcPlcflvcNew = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcflvcNew = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcflvcNew, lcbPlcflvcNew, "LVC PLC(New View)".into()));
+    let fcPlcflvcNewInline = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcflvcNewInline = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcflvcNewInline,
+        lcbPlcflvcNewInline,
+        "LVC PLC(New Inline View)".into(),
+    ));
+
+    let fcPgdMother = reader.read_i32::<LittleEndian>()?;
+    let lcbPgdMother = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPgdMother,
+        lcbPgdMother,
+        "page descriptors of the main document".into(),
+    ));
+    let fcBkdMother = reader.read_i32::<LittleEndian>()?;
+    let lcbBkdMother = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcBkdMother,
+        lcbBkdMother,
+        "break descriptors of the main document".into(),
+    ));
+    let fcAfdMother = reader.read_i32::<LittleEndian>()?;
+    let lcbAfdMother = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcAfdMother,
+        lcbAfdMother,
+        "auto format descriptors of the main document".into(),
+    ));
+    let fcPgdFtn = reader.read_i32::<LittleEndian>()?;
+    let lcbPgdFtn = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPgdFtn,
+        lcbPgdFtn,
+        "page descriptors of the footnote document".into(),
+    ));
+    let fcBkdFtn = reader.read_i32::<LittleEndian>()?;
+    let lcbBkdFtn = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcBkdFtn,
+        lcbBkdFtn,
+        "break descriptors of the footnote document".into(),
+    ));
+    let fcAfdFtn = reader.read_i32::<LittleEndian>()?;
+    let lcbAfdFtn = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcAfdFtn,
+        lcbAfdFtn,
+        "auto format descriptors of the footnote document".into(),
+    ));
+    let fcPgdEdn = reader.read_i32::<LittleEndian>()?;
+    let lcbPgdEdn = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPgdEdn,
+        lcbPgdEdn,
+        "page descriptors of the endnote document".into(),
+    ));
+    let fcBkdEdn = reader.read_i32::<LittleEndian>()?;
+    let lcbBkdEdn = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcBkdEdn,
+        lcbBkdEdn,
+        "break descriptors of the endnote document".into(),
+    ));
+    let fcAfdEdn = reader.read_i32::<LittleEndian>()?;
+    let lcbAfdEdn = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcAfdEdn,
+        lcbAfdEdn,
+        "auto format descriptors of the endnote document".into(),
+    ));
+    let fcAfd = reader.read_i32::<LittleEndian>()?;
+    let lcbAfd = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcAfd,
+        lcbAfd,
+        "Internal revision mark view information".into(),
+    ));
+
+    Ok((
+        FibRgFcLcb2003 {
             fcHplxsdr,
             lcbHplxsdr,
             fcSttbfBkmkSdt,
@@ -1351,22 +2186,195 @@ pub fn fib_from_read_impl<R: Read + Seek>(
             lcbPlcflvcNew,
             fcPlcflvcNewInline,
             lcbPlcflvcNewInline,
-            fcpgdMother,
-            fcpgdFtn,
-            fcpgdEdn,
+            fcPgdMother,
+            lcbPgdMother,
+            fcBkdMother,
+            lcbBkdMother,
+            fcAfdMother,
+            lcbAfdMother,
+            fcPgdFtn,
+            lcbPgdFtn,
+            fcBkdFtn,
+            lcbBkdFtn,
+            fcAfdFtn,
+            lcbAfdFtn,
+            fcPgdEdn,
+            lcbPgdEdn,
+            fcBkdEdn,
+            lcbBkdEdn,
+            fcAfdEdn,
+            lcbAfdEdn,
             fcAfd,
             lcbAfd,
-            cswNew,
-            actualNFib,
-            cQuickSavesNew,
+        },
+        pairs,
+    ))
+}
+
+#[allow(non_snake_case)]
+fn fib_rg_fc_lcb_2007_from_read_impl<R: Read>(
+    reader: &mut R,
+) -> io::Result<(FibRgFcLcb2007, FcLcbPairs)> {
+    let mut pairs = vec![];
+
+    let fcPlcfmthd = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfmthd = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcPlcfmthd, lcbPlcfmthd, "Unused".into()));
+    let fcSttbfBkmkMoveFrom = reader.read_i32::<LittleEndian>()?;
+    let lcbSttbfBkmkMoveFrom = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcSttbfBkmkMoveFrom,
+        lcbSttbfBkmkMoveFrom,
+        "names of bookmarks of moved from text".into(),
+    ));
+    let fcPlcfBkfMoveFrom = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfBkfMoveFrom = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfBkfMoveFrom,
+        lcbPlcfBkfMoveFrom,
+        "starts of bookmarks of moved from text".into(),
+    ));
+    let fcPlcfBklMoveFrom = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfBklMoveFrom = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfBklMoveFrom,
+        lcbPlcfBklMoveFrom,
+        "ends of bookmarks of moved from text".into(),
+    ));
+    let fcSttbfBkmkMoveTo = reader.read_i32::<LittleEndian>()?;
+    let lcbSttbfBkmkMoveTo = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcSttbfBkmkMoveTo,
+        lcbSttbfBkmkMoveTo,
+        "names of bookmarks of moved to text".into(),
+    ));
+    let fcPlcfBkfMoveTo = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfBkfMoveTo = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfBkfMoveTo,
+        lcbPlcfBkfMoveTo,
+        "starts of bookmarks of moved to text".into(),
+    ));
+    let fcPlcfBklMoveTo = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfBklMoveTo = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfBklMoveTo,
+        lcbPlcfBklMoveTo,
+        "ends of bookmarks of moved to text".into(),
+    ));
+    let fcUnused1 = reader.read_i32::<LittleEndian>()?;
+    let lcbUnused1 = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcUnused1, lcbUnused1, "Unused".into()));
+    let fcUnused2 = reader.read_i32::<LittleEndian>()?;
+    let lcbUnused2 = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcUnused2, lcbUnused2, "Unused".into()));
+    let fcUnused3 = reader.read_i32::<LittleEndian>()?;
+    let lcbUnused3 = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcUnused3, lcbUnused3, "Unused".into()));
+    let fcSttbfBkmkArto = reader.read_i32::<LittleEndian>()?;
+    let lcbSttbfBkmkArto = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcSttbfBkmkArto,
+        lcbSttbfBkmkArto,
+        "names of bookmarks of AutoText".into(),
+    ));
+    let fcPlcfBkfArto = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfBkfArto = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfBkfArto,
+        lcbPlcfBkfArto,
+        "starts of bookmarks of AutoText".into(),
+    ));
+    let fcPlcfBklArto = reader.read_i32::<LittleEndian>()?;
+    let lcbPlcfBklArto = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcPlcfBklArto,
+        lcbPlcfBklArto,
+        "ends of bookmarks of AutoText".into(),
+    ));
+    let fcArtoData = reader.read_i32::<LittleEndian>()?;
+    let lcbArtoData = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcArtoData, lcbArtoData, "Unused".into()));
+    let fcUnused4 = reader.read_i32::<LittleEndian>()?;
+    let lcbUnused4 = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcUnused4, lcbUnused4, "Unused".into()));
+    let fcUnused5 = reader.read_i32::<LittleEndian>()?;
+    let lcbUnused5 = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcUnused5, lcbUnused5, "Unused".into()));
+    let fcUnused6 = reader.read_i32::<LittleEndian>()?;
+    let lcbUnused6 = reader.read_u32::<LittleEndian>()?;
+    pairs.push((fcUnused6, lcbUnused6, "Unused".into()));
+    let fcOssTheme = reader.read_i32::<LittleEndian>()?;
+    let lcbOssTheme = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcOssTheme,
+        lcbOssTheme,
+        "Office theme of the document".into(),
+    ));
+    let fcColorSchemeMapping = reader.read_i32::<LittleEndian>()?;
+    let lcbColorSchemeMapping = reader.read_u32::<LittleEndian>()?;
+    pairs.push((
+        fcColorSchemeMapping,
+        lcbColorSchemeMapping,
+        "color scheme mapping of the document".into(),
+    ));
+
+    Ok((
+        FibRgFcLcb2007 {
+            fcPlcfmthd,
+            lcbPlcfmthd,
+            fcSttbfBkmkMoveFrom,
+            lcbSttbfBkmkMoveFrom,
+            fcPlcfBkfMoveFrom,
+            lcbPlcfBkfMoveFrom,
+            fcPlcfBklMoveFrom,
+            lcbPlcfBklMoveFrom,
+            fcSttbfBkmkMoveTo,
+            lcbSttbfBkmkMoveTo,
+            fcPlcfBkfMoveTo,
+            lcbPlcfBkfMoveTo,
+            fcPlcfBklMoveTo,
+            lcbPlcfBklMoveTo,
+            fcUnused1,
+            lcbUnused1,
+            fcUnused2,
+            lcbUnused2,
+            fcUnused3,
+            lcbUnused3,
+            fcSttbfBkmkArto,
+            lcbSttbfBkmkArto,
+            fcPlcfBkfArto,
+            lcbPlcfBkfArto,
+            fcPlcfBklArto,
+            lcbPlcfBklArto,
+            fcArtoData,
+            lcbArtoData,
+            fcUnused4,
+            lcbUnused4,
+            fcUnused5,
+            lcbUnused5,
+            fcUnused6,
+            lcbUnused6,
+            fcOssTheme,
+            lcbOssTheme,
+            fcColorSchemeMapping,
+            lcbColorSchemeMapping,
         },
         pairs,
     ))
 }
 
 impl FromReader for SHSHI {
-    #[allow(non_snake_case)]
     fn from_reader<R: Read + Seek>(stsh_buffer: &mut R) -> io::Result<Self> {
+        shshi_from_read_impl(stsh_buffer, false)
+    }
+}
+
+/// Reads a stylesheet, the STSHI and STD bases are read as far as they are stored in the file.
+/// Word 6/95 stylesheets store the style names as 8-bit Pascal strings
+#[allow(non_snake_case)]
+pub fn shshi_from_read_impl<R: Read + Seek>(stsh_buffer: &mut R, word6: bool) -> io::Result<SHSHI> {
+    {
         // read first 2 bytes for size of the STSHI structure
         let cbStshi = stsh_buffer.read_u16::<LittleEndian>()?;
         let mut stshi_buffer = vec![0; cbStshi as usize];
@@ -1382,21 +2390,27 @@ impl FromReader for SHSHI {
         let istdMaxFixedWhenSaved = stshi_buffer.read_u16::<LittleEndian>()?;
         let nVerBuiltInNamesWhenSaved = stshi_buffer.read_u16::<LittleEndian>()?;
 
-        let rgftcStandardChpStsh: [u16; 4] = [
-            stshi_buffer.read_u16::<LittleEndian>()?,
-            stshi_buffer.read_u16::<LittleEndian>()?,
-            stshi_buffer.read_u16::<LittleEndian>()?,
-            stshi_buffer.read_u16::<LittleEndian>()?
-        ];
+        // Word 6 stores 1 ftc, Word 97 3 and Word 2000 and later 4 followed by the latent styles
+        let ftc_count = (cbStshi.saturating_sub(12) / 2).min(4);
+        let mut rgftcStandardChpStsh = Vec::with_capacity(ftc_count as usize);
+        for _ in 0..ftc_count {
+            rgftcStandardChpStsh.push(stshi_buffer.read_u16::<LittleEndian>()?);
+        }
 
-        let cbLSD = stshi_buffer.read_u16::<LittleEndian>()?;
+        let cbLSD = if cbStshi >= 22 {
+            Some(stshi_buffer.read_u16::<LittleEndian>()?)
+        } else {
+            None
+        };
 
         // reading latent style data
         let mut mpstilsd: Vec<Bytes> = Vec::with_capacity(stiMaxWhenSaved as usize);
-        for _ in 0..stiMaxWhenSaved {
-            let mut lsd_buffer = vec![0; cbLSD as usize];
-            stshi_buffer.read_exact(&mut lsd_buffer)?;
-            mpstilsd.push(Bytes::new(lsd_buffer));
+        if let Some(cbLSD) = cbLSD {
+            for _ in 0..stiMaxWhenSaved {
+                let mut lsd_buffer = vec![0; cbLSD as usize];
+                stshi_buffer.read_exact(&mut lsd_buffer)?;
+                mpstilsd.push(Bytes::new(lsd_buffer));
+            }
         }
 
         // Reading in the styles
@@ -1411,7 +2425,7 @@ impl FromReader for SHSHI {
             stsh_buffer.read_exact(&mut std_buffer)?;
             let mut std_buffer = BufReader::new(Cursor::new(std_buffer));
 
-            let stylesheet_std = STD::from_reader(&mut std_buffer)?;
+            let stylesheet_std = std_from_read_impl(&mut std_buffer, cbSTDBaseInFile, word6)?;
 
             // println!("{:?}\n", stylesheet_std);
             let _remaining_buff = std_buffer.fill_buf()?;
@@ -1461,90 +2475,126 @@ impl FromReader for PLCF<PCD> {
 }
 
 impl FromReader for STD {
-    #[allow(non_snake_case)]
     fn from_reader<R: Read + Seek>(std_buffer: &mut R) -> io::Result<Self> {
-        let mut bitfield: u16;
-
-        bitfield = std_buffer.read_u16::<LittleEndian>()?;
-        let sti = bitfield & 0x0FFF;
-        let fScratch = (bitfield & 0x8000) == 0x8000;
-        let fInvalHeight = (bitfield & 0x4000) == 0x4000;
-        let fHasUpe = (bitfield & 0x2000) == 0x2000;
-        let fMassCopy = (bitfield & 0x1000) == 0x1000;
-
-        bitfield = std_buffer.read_u16::<LittleEndian>()?;
-        let stk = bitfield & 0xF000;
-        let istdBase = bitfield & 0x0FFF;
-
-        bitfield = std_buffer.read_u16::<LittleEndian>()?;
-        let cupx = bitfield & 0xF000;
-        let istdNext = bitfield & 0x0FFF;
-
-        let bchUpe = std_buffer.read_u16::<LittleEndian>()?;
-
-        bitfield = std_buffer.read_u16::<LittleEndian>()?;
-        let fAutoRedef = (bitfield & 0x8000) == 0x8000;
-        let fHidden = (bitfield & 0x4000) == 0x4000;
-        let f97LidsSet = (bitfield & 0x2000) == 0x2000;
-        let fCopyLang = (bitfield & 0x1000) == 0x1000;
-        let fPersonalCompose = (bitfield & 0x0800) == 0x0800;
-        let fPersonalReply = (bitfield & 0x0400) == 0x0400;
-        let fPersonal = (bitfield & 0x0200) == 0x0200;
-        let fNoHtmlExport = (bitfield & 0x0100) == 0x0100;
-        let fSemiHidden = (bitfield & 0x0080) == 0x0080;
-        let fLocked = (bitfield & 0x0040) == 0x0040;
-        let fInternalUse = (bitfield & 0x0020) == 0x0020;
-        let _ = bitfield & 0x001F;
+        std_from_read_impl(std_buffer, 18, false)
+    }
+}
 
+/// Reads a STD whose base is `cb_std_base` bytes long, fields beyond the stored base are None
+#[allow(non_snake_case)]
+pub fn std_from_read_impl<R: Read + Seek>(
+    std_buffer: &mut R,
+    cb_std_base: u16,
+    word6: bool,
+) -> io::Result<STD> {
+    let mut bitfield: u16;
+
+    bitfield = std_buffer.read_u16::<LittleEndian>()?;
+    let sti = bitfield & 0x0FFF;
+    let fScratch = (bitfield & 0x8000) == 0x8000;
+    let fInvalHeight = (bitfield & 0x4000) == 0x4000;
+    let fHasUpe = (bitfield & 0x2000) == 0x2000;
+    let fMassCopy = (bitfield & 0x1000) == 0x1000;
+
+    bitfield = std_buffer.read_u16::<LittleEndian>()?;
+    let stk = bitfield & 0xF000;
+    let istdBase = bitfield & 0x0FFF;
+
+    bitfield = std_buffer.read_u16::<LittleEndian>()?;
+    let cupx = bitfield & 0xF000;
+    let istdNext = bitfield & 0x0FFF;
+
+    let bchUpe = std_buffer.read_u16::<LittleEndian>()?;
+
+    let flags = if cb_std_base >= 10 {
+        Some(std_buffer.read_u16::<LittleEndian>()?)
+    } else {
+        None
+    };
+    let flag = |mask: u16| flags.map(|bits| (bits & mask) == mask);
+    let fAutoRedef = flag(0x8000);
+    let fHidden = flag(0x4000);
+    let f97LidsSet = flag(0x2000);
+    let fCopyLang = flag(0x1000);
+    let fPersonalCompose = flag(0x0800);
+    let fPersonalReply = flag(0x0400);
+    let fPersonal = flag(0x0200);
+    let fNoHtmlExport = flag(0x0100);
+    let fSemiHidden = flag(0x0080);
+    let fLocked = flag(0x0040);
+    let fInternalUse = flag(0x0020);
+
+    let (istdLink, fSpare, rsid, iftcHtml) = if cb_std_base >= 18 {
         bitfield = std_buffer.read_u16::<LittleEndian>()?;
         let istdLink = bitfield & 0x0FFF;
         let fSpare = bitfield & 0xF000;
-
         let rsid = std_buffer.read_i32::<LittleEndian>()?;
-
         bitfield = std_buffer.read_u16::<LittleEndian>()?;
-        let iftcHtml = bitfield & 0x0007;
-        let _ = bitfield & 0xFFF8;
-
-        let xstzName = {
-            let length_byte = std_buffer.read_u8()?;
-            let mut name_buffer: Vec<u16> = vec![0; length_byte as usize];
-            std_buffer.read_u16_into::<BigEndian>(&mut name_buffer)?;
-            let _ = std_buffer.read_u8()?; // Null Terminator
-            String::from_utf16(&name_buffer).unwrap()
-        };
-
-        Ok(STD {
-            sti,
-            fScratch,
-            fInvalHeight,
-            fHasUpe,
-            fMassCopy,
-            stk,
-            istdBase,
-            cupx,
-            istdNext,
-            bchUpe,
-            fAutoRedef,
-            fHidden,
-            f97LidsSet,
-            fCopyLang,
-            fPersonalCompose,
-            fPersonalReply,
-            fPersonal,
-            fNoHtmlExport,
-            fSemiHidden,
-            fLocked,
-            fInternalUse,
-            unused_bits: 0,
-            istdLink,
-            fSpare,
-            rsid,
-            iftcHtml,
-            unused: 0,
-            xstzName,
-        })
+        (
+            Some(istdLink),
+            Some(fSpare),
+            Some(rsid),
+            Some(bitfield & 0x0007),
+        )
+    } else {
+        (None, None, None, None)
+    };
+
+    // skip any part of the base this version does not know about
+    let known = match cb_std_base {
+        0..=9 => 8,
+        10..=17 => 10,
+        _ => 18,
+    };
+    if cb_std_base > known {
+        std_buffer.seek(SeekFrom::Current((cb_std_base - known) as i64))?;
     }
+
+    let xstzName = if word6 {
+        // Word 6 names are 8-bit Pascal strings followed by a null terminator
+        let length_byte = std_buffer.read_u8()?;
+        let mut name_buffer = vec![0; length_byte as usize];
+        std_buffer.read_exact(&mut name_buffer)?;
+        let _ = std_buffer.read_u8()?; // Null Terminator
+        name_buffer.iter().map(|&c| c as char).collect()
+    } else {
+        let length_byte = std_buffer.read_u8()?;
+        let mut name_buffer: Vec<u16> = vec![0; length_byte as usize];
+        std_buffer.read_u16_into::<BigEndian>(&mut name_buffer)?;
+        let _ = std_buffer.read_u8()?; // Null Terminator
+        String::from_utf16(&name_buffer).unwrap()
+    };
+
+    Ok(STD {
+        sti,
+        fScratch,
+        fInvalHeight,
+        fHasUpe,
+        fMassCopy,
+        stk,
+        istdBase,
+        cupx,
+        istdNext,
+        bchUpe,
+        fAutoRedef,
+        fHidden,
+        f97LidsSet,
+        fCopyLang,
+        fPersonalCompose,
+        fPersonalReply,
+        fPersonal,
+        fNoHtmlExport,
+        fSemiHidden,
+        fLocked,
+        fInternalUse,
+        unused_bits: 0,
+        istdLink,
+        fSpare,
+        rsid,
+        iftcHtml,
+        unused: 0,
+        xstzName,
+    })
 }
 
 impl FromReader for DocumentSummaryInfoStream {
@@ -2468,3 +3518,515 @@ impl FromReader for LSTs {
         })
     }
 }
+
+impl FromReader for OfficeArtRecordHeader {
+    #[allow(non_snake_case)]
+    fn from_reader<R: Read + Seek>(reader: &mut R) -> io::Result<Self> {
+        let bitfield = reader.read_u16::<LittleEndian>()?;
+        let recVer = (bitfield & 0x000F) as u8;
+        let recInstance = bitfield >> 4;
+        let recType = reader.read_u16::<LittleEndian>()?;
+        let recLen = reader.read_u32::<LittleEndian>()?;
+
+        Ok(OfficeArtRecordHeader {
+            recVer,
+            recInstance,
+            recType,
+            recLen,
+        })
+    }
+}
+
+impl FromReader for OfficeArtFBSE {
+    /// Reads the FBSE after its record header, up to the BLIP that may be embedded in it
+    #[allow(non_snake_case)]
+    fn from_reader<R: Read + Seek>(reader: &mut R) -> io::Result<Self> {
+        let btWin32 = reader.read_u8()?;
+        let btMacOS = reader.read_u8()?;
+        let mut rgbUid = vec![0; 16];
+        reader.read_exact(&mut rgbUid)?;
+        let tag = reader.read_u16::<LittleEndian>()?;
+        let size = reader.read_u32::<LittleEndian>()?;
+        let cRef = reader.read_u32::<LittleEndian>()?;
+        let foDelay = reader.read_u32::<LittleEndian>()?;
+        let _unused1 = reader.read_u8()?;
+        let cbName = reader.read_u8()?;
+        let _unused2 = reader.read_u8()?;
+        let _unused3 = reader.read_u8()?;
+
+        let mut name_buffer = vec![0; cbName as usize / 2];
+        reader.read_u16_into::<LittleEndian>(&mut name_buffer)?;
+        let nameData = String::from_utf16_lossy(&name_buffer)
+            .trim_end_matches('\0')
+            .to_string();
+
+        Ok(OfficeArtFBSE {
+            btWin32,
+            btMacOS,
+            rgbUid: Bytes::new(rgbUid),
+            tag,
+            size,
+            cRef,
+            foDelay,
+            cbName,
+            nameData,
+        })
+    }
+}
+
+impl FromReader for PICF {
+    #[allow(non_snake_case)]
+    fn from_reader<R: Read + Seek>(reader: &mut R) -> io::Result<Self> {
+        let lcb = reader.read_u32::<LittleEndian>()?;
+        let cbHeader = reader.read_u16::<LittleEndian>()?;
+
+        // mfpf
+        let mm = reader.read_u16::<LittleEndian>()?;
+        let xExt = reader.read_u16::<LittleEndian>()?;
+        let yExt = reader.read_u16::<LittleEndian>()?;
+        let swHMF = reader.read_u16::<LittleEndian>()?;
+
+        // innerHeader is unused
+        reader.seek(SeekFrom::Current(14))?;
+
+        // picmid
+        let dxaGoal = reader.read_i16::<LittleEndian>()?;
+        let dyaGoal = reader.read_i16::<LittleEndian>()?;
+        let mx = reader.read_u16::<LittleEndian>()?;
+        let my = reader.read_u16::<LittleEndian>()?;
+        // reserved fields
+        reader.seek(SeekFrom::Current(9))?;
+        let bpp = reader.read_u8()?;
+        // brc80 borders and reserved fields
+        reader.seek(SeekFrom::Current(20))?;
+
+        let cProps = reader.read_u16::<LittleEndian>()?;
+
+        // MM_SHAPEFILE, the picture is linked and its file name follows
+        let stPicName = if mm == 0x0066 {
+            let length_byte = reader.read_u8()?;
+            let mut name_buffer = vec![0; length_byte as usize];
+            reader.read_exact(&mut name_buffer)?;
+            Some(name_buffer.iter().map(|&c| c as char).collect())
+        } else {
+            None
+        };
+
+        Ok(PICF {
+            lcb,
+            cbHeader,
+            mm,
+            xExt,
+            yExt,
+            swHMF,
+            dxaGoal,
+            dyaGoal,
+            mx,
+            my,
+            bpp,
+            cProps,
+            stPicName,
+        })
+    }
+}
+
+/// Reads the Prls of a grpprl, the size of each operand is given by the sprm
+pub fn grpprl_from_bytes(grpprl: &[u8]) -> Vec<Prl> {
+    let mut prls = vec![];
+    let mut offset = 0;
+
+    while offset + 2 <= grpprl.len() {
+        let sprm = u16::from_le_bytes([grpprl[offset], grpprl[offset + 1]]);
+        offset += 2;
+
+        let operand_len = match sprm >> 13 {
+            0 | 1 => 1,
+            2 | 4 | 5 => 2,
+            3 => 4,
+            7 => 3,
+            // sprmTDefTable stores its size in 2 bytes, incremented by 1
+            _ if sprm == 0xD608 && offset + 2 <= grpprl.len() => {
+                let cb = u16::from_le_bytes([grpprl[offset], grpprl[offset + 1]]) as usize;
+                cb + 1
+            }
+            _ => match grpprl.get(offset) {
+                Some(cb) => *cb as usize + 1,
+                None => break,
+            },
+        };
+
+        let end = (offset + operand_len).min(grpprl.len());
+        prls.push(Prl {
+            sprm,
+            operand: grpprl[offset..end].to_vec(),
+        });
+        offset = end;
+    }
+
+    prls
+}
+
+/// Reads the CHPXs of a 512 byte ChpxFkp page
+pub fn chpx_fkp_from_bytes(page: &[u8]) -> Vec<Chpx> {
+    let crun = page[511] as usize;
+    let fc = |index: usize| {
+        let offset = index * 4;
+        i32::from_le_bytes([
+            page[offset],
+            page[offset + 1],
+            page[offset + 2],
+            page[offset + 3],
+        ])
+    };
+
+    (0..crun)
+        .map(|index| {
+            // an offset of 0 means the run has no properties
+            let offset = page[4 * (crun + 1) + index] as usize * 2;
+            let grpprl = if offset == 0 {
+                vec![]
+            } else {
+                let cb = page[offset] as usize;
+                grpprl_from_bytes(&page[offset + 1..(offset + 1 + cb).min(511)])
+            };
+
+            Chpx {
+                fc_start: fc(index),
+                fc_end: fc(index + 1),
+                grpprl,
+            }
+        })
+        .collect()
+}
+
+/// Reads the PAPXs of a 512 byte PapxFkp page, whose BX entries of 13 bytes point at the
+/// PapxInFkp of every run
+pub fn papx_fkp_from_bytes(page: &[u8]) -> Vec<Papx> {
+    let crun = page[511] as usize;
+    let fc = |index: usize| {
+        let offset = index * 4;
+        i32::from_le_bytes([
+            page[offset],
+            page[offset + 1],
+            page[offset + 2],
+            page[offset + 3],
+        ])
+    };
+
+    (0..crun)
+        .map(|index| {
+            let offset = page[4 * (crun + 1) + 13 * index] as usize * 2;
+            // a cb of 0 is followed by the real cb, the GrpPrlAndIstd is then 2 * cb bytes long
+            let (start, len) = match page[offset] {
+                _ if offset == 0 => (0, 0),
+                0 => (offset + 2, page[offset + 1] as usize * 2),
+                cb => (offset + 1, cb as usize * 2 - 1),
+            };
+            let grpprl_and_istd = page.get(start..(start + len).min(511)).unwrap_or_default();
+            let istd = grpprl_and_istd
+                .get(0..2)
+                .map(|istd| u16::from_le_bytes([istd[0], istd[1]]))
+                .unwrap_or_default();
+
+            Papx {
+                fc_start: fc(index),
+                fc_end: fc(index + 1),
+                istd,
+                grpprl: grpprl_from_bytes(grpprl_and_istd.get(2..).unwrap_or_default()),
+            }
+        })
+        .collect()
+}
+
+/// Reads the OfficeArtContent at `fcDggInfo`, `end` is `fcDggInfo + lcbDggInfo`
+pub fn office_art_content_from_read_impl<R: Read + Seek>(
+    reader: &mut R,
+    end: u64,
+) -> io::Result<OfficeArtContent> {
+    let dgg_container = OfficeArtRecord::from_reader(reader)?;
+
+    let mut drawings = vec![];
+    while reader.stream_position()? + 9 <= end {
+        let dgglbl = reader.read_u8()?;
+        let container = OfficeArtRecord::from_reader(reader)?;
+        drawings.push(OfficeArtWordDrawing { dgglbl, container });
+    }
+
+    Ok(OfficeArtContent {
+        dgg_container,
+        drawings,
+    })
+}
+
+impl FromReader for OfficeArtRecord {
+    /// Reads a record and, for a container, the records in it
+    #[allow(non_snake_case)]
+    fn from_reader<R: Read + Seek>(reader: &mut R) -> io::Result<Self> {
+        let offset = reader.stream_position()?;
+        let header = OfficeArtRecordHeader::from_reader(reader)?;
+        let end = reader.stream_position()? + header.recLen as u64;
+
+        let mut children = vec![];
+        let mut properties = vec![];
+        let mut spid = None;
+        let mut grfPersistent = None;
+        let mut clientTextbox = None;
+        match header.recType {
+            _ if header.recVer == 0xF => {
+                while reader.stream_position()? + 8 <= end {
+                    children.push(OfficeArtRecord::from_reader(reader)?);
+                }
+            }
+            // OfficeArtFOPT, OfficeArtSecondaryFOPT and OfficeArtTertiaryFOPT
+            0xF00B | 0xF121 | 0xF122 => {
+                properties = fopt_from_read_impl(reader, header.recInstance, end)?;
+            }
+            // OfficeArtFSP
+            0xF00A => {
+                spid = Some(reader.read_u32::<LittleEndian>()?);
+                grfPersistent = Some(reader.read_u32::<LittleEndian>()?);
+            }
+            // OfficeArtClientTextbox
+            0xF00D if header.recLen >= 4 => {
+                clientTextbox = Some(reader.read_u32::<LittleEndian>()?);
+            }
+            _ => {}
+        }
+        reader.seek(SeekFrom::Start(end))?;
+
+        Ok(OfficeArtRecord {
+            offset,
+            header,
+            children,
+            properties,
+            spid,
+            grfPersistent,
+            clientTextbox,
+        })
+    }
+}
+
+/// Reads the `count` properties of a property table and the complex data that follows them,
+/// the complex data is cut off at `end`
+#[allow(non_snake_case)]
+pub fn fopt_from_read_impl<R: Read + Seek>(
+    reader: &mut R,
+    count: u16,
+    end: u64,
+) -> io::Result<Vec<OfficeArtFOPTE>> {
+    let mut properties = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let opid = reader.read_u16::<LittleEndian>()?;
+        let op = reader.read_i32::<LittleEndian>()?;
+        properties.push(OfficeArtFOPTE {
+            pid: opid & 0x3FFF,
+            fBid: opid & 0x4000 == 0x4000,
+            fComplex: opid & 0x8000 == 0x8000,
+            op,
+            complexData: None,
+        });
+    }
+
+    // the complex data is stored in the same order as the properties
+    for property in properties.iter_mut().filter(|property| property.fComplex) {
+        let left = end.saturating_sub(reader.stream_position()?);
+        let mut complexData = vec![0; (property.op.max(0) as u64).min(left) as usize];
+        reader.read_exact(&mut complexData)?;
+        property.complexData = Some(Bytes::new(complexData));
+    }
+
+    Ok(properties)
+}
+
+impl FromReader for CompObjStream {
+    /// Reads the ANSI part of the stream, the Unicode strings that may follow repeat it
+    #[allow(non_snake_case)]
+    fn from_reader<R: Read + Seek>(reader: &mut R) -> io::Result<Self> {
+        // reserved, version and reserved
+        reader.seek(SeekFrom::Current(28))?;
+
+        let AnsiUserType = length_prefixed_ansi_string(reader)?;
+        let AnsiClipboardFormat = match reader.read_u32::<LittleEndian>()? {
+            0 => None,
+            // a standard clipboard format, e.g. 3 for CF_METAFILEPICT
+            0xFFFF_FFFF | 0xFFFF_FFFE => Some(format!("{:#X}", reader.read_u32::<LittleEndian>()?)),
+            length => Some(ansi_string(&read_length(reader, length)?)),
+        };
+        let Reserved1 = Some(length_prefixed_ansi_string(reader)?).filter(|s| !s.is_empty());
+
+        Ok(CompObjStream {
+            AnsiUserType,
+            AnsiClipboardFormat,
+            Reserved1,
+        })
+    }
+}
+
+/// Reads a 32-bit length followed by a null terminated ANSI string
+fn length_prefixed_ansi_string<R: Read>(reader: &mut R) -> io::Result<String> {
+    let length = reader.read_u32::<LittleEndian>()?;
+    Ok(ansi_string(&read_length(reader, length)?))
+}
+
+/// Reads `length` bytes without trusting `length` for the allocation
+fn read_length<R: Read>(reader: &mut R, length: u32) -> io::Result<Vec<u8>> {
+    let mut buffer = vec![];
+    reader.take(length as u64).read_to_end(&mut buffer)?;
+    if buffer.len() != length as usize {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    Ok(buffer)
+}
+
+/// ANSI bytes up to the first null as a string
+fn ansi_string(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .take_while(|&&byte| byte != 0)
+        .map(|&byte| byte as char)
+        .collect()
+}
+
+/// Splits the Ole10Native stream of an OLE 1.0 Package into its label, the original path of the
+/// file and the file. Returns `None` if the stream is not a Package
+pub fn ole10_native_package_from_bytes(native: &[u8]) -> Option<(String, String, Vec<u8>)> {
+    ole10_native_package_from_read_impl(&mut Cursor::new(native)).ok()
+}
+
+fn ole10_native_package_from_read_impl<R: BufRead + Seek>(
+    reader: &mut R,
+) -> io::Result<(String, String, Vec<u8>)> {
+    let read_c_string = |reader: &mut R| -> io::Result<String> {
+        let mut bytes = vec![];
+        reader.read_until(0, &mut bytes)?;
+        Ok(ansi_string(&bytes))
+    };
+
+    let _native_data_size = reader.read_u32::<LittleEndian>()?;
+    if reader.read_u16::<LittleEndian>()? != 2 {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "not a Package"));
+    }
+    let label = read_c_string(reader)?;
+    let path = read_c_string(reader)?;
+    // reserved and the temporary path the file was extracted to
+    let _reserved = reader.read_u32::<LittleEndian>()?;
+    let temp_path_len = reader.read_u32::<LittleEndian>()?;
+    reader.seek(SeekFrom::Current(temp_path_len as i64))?;
+    let data_size = reader.read_u32::<LittleEndian>()?;
+    let data = read_length(reader, data_size)?;
+
+    Ok((label, path, data))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// The bytes of a FIB with `cfclcb` zeroed FC/LCB pairs and the given array of new shorts
+    pub(crate) fn fib_bytes(n_fib: u16, cfclcb: u16, rg_csw_new: &[u16]) -> Vec<u8> {
+        let mut bytes = vec![0; 0x20];
+        bytes[0..2].copy_from_slice(&0xA5ECu16.to_le_bytes());
+        bytes[2..4].copy_from_slice(&n_fib.to_le_bytes());
+        bytes.extend(14u16.to_le_bytes());
+        bytes.extend([0; 14 * 2]);
+        bytes.extend(22u16.to_le_bytes());
+        bytes.extend([0; 22 * 4]);
+        bytes.extend(cfclcb.to_le_bytes());
+        bytes.extend(vec![0; cfclcb as usize * 8]);
+        bytes.extend((rg_csw_new.len() as u16).to_le_bytes());
+        for value in rg_csw_new {
+            bytes.extend(value.to_le_bytes());
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_version_aware_fib() {
+        let word_97 = fib_bytes(0xC1, 0x5D, &[]);
+        let (fib, pairs) = fib_from_read_impl(&mut Cursor::new(word_97)).unwrap();
+        assert!(fib.fibRgFcLcb2000.is_none());
+        assert!(fib.fibRgFcLcb2007.is_none());
+        assert_eq!(fib.nFibNew, None);
+        assert_eq!(fib.effective_nfib(), 0xC1);
+        // every pair but the FILETIME of the last modification
+        assert_eq!(pairs.len(), 0x5D - 1);
+        let blocks = fib
+            .blocks()
+            .into_iter()
+            .map(|(name, _, start, end)| (name, start, end))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            blocks,
+            vec![
+                ("FibBase", 0, 0x20),
+                ("FibRgW97", 0x20, 0x3E),
+                ("FibRgLw97", 0x3E, 0x98),
+                ("FibRgFcLcb97", 0x98, 0x382),
+                ("FibRgCswNew", 0x382, 0x384),
+            ]
+        );
+
+        let word_2007 = fib_bytes(0xC1, 0xB7, &[0x0112, 3, 0x0409, 0x0411, 0x0401]);
+        let (fib, pairs) = fib_from_read_impl(&mut Cursor::new(word_2007)).unwrap();
+        assert!(fib.fibRgFcLcb2000.is_some());
+        assert!(fib.fibRgFcLcb2002.is_some());
+        assert!(fib.fibRgFcLcb2003.is_some());
+        assert!(fib.fibRgFcLcb2007.is_some());
+        assert_eq!(fib.effective_nfib(), 0x0112);
+        assert_eq!(fib.cQuickSavesNew, Some(3));
+        assert_eq!(fib.lidThemeCS, Some(0x0401));
+        assert_eq!(pairs.len(), 0xB7 - 1);
+        let blocks = fib.blocks();
+        assert_eq!(blocks[4].0, "FibRgFcLcb2000");
+        assert_eq!(blocks[7].0, "FibRgFcLcb2007");
+        assert_eq!((blocks[7].2, blocks[7].3), (0x5BA, 0x652));
+        assert_eq!(blocks[8], ("FibRgCswNew", blocks[8].1, 0x652, 0x65E));
+
+        // a block that is cut short is not read, its pairs are described as unknown
+        let partial = fib_bytes(0xD9, 0x70, &[]);
+        let (fib, _) = fib_from_read_impl(&mut Cursor::new(partial)).unwrap();
+        assert!(fib.fibRgFcLcb2000.is_some());
+        assert!(fib.fibRgFcLcb2002.is_none());
+        let unknown = fib.blocks()[5];
+        assert_eq!(
+            (unknown.0, unknown.2, unknown.3),
+            ("FibRgFcLcb unknown", 0x3FA, 0x41A)
+        );
+    }
+
+    #[test]
+    fn test_word_6_fib_and_stylesheet() {
+        let mut word_6 = vec![0; 0x2AA];
+        word_6[0..2].copy_from_slice(&0xA5DCu16.to_le_bytes());
+        word_6[2..4].copy_from_slice(&104u16.to_le_bytes());
+        word_6[0x18..0x1C].copy_from_slice(&0x300i32.to_le_bytes());
+        // fcStshf/lcbStshf and fcClx/lcbClx
+        word_6[0x60..0x68].copy_from_slice(&[0x00, 0x04, 0, 0, 0x20, 0, 0, 0]);
+        word_6[0x160..0x168].copy_from_slice(&[0x40, 0x04, 0, 0, 0x10, 0, 0, 0]);
+        let (fib6, pairs) = fib6_from_read_impl(&mut Cursor::new(word_6)).unwrap();
+        assert!(NFIB_WORD_6.contains(&fib6.nFib));
+        assert_eq!(pairs.len(), 38 + 35);
+        assert_eq!(pairs[1].0..pairs[1].0 + pairs[1].1 as i32, 0x400..0x420);
+        assert_eq!(fib6.blocks().last().unwrap().3, 0x2AA);
+
+        let fib = Fib::from(&fib6);
+        assert_eq!(fib.fcMin, 0x300);
+        assert_eq!((fib.fcStshf, fib.lcbStshf), (0x400, 0x20));
+        assert_eq!((fib.fcClx, fib.lcbClx), (0x440, 0x10));
+
+        // a Word 6 stylesheet with a single ftc, an 8 byte STD base and an 8-bit style name
+        let mut stsh = vec![14, 0, 1, 0, 8, 0];
+        stsh.extend([0; 10]);
+        stsh.extend([16, 0]);
+        stsh.extend([0; 8]);
+        stsh.push(6);
+        stsh.extend(b"Normal\0");
+        let stylesheet =
+            shshi_from_read_impl(&mut BufReader::new(Cursor::new(stsh)), true).unwrap();
+        assert_eq!(stylesheet.rgftcStandardChpStsh.len(), 1);
+        assert_eq!(stylesheet.cbLSD, None);
+        assert_eq!(stylesheet.styles[0].xstzName, "Normal");
+        assert_eq!(stylesheet.styles[0].fHidden, None);
+        assert_eq!(stylesheet.styles[0].rsid, None);
+    }
+}
@@ -1,24 +1,46 @@
 use cfb::CompoundFile;
-use from_reader::{fib_from_read_impl, FromReader};
-use json::JsonValue;
+pub use docx::{docx_rsids, is_zip};
+use from_reader::{fib6_from_read_impl, fib_from_read_impl, shshi_from_read_impl, FromReader};
 pub use model::*;
+pub use revisions::rsid_overlap;
+use schemars::JsonSchema;
+use serde::Serialize;
+use serde_json::Value;
 use std::{
     cell::RefCell,
     fs::File,
-    io::{self, BufRead, BufReader, Cursor, Read, Seek, SeekFrom},
+    io::{self, BufRead, BufReader, Cursor, Read, Seek, SeekFrom, Write},
     vec,
 };
-
+use struct_iterable::Iterable;
+
+mod bookmarks;
+mod compound_file;
+mod coverage;
+mod crypto;
+mod docx;
+mod fields;
 mod from_c_struct;
 mod from_reader;
 mod model;
+mod objects;
+mod office_art;
+mod pictures;
+mod residual;
+mod revisions;
+mod stories;
+mod sttb;
 mod to_structure;
+mod validate;
+mod vba;
 
 #[allow(unused)]
 #[derive(Debug)]
 pub struct WordDocument {
-    pub cfb: RefCell<CompoundFile<File>>,
+    pub cfb: RefCell<CompoundFile<Cursor<Vec<u8>>>>,
     pub fib: Fib,
+    /// The FIB as stored in Word 6.0/95 files, `fib` then holds the fields shared with Word 97
+    pub fib6: Option<Fib6>,
     pub text: Text,
     pub stylesheet: SHSHI,
     pub piece_table: PLCF<PCD>,
@@ -26,14 +48,130 @@ pub struct WordDocument {
     pub table_stream_name: String,
     pub document_summary_information_stream: DocumentSummaryInfoStream,
     pub summary_information: SummaryInformation,
+    /// How the document was protected, if it was encrypted
+    pub encryption: Option<Encryption>,
+    /// Pictures of the Data stream and the BLIP store
+    pub pictures: Vec<Picture>,
+    /// The header document split into the note separators and the headers and footers of every
+    /// section
+    pub header_stories: Vec<HeaderStory>,
+    /// The footnotes followed by the endnotes, with their references in the main document
+    pub notes: Vec<Note>,
+    /// The comments with their authors and the ranges of the main document they comment on
+    pub annotations: Vec<Annotation>,
+    /// The bookmarks of the main document with their CP ranges
+    pub bookmarks: Vec<Bookmark>,
+    /// The fields of every subdocument, nested fields are the children of their field
+    pub fields: Vec<Field>,
+    /// The tracked changes with the revision settings and authors
+    pub revisions: Revisions,
+    /// The rsids of the editing sessions and the runs changed in them
+    pub rsids: Rsids,
+    /// The last saves of the SttbSavedBy and the strings of the SttbfAssoc
+    pub save_history: SaveHistory,
+    /// The OfficeArt drawing group and drawings at `fcDggInfo`
+    pub office_art: Option<OfficeArtContent>,
+    /// The shapes anchored in the main document and the headers
+    pub shapes: Vec<Shape>,
+    /// The objects embedded in the ObjectPool
+    pub objects: Vec<EmbeddedObject>,
+    /// The VBA project of the Macros storage, if the document has macros
+    pub vba_project: Option<VbaProject>,
+    /// The entries of the directory of the compound file, by stream identifier
+    pub directory: Vec<DirectoryEntry>,
+    /// Text in the bytes that neither the piece table nor a stream refers to
+    pub residual_text: Vec<ResidualText>,
     fc_lb_pairs: Vec<(i32, u32, String)>,
+    /// The regions of the compound file itself, beneath its streams
+    container: Vec<PhysicalStructure>,
+    /// Errors of the optional parts of the document that could not be decoded
+    decode_diagnostics: Vec<Diagnostic>,
 }
 
 // endregion: Structs
 
+/// The decoded optional part of a document, or its default if it could not be decoded. A broken
+/// part does not keep the rest of the document from being decoded, its error is reported in
+/// `diagnostics` instead
+fn decoded_or_default<T: Default>(
+    diagnostics: &mut Vec<Diagnostic>,
+    part: &str,
+    result: io::Result<T>,
+) -> T {
+    result.unwrap_or_else(|err| {
+        diagnostics.push(Diagnostic::new(
+            Severity::Error,
+            "decode_failed",
+            format!("the {part} could not be decoded: {err}"),
+        ));
+        T::default()
+    })
+}
+
+/// Reads the FIB of the Word 97 or later layout, or the Word 6.0/95 layout as told by `n_fib`
+fn read_fib<R: Read + Seek>(
+    reader: &mut R,
+    n_fib: u16,
+) -> io::Result<(Fib, Option<Fib6>, from_reader::FcLcbPairs)> {
+    if NFIB_WORD_6.contains(&n_fib) {
+        let (fib6, fc_lb_pairs) = fib6_from_read_impl(reader)?;
+        Ok((Fib::from(&fib6), Some(fib6), fc_lb_pairs))
+    } else {
+        let (fib, fc_lb_pairs) = fib_from_read_impl(reader)?;
+        Ok((fib, None, fc_lb_pairs))
+    }
+}
+
 impl WordDocument {
-    pub fn read_file(file: File) -> io::Result<Self> {
-        let mut cfb = CompoundFile::open(file)?;
+    pub fn read_file(mut file: File) -> io::Result<Self> {
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)?;
+        Self::read_bytes(bytes)
+    }
+
+    /// Decodes a Word document that is already held in memory, e.g. an upload
+    pub fn read_bytes(bytes: Vec<u8>) -> io::Result<Self> {
+        Self::read_bytes_with_password(bytes, None)
+    }
+
+    /// Decodes a Word document held in memory, decrypting it with `password` first if it is
+    /// encrypted. Without a password only the parts of an encrypted document that are never
+    /// encrypted are decoded, see `needs_password`
+    pub fn read_bytes_with_password(bytes: Vec<u8>, password: Option<&str>) -> io::Result<Self> {
+        let mut cfb = CompoundFile::open(Cursor::new(bytes))?;
+        let encryption = match crypto::read_encryption(&mut cfb)? {
+            Some(mut encryption) => {
+                let Some(password) = password else {
+                    return Self::read_encrypted(cfb, encryption);
+                };
+                crypto::decrypt(&mut cfb, &encryption, password)?;
+                encryption.decrypted = true;
+                Some(encryption)
+            }
+            None => None,
+        };
+
+        // cfb hides the raw directory and sectors, they are read from the bytes of the decrypted
+        // file so that the sectors and the residual text hold no encrypted bytes
+        cfb.flush()?;
+        let bytes = cfb.into_inner().into_inner();
+        let directory = compound_file::read_directory(&bytes);
+        let container = compound_file::container_structures(&bytes);
+        let mut cfb = CompoundFile::open(Cursor::new(bytes))?;
+
+        // The optional parts of the document are left empty when they can not be decoded
+        let mut decode_diagnostics = vec![];
+        let directory = decoded_or_default(
+            &mut decode_diagnostics,
+            "directory of the compound file",
+            directory,
+        );
+        let container = decoded_or_default(
+            &mut decode_diagnostics,
+            "sectors of the compound file",
+            container,
+        );
+
         let mut word_doc_stream = cfb.open_stream("WordDocument")?;
 
         let document_summary_information_stream = {
@@ -86,7 +224,14 @@ impl WordDocument {
             summary_info
         };
 
-        let (fib, fc_lb_pairs) = fib_from_read_impl(&mut word_doc_stream)?;
+        let n_fib = {
+            let mut n_fib = [0u8; 2];
+            word_doc_stream.seek(SeekFrom::Start(2))?;
+            word_doc_stream.read_exact(&mut n_fib)?;
+            u16::from_le_bytes(n_fib)
+        };
+
+        let (fib, fib6, fc_lb_pairs) = read_fib(&mut word_doc_stream, n_fib)?;
 
         let text = {
             // does not account for fib.fComplex (complex / fast-saved) file
@@ -152,7 +297,10 @@ impl WordDocument {
         };
 
         // Determine which table stream to use
-        let table_stream_name = if !fib.fWhichTblStm {
+        // Word 6.0/95 files keep everything in the WordDocument stream
+        let table_stream_name = if fib6.is_some() {
+            "WordDocument"
+        } else if !fib.fWhichTblStm {
             "0Table"
         } else {
             "1Table"
@@ -160,7 +308,16 @@ impl WordDocument {
         let mut table_stream = cfb.open_stream(table_stream_name)?;
 
         // Read the complex part of the document
-        let (_grppls, piece_table) = {
+        let (_grppls, piece_table) = if fib.lcbClx == 0 {
+            // non complex Word 6.0/95 files have no piece table, the text is stored in one piece
+            (
+                vec![],
+                PLCF {
+                    rgfc: vec![],
+                    rgstruct: vec![],
+                },
+            )
+        } else {
             let mut complex_buff = vec![0; fib.lcbClx as usize];
             table_stream.seek(SeekFrom::Start(fib.fcClx as u64))?;
             table_stream.read_exact(&mut complex_buff)?;
@@ -181,7 +338,7 @@ impl WordDocument {
             table_stream.read_exact(&mut stsh_buffer)?;
 
             let mut stsh_buffer = BufReader::new(Cursor::new(stsh_buffer));
-            let stylesheet = SHSHI::from_reader(&mut stsh_buffer)?;
+            let stylesheet = shshi_from_read_impl(&mut stsh_buffer, fib6.is_some())?;
 
             let left_bytes = stsh_buffer.fill_buf()?;
             assert_eq!(left_bytes.len(), 0);
@@ -248,12 +405,6 @@ impl WordDocument {
                 let mut list_table_buffer = vec![0; distance_to_plf_lfo as usize];
                 table_stream.seek(SeekFrom::Start(fib.fcPlcfLst as u64))?;
                 table_stream.read_exact(&mut list_table_buffer)?;
-                dbg!(fib.fcPlcfLst);
-                dbg!(fib.lcbPlcfLst);
-                println!(
-                    "bytes between fcPlcfLst and fcPlfLfo: {}",
-                    fib.fcPlfLfo - fib.fcPlcfLst
-                );
                 // println!("bytes remaining in table stream: {}", table_stream.bytes().count());
 
                 let mut list_table_buffer = BufReader::new(Cursor::new(list_table_buffer));
@@ -281,9 +432,147 @@ impl WordDocument {
             // );
         }
 
+        // Split the header document into its stories
+        let header_stories =
+            stories::read_header_stories(&mut table_stream, &fib, &text.header_text);
+        let header_stories =
+            decoded_or_default(&mut decode_diagnostics, "header stories", header_stories);
+
+        // Pair the footnotes and endnotes with their references
+        let footnotes = stories::read_notes(
+            &mut table_stream,
+            "footnote",
+            (fib.fcPlcffndRef, fib.lcbPlcffndRef),
+            (fib.fcPlcffndTxt, fib.lcbPlcffndTxt),
+            &text.main_text,
+            &text.footnote_text,
+        );
+        let endnotes = stories::read_notes(
+            &mut table_stream,
+            "endnote",
+            (fib.fcPlcfendRef, fib.lcbPlcfendRef),
+            (fib.fcPlcfendTxt, fib.lcbPlcfendTxt),
+            &text.main_text,
+            &text.endnote_text,
+        );
+        let mut notes = decoded_or_default(&mut decode_diagnostics, "footnotes", footnotes);
+        notes.extend(decoded_or_default(
+            &mut decode_diagnostics,
+            "endnotes",
+            endnotes,
+        ));
+
+        // Read the comments with their authors and commented ranges
+        // Word 6.0/95 files store the initials of the authors as 8-bit strings
+        let annotations = if fib6.is_some() {
+            vec![]
+        } else {
+            let annotations = stories::read_annotations(
+                &mut table_stream,
+                &fib,
+                &text.main_text,
+                &text.annotation_text,
+            );
+            decoded_or_default(&mut decode_diagnostics, "comments", annotations)
+        };
+
+        // Resolve the bookmarks to their names and CP ranges
+        // Word 6.0/95 files store the names in an STTBF of 8-bit strings
+        let bookmarks = if fib6.is_some() {
+            vec![]
+        } else {
+            let bookmarks = bookmarks::read_bookmarks(&mut table_stream, &fib, &text.main_text);
+            decoded_or_default(&mut decode_diagnostics, "bookmarks", bookmarks)
+        };
+
+        // Build the fields of every subdocument from its PlcFld
+        let fields = fields::read_fields(&mut table_stream, &fib, &text);
+        let fields = decoded_or_default(&mut decode_diagnostics, "fields", fields);
+
+        // Read who saved the document where, and the template and strings associated with it
+        let save_history = sttb::read_save_history(&mut table_stream, &fib, fib6.is_some());
+        let save_history =
+            decoded_or_default(&mut decode_diagnostics, "save history", save_history);
+
+        // Read the OfficeArt drawings and the shapes anchored in the text
+        // Word 6.0/95 files store drawing objects in the Plcfdoa instead
+        let (office_art, shapes) = if fib6.is_some() {
+            (None, vec![])
+        } else {
+            let office_art = office_art::read_office_art(&mut table_stream, &fib);
+            let shapes = office_art::read_shapes(&mut table_stream, &fib, &text);
+            (
+                decoded_or_default(&mut decode_diagnostics, "OfficeArt drawings", office_art),
+                decoded_or_default(&mut decode_diagnostics, "shapes", shapes),
+            )
+        };
+
+        // Read the pictures of the Data stream and the BLIP store
+        drop(word_doc_stream);
+        drop(table_stream);
+        let pictures = if fib6.is_some() {
+            // Word 6.0/95 pictures are not stored as PICFAndOfficeArtData
+            vec![]
+        } else {
+            let pictures =
+                pictures::read_pictures(&mut cfb, &fib, table_stream_name, &mut decode_diagnostics);
+            decoded_or_default(&mut decode_diagnostics, "pictures", pictures)
+        };
+
+        // Read the objects embedded in the ObjectPool
+        let objects = if fib6.is_some() {
+            vec![]
+        } else {
+            let objects = objects::read_embedded_objects(
+                &mut cfb,
+                &fib,
+                table_stream_name,
+                &piece_table,
+                &text.main_text,
+            );
+            decoded_or_default(&mut decode_diagnostics, "embedded objects", objects)
+        };
+
+        // Read the tracked changes from the revision marks of the character and paragraph runs,
+        // and the rsids of the sessions that changed them
+        // Word 6.0/95 files use sprms of a single byte
+        let (revisions, rsids) = if fib6.is_some() {
+            (Revisions::default(), Rsids::default())
+        } else {
+            let revisions =
+                revisions::read_revisions(&mut cfb, &fib, table_stream_name, &piece_table, &text);
+            let rsids =
+                revisions::read_rsids(&mut cfb, &fib, table_stream_name, &piece_table, &stylesheet);
+            (
+                decoded_or_default(&mut decode_diagnostics, "revisions", revisions),
+                decoded_or_default(&mut decode_diagnostics, "rsids", rsids),
+            )
+        };
+
+        // Read the VBA project of the Macros storage
+        let vba_project = vba::read_vba_project(&mut cfb);
+        let vba_project = decoded_or_default(&mut decode_diagnostics, "VBA project", vba_project);
+
+        // Scan the bytes the document no longer refers to for text
+        let fib_blocks = match &fib6 {
+            Some(fib6) => fib6.blocks(),
+            None => fib.blocks(),
+        };
+        let fib_end = fib_blocks.iter().map(|(_, _, _, end)| *end).max();
+        let residual_text = residual::read_residual_text(
+            &mut cfb,
+            &fib,
+            fib_end.unwrap_or_default(),
+            &piece_table,
+            &container,
+        );
+        let residual_text =
+            decoded_or_default(&mut decode_diagnostics, "residual text", residual_text);
+
         Ok(WordDocument {
             cfb: RefCell::new(cfb),
             fib,
+            fib6,
             text,
             stylesheet,
             piece_table,
@@ -291,12 +580,116 @@ impl WordDocument {
             list_tables,
             document_summary_information_stream,
             summary_information,
+            encryption,
+            pictures,
+            header_stories,
+            notes,
+            annotations,
+            bookmarks,
+            fields,
+            revisions,
+            rsids,
+            save_history,
+            office_art,
+            shapes,
+            objects,
+            vba_project,
+            directory,
+            residual_text,
             fc_lb_pairs,
+            container,
+            decode_diagnostics,
         })
     }
 
+    /// Reads what is known of an encrypted document without its password: the start of the FIB,
+    /// the encryption, the directory of the compound file and the VBA project, which are never
+    /// encrypted. The sectors are not scanned, their slack and free sectors hold encrypted bytes
+    fn read_encrypted(
+        cfb: CompoundFile<Cursor<Vec<u8>>>,
+        encryption: Encryption,
+    ) -> io::Result<Self> {
+        let mut decode_diagnostics = vec![Diagnostic::new(
+            Severity::Info,
+            "encrypted_sectors_not_scanned",
+            "the sectors of the compound file and the residual text are not scanned without a \
+             password"
+                .to_string(),
+        )];
+        let bytes = cfb.into_inner().into_inner();
+        let directory = compound_file::read_directory(&bytes);
+        let mut cfb = CompoundFile::open(Cursor::new(bytes))?;
+        let directory = decoded_or_default(
+            &mut decode_diagnostics,
+            "directory of the compound file",
+            directory,
+        );
+        let mut fib_stream = Cursor::new(crypto::unencrypted_fib(&mut cfb)?);
+        let n_fib = u16::from_le_bytes([fib_stream.get_ref()[2], fib_stream.get_ref()[3]]);
+        let (fib, fib6, _) = read_fib(&mut fib_stream, n_fib)?;
+        let table_stream_name = match (&fib6, fib.fWhichTblStm) {
+            (Some(_), _) => "WordDocument",
+            (None, false) => "0Table",
+            (None, true) => "1Table",
+        };
+        let vba_project = vba::read_vba_project(&mut cfb);
+        let vba_project = decoded_or_default(&mut decode_diagnostics, "VBA project", vba_project);
+
+        Ok(WordDocument {
+            cfb: RefCell::new(cfb),
+            fib,
+            fib6,
+            text: Text::default(),
+            stylesheet: SHSHI::default(),
+            piece_table: PLCF {
+                rgfc: vec![],
+                rgstruct: vec![],
+            },
+            list_tables: LSTs::default(),
+            table_stream_name: table_stream_name.to_string(),
+            document_summary_information_stream: DocumentSummaryInfoStream::default(),
+            summary_information: SummaryInformation::default(),
+            encryption: Some(encryption),
+            pictures: vec![],
+            header_stories: vec![],
+            notes: vec![],
+            annotations: vec![],
+            bookmarks: vec![],
+            fields: vec![],
+            revisions: Revisions::default(),
+            rsids: Rsids::default(),
+            save_history: SaveHistory::default(),
+            office_art: None,
+            shapes: vec![],
+            objects: vec![],
+            vba_project,
+            directory,
+            residual_text: vec![],
+            fc_lb_pairs: vec![],
+            container: vec![],
+            decode_diagnostics,
+        })
+    }
+
+    /// Whether the document is encrypted and was read without its password, so that only the
+    /// parts of it that are never encrypted are decoded
+    pub fn needs_password(&self) -> bool {
+        self.encryption
+            .as_ref()
+            .is_some_and(|encryption| !encryption.decrypted)
+    }
+
     pub fn get_logical_structures(&self) -> Vec<Structure> {
-        let fib = Structure::from("Fib", &self.fib);
+        let mut fib = match &self.fib6 {
+            Some(fib6) => Structure::from("Fib", fib6),
+            None => Structure::from("Fib", &self.fib),
+        };
+        if self.needs_password() {
+            // the FibBase, up to fcMac, is all of the FIB that is never encrypted
+            let fib_base_len = fib.structure.iter().position(|item| item.name == "fcMac");
+            fib.structure
+                .truncate(fib_base_len.map_or(0, |index| index + 1));
+        }
         let text = Structure::from("Text", &self.text);
         let stylesheet = Structure::from("StyleSheet", &self.stylesheet);
         let peice_tables = Structure::from("Piece Tables", &self.piece_table);
@@ -307,73 +700,119 @@ impl WordDocument {
         let summary_information_stream =
             Structure::from("Summary Information", &self.summary_information);
 
-        vec![
+        let pictures = Structure::from("Pictures", &self.pictures);
+        let office_art = match &self.office_art {
+            Some(office_art) => Structure::from("OfficeArt", office_art),
+            None => Structure {
+                name: "OfficeArt".to_string(),
+                structure: vec![],
+                substructs: Some(vec![]),
+            },
+        };
+        let shapes = Structure::from("Shapes", &self.shapes);
+        let objects = Structure::from("Embedded Objects", &self.objects);
+        let vba_project = match &self.vba_project {
+            Some(vba_project) => Structure::from("VBA Project", vba_project),
+            None => Structure {
+                name: "VBA Project".to_string(),
+                structure: vec![],
+                substructs: Some(vec![]),
+            },
+        };
+        let directory = Structure::from("Compound File Directory", &self.directory);
+        let residual_text = Structure::from("Residual Text", &self.residual_text);
+        let headers = Structure::from("Headers", &self.header_stories);
+        let notes = Structure::from("Notes", &self.notes);
+        let annotations = Structure::from("Annotations", &self.annotations);
+        let bookmarks = Structure::from("Bookmarks", &self.bookmarks);
+        let fields = Structure::from("Fields", &self.fields);
+        let revisions = Structure::from("Revisions", &self.revisions);
+        let rsids = Structure::from("Rsids", &self.rsids);
+        let save_history = Structure::from("Save History", &self.save_history);
+
+        // documents are compared structure by structure, so the optional ones come last
+        let mut structures = vec![
             fib,
             text,
             stylesheet,
             peice_tables,
             document_summary_information_stream,
             summary_information_stream,
-        ]
+            pictures,
+            office_art,
+            shapes,
+            objects,
+            vba_project,
+            directory,
+            residual_text,
+            headers,
+            notes,
+            annotations,
+            bookmarks,
+            fields,
+            revisions,
+            rsids,
+            save_history,
+        ];
+        if let Some(encryption) = &self.encryption {
+            structures.push(Structure::from("Encryption", encryption));
+        }
+        structures
     }
 
-    pub fn to_json_logical(&self) -> JsonValue {
-        JsonValue::from(self.get_logical_structures())
+    pub fn to_json_logical(&self) -> Value {
+        self.to_versioned_json(OutputKind::Logical, self.get_logical_structures())
     }
 
-    pub fn get_physical_sructures(&self) -> Vec<PhysicalStructure> {
+    pub fn get_physical_sructures(&self) -> io::Result<Vec<PhysicalStructure>> {
         let fib = &self.fib;
 
         let mut output = Vec::new();
-        let mut word_doc_stream = self.cfb.borrow_mut().open_stream("WordDocument").unwrap();
-
-        let fib_header_bytes =
-            PhysicalStructure::from_reader_range(&mut word_doc_stream, 0, 72, "WordDocument")
-                .description("Fib 1997 bytes")
-                .structure_name("Fib 1997 Header");
-        let next_fib_section =
-            PhysicalStructure::from_reader_range(&mut word_doc_stream, 72, 402, "WordDocument")
-                .description("variables ccpText - lcbWss of the Fib")
-                .structure_name("Fib 1997 cont.");
-        let next_fib_section_2 =
-            PhysicalStructure::from_reader_range(&mut word_doc_stream, 402, 898, "WordDocument")
-                .description("variables fcDop - lcbSttbfUser of the Fib. End of Word 97 Fib")
-                .structure_name("Fib 1997 cont.");
-        let word_2000_defs =
-            PhysicalStructure::from_reader_range(&mut word_doc_stream, 898, 1034, "WordDocument")
-                .description("Word 2000 definitions. Variables fcPlcTch - lcbPlcfpgp")
-                .structure_name("Fib 2000 ext.");
-        let word_2002_defs =
-            PhysicalStructure::from_reader_range(&mut word_doc_stream, 1034, 1242, "WordDocument")
-                .description("Word 2002 definitions. Variables fcPlcfuim - lcbPlcflvcMixedXP")
-                .structure_name("Fib 2002 ext.");
-        let word_2003_defs =
-            PhysicalStructure::from_reader_range(&mut word_doc_stream, 1242, 1258, "WordDocument")
-                .description("Word 2003 definitions. Variables fcHplxsdr - cQuickSavesNew")
-                .structure_name("Fib 2003 ext.");
-
-        output.extend(vec![
-            fib_header_bytes,
-            next_fib_section,
-            next_fib_section_2,
-            word_2000_defs,
-            word_2002_defs,
-            word_2003_defs,
-        ]); // Consider annotating these all as FIB as `(partial FIB)`
-
-        let mut table_stream = self
-            .cfb
-            .borrow_mut()
-            .open_stream(&self.table_stream_name)
-            .unwrap();
+        let mut word_doc_stream = self.cfb.borrow_mut().open_stream("WordDocument")?;
 
-        let text_section = PhysicalStructure::from_reader_range(
-            &mut word_doc_stream,
-            fib.fcMin as u64,
-            (fib.fcMin + fib.fcMac) as u64,
-            "WordDocument",
-        ).description("fib.fcMin - fib.fcMac");
-        output.push(text_section);
+        let mut fib_blocks = match &self.fib6 {
+            Some(fib6) => fib6.blocks(),
+            None => fib.blocks(),
+        };
+        if self.needs_password() {
+            fib_blocks.retain(|(structure_name, ..)| *structure_name == "FibBase");
+        }
+        for (structure_name, description, start, end) in fib_blocks {
+            let fib_block = PhysicalStructure::from_reader_range(
+                &mut word_doc_stream,
+                start,
+                end,
+                "WordDocument",
+            )?
+            .description(description)
+            .structure_name(structure_name);
+            output.push(fib_block);
+        }
+
+        let mut table_stream = self.cfb.borrow_mut().open_stream(&self.table_stream_name)?;
+
+        if let Some(encryption) = self.encryption.as_ref().filter(|e| e.cbHeader > 0) {
+            let encryption_header = PhysicalStructure::from_reader_range(
+                &mut table_stream,
+                0,
+                encryption.cbHeader as u64,
+                "Table Stream",
+            )?
+            .description("EncryptionHeader, not encrypted")
+            .structure_name("EncryptionHeader");
+            output.push(encryption_header);
+        }
+
+        if !self.needs_password() {
+            let text_section = PhysicalStructure::from_reader_range(
+                &mut word_doc_stream,
+                fib.fcMin as u64,
+                (fib.fcMin + fib.fcMac) as u64,
+                "WordDocument",
+            )?
+            .description("fib.fcMin - fib.fcMac");
+            output.push(text_section);
+        }
 
         // let main_text_section = PhysicalStructure::from_reader_range(
         //     &mut word_doc_stream,
@@ -384,38 +823,219 @@ impl WordDocument {
         // .description("first character to last character of the regular text section");
         // output.push(main_text_section);
 
+        // the fc's of Word 6.0/95 files point into the WordDocument stream
+        let pairs_stream_name = if self.fib6.is_some() {
+            "WordDocument"
+        } else {
+            "Table Stream"
+        };
+        // pairs that do not fit in the stream keep their range with the bytes that are in the
+        // stream, and the code of the diagnostic `get_diagnostics` reports for them
+        let table_stream_len = table_stream.len();
         let fc_lb_pairs = self
             .fc_lb_pairs
             .iter()
             .map(|(fc, lb, desc)| {
-                if *fc == -1 {
+                let (start, end) = (*fc as i64, *fc as i64 + *lb as i64);
+                let structure = if start < 0 {
                     PhysicalStructure {
-                        stream_name: "Table Stream".to_string(),
+                        stream_name: pairs_stream_name.to_string(),
                         structure_name: None,
                         bytes: vec![],
-                        start_index: *fc as i64,
-                        end_index: *lb as i64,
-                        description: Some(desc.to_string()),
+                        start_index: start,
+                        end_index: end,
+                        description: None,
+                        diagnostic: (*lb > 0).then(|| "fc_lcb_negative_fc".to_string()),
                     }
                 } else {
-                    PhysicalStructure::from_reader_range(
+                    let clamped = |index: i64| (index as u64).min(table_stream_len);
+                    let mut structure = PhysicalStructure::from_reader_range(
                         &mut table_stream,
-                        *fc as u64,
-                        (*fc as u32 + *lb) as u64,
-                        "Table Stream",
-                    )
-                    .description(desc)
-                }
+                        clamped(start),
+                        clamped(end),
+                        pairs_stream_name,
+                    )?;
+                    structure.start_index = start;
+                    structure.end_index = end;
+                    if *lb > 0 && end as u64 > table_stream_len {
+                        structure = structure.diagnostic("fc_lcb_out_of_bounds");
+                    }
+                    structure
+                };
+                Ok(structure.description(desc))
             })
-            .collect::<Vec<_>>();
+            .collect::<io::Result<Vec<_>>>()?;
         output.extend(fc_lb_pairs);
 
-        output
+        // the BLIPs embedded in the BLIP store are part of fcDggInfo
+        for picture in self
+            .pictures
+            .iter()
+            .filter(|picture| picture.stream_name != self.table_stream_name)
+        {
+            let mut stream = self.cfb.borrow_mut().open_stream(&picture.stream_name)?;
+            let (structure_name, description) = match picture.source.as_str() {
+                "Data" => (
+                    "PICFAndOfficeArtData",
+                    "PICF and the OfficeArt data of a picture",
+                ),
+                _ => ("OfficeArtBlip", "BLIP of the BLIP store at foDelay"),
+            };
+            let picture_structure = PhysicalStructure::from_reader_range(
+                &mut stream,
+                picture.offset,
+                picture.offset + picture.len,
+                &picture.stream_name,
+            )?
+            .description(description)
+            .structure_name(structure_name);
+            output.push(picture_structure);
+        }
+
+        // the OLE streams of the embedded objects, the payloads are described by the objects
+        for object in &self.objects {
+            for (stream, len) in &object.streams {
+                let (structure_name, description) = match stream.as_str() {
+                    "\x01CompObj" => ("CompObjStream", "user type, clipboard format and ProgID"),
+                    "\x01Ole" => ("OLEStream", "whether the object is linked or embedded"),
+                    "\x03ObjInfo" => ("ObjInfoStream", "ODT flags of the object"),
+                    "\x01Ole10Native" => ("Ole10Native", "native data of an OLE 1.0 object"),
+                    _ => continue,
+                };
+                let stream_name = format!("{}/{stream}", object.storage_path);
+                let mut stream = self.cfb.borrow_mut().open_stream(&stream_name)?;
+                let object_structure =
+                    PhysicalStructure::from_reader_range(&mut stream, 0, *len, &stream_name)?
+                        .description(description)
+                        .structure_name(structure_name);
+                output.push(object_structure);
+            }
+        }
+
+        // the dir stream and the modules of the VBA project, p-code before the source code
+        if let Some(vba_project) = &self.vba_project {
+            let dir_name = format!("{}/dir", vba_project.storage_path);
+            let mut dir = self.cfb.borrow_mut().open_stream(&dir_name)?;
+            let dir_len = dir.len();
+            let dir_structure =
+                PhysicalStructure::from_reader_range(&mut dir, 0, dir_len, &dir_name)?
+                    .description("compressed records of the VBA project and its modules")
+                    .structure_name("dir");
+            output.push(dir_structure);
+
+            for module in &vba_project.modules {
+                let stream_name = format!("{}/{}", vba_project.storage_path, module.stream_name);
+                let Ok(mut stream) = self.cfb.borrow_mut().open_stream(&stream_name) else {
+                    continue;
+                };
+                let stream_len = stream.len();
+                let text_offset = (module.text_offset as u64).min(stream_len);
+                let performance_cache = PhysicalStructure::from_reader_range(
+                    &mut stream,
+                    0,
+                    text_offset,
+                    &stream_name,
+                )?
+                .description("p-code and the other compiled data of the module")
+                .structure_name("PerformanceCache");
+                let source_code = PhysicalStructure::from_reader_range(
+                    &mut stream,
+                    text_offset,
+                    stream_len,
+                    &stream_name,
+                )?
+                .description("compressed source code of the module")
+                .structure_name("CompressedSourceCode");
+                output.push(performance_cache);
+                output.push(source_code);
+            }
+        }
+
+        // the header, sectors, slack and anomalies of the compound file
+        output.extend(self.container.iter().cloned());
+
+        Ok(output)
     }
 
     /// Returns an array of the json value used fo rthe physical Table analysis in the frontend
-    pub fn to_json_physical(&self) -> JsonValue {
-        self.get_physical_sructures().into()
+    pub fn to_json_physical(&self) -> io::Result<Value> {
+        Ok(self.to_versioned_json(OutputKind::Physical, self.get_physical_sructures()?))
+    }
+
+    /// How much of every stream of the compound file is explained by the physical structures,
+    /// with the gaps and overlaps between them
+    pub fn get_coverage(&self) -> io::Result<Vec<StreamCoverage>> {
+        let physical_structures = self.get_physical_sructures()?;
+        let streams = self
+            .cfb
+            .borrow()
+            .walk()
+            .filter(|entry| entry.is_stream())
+            .map(|entry| (entry.path().to_string_lossy().to_string(), entry.len()))
+            .collect::<Vec<_>>();
+
+        let coverage = streams
+            .into_iter()
+            .map(|(path, stream_len)| {
+                let stream_name = path.trim_start_matches('/');
+                let structures = physical_structures
+                    .iter()
+                    .filter(|structure| {
+                        // the fc/lcb pairs are all in the table stream
+                        structure.stream_name == stream_name
+                            || (structure.stream_name == "Table Stream"
+                                && stream_name == self.table_stream_name)
+                    })
+                    .collect::<Vec<_>>();
+                coverage::compute_stream_coverage(stream_name, stream_len, &structures)
+            })
+            .collect();
+        Ok(coverage)
+    }
+
+    pub fn to_json_coverage(&self) -> io::Result<Value> {
+        Ok(self.to_versioned_json(OutputKind::Coverage, self.get_coverage()?))
+    }
+
+    /// Problems found in the document, e.g. fc/lcb pairs of the FIB that point past the end of
+    /// the table stream or overlap each other, or broken sector chains of the compound file
+    pub fn get_diagnostics(&self) -> Vec<Diagnostic> {
+        let table_stream_len = self
+            .cfb
+            .borrow()
+            .entry(&self.table_stream_name)
+            .map(|entry| entry.len())
+            .unwrap_or_default();
+
+        let mut diagnostics = validate::validate_fc_lcb_pairs(
+            &self.fc_lb_pairs,
+            &self.table_stream_name,
+            table_stream_len,
+        );
+        diagnostics.extend(validate::validate_legacy_pairs(&self.fib));
+        if let Some(encryption) = self.encryption.as_ref().filter(|_| self.needs_password()) {
+            diagnostics.push(Diagnostic::new(
+                Severity::Warning,
+                "encrypted_without_password",
+                format!(
+                    "the document is protected with {}, only the FibBase, the encryption and the \
+                     parts of the compound file that are never encrypted are decoded without a \
+                     password",
+                    encryption.encryptionType
+                ),
+            ));
+        }
+        diagnostics.extend(self.decode_diagnostics.iter().cloned());
+        diagnostics.extend(compound_file::container_diagnostics(&self.container));
+        diagnostics.extend(stories::note_diagnostics(&self.notes));
+        diagnostics.extend(bookmarks::bookmark_diagnostics(&self.bookmarks));
+        diagnostics.extend(fields::field_diagnostics(&self.fields));
+        diagnostics
+    }
+
+    /// Wraps output about this document in the versioned envelope, with its diagnostics
+    fn to_versioned_json<T: Serialize>(&self, kind: OutputKind, data: T) -> Value {
+        to_versioned_json(kind, data, self.get_diagnostics())
     }
 
     /// helper function to read undecoded fib variables from the Word document stream
@@ -430,102 +1050,125 @@ impl WordDocument {
         (fc_offset as u64, count as u64)
     }
 
-    pub fn compare_to_physical(&self, other_word_doc: &WordDocument) -> JsonValue {
-        let reference_physical_strucutres = self.get_physical_sructures();
-        let other_physical_strucutres = other_word_doc.get_physical_sructures();
-
-        let difference_indeces = reference_physical_strucutres
-            .iter()
-            .zip(other_physical_strucutres.iter())
-            .map(|(ref_structure, other_structure)| {
-                let differences = compute_physical_differences(
+    pub fn compare_to_physical(&self, other_word_doc: &WordDocument) -> io::Result<Value> {
+        let reference_physical_strucutres = self.get_physical_sructures()?;
+        let other_physical_strucutres = other_word_doc.get_physical_sructures()?;
+
+        let difference_indeces = pair_same(
+            &reference_physical_strucutres,
+            &other_physical_strucutres,
+            same_physical_structure,
+        )
+        .into_iter()
+        .map(|(ref_structure, other_structure)| {
+            let differences = match (ref_structure, other_structure) {
+                (Some(ref_structure), Some(other_structure)) => compute_physical_differences(
                     ref_structure.bytes.clone(),
                     other_structure.bytes.clone(),
-                );
-                ComparisonPhysicalStructure {
-                    ref_structure: other_structure,
-                    comp_structure: ref_structure,
-                    difference_indices: differences,
-                }
-            })
-            .collect::<Vec<_>>();
+                ),
+                _ => Vec::new(),
+            };
+            ComparisonPhysicalStructure {
+                ref_structure: other_structure,
+                comp_structure: ref_structure,
+                difference_indices: differences,
+            }
+        })
+        .collect::<Vec<_>>();
 
-        difference_indeces.into()
+        Ok(to_versioned_json(
+            OutputKind::PhysicalComparison,
+            difference_indeces,
+            vec![],
+        ))
     }
 
-    pub fn compare_to_logical(&self, other_word_doc: &WordDocument) -> JsonValue {
+    pub fn compare_to_logical(&self, other_word_doc: &WordDocument) -> Value {
         let reference_logical_structures = self.get_logical_structures();
         let other_logical_strucutres = other_word_doc.get_logical_structures();
 
-        let mut logical_structures: Vec<ComparisonLogicalStructure> = Vec::new();
+        // the other document is the reference of the output, a structure only one of the
+        // documents has, like the encryption, is a difference
+        let logical_structures = compute_subsctructure_differences(
+            &other_logical_strucutres,
+            &reference_logical_structures,
+        );
 
-        for (ref_structure, other_structure) in reference_logical_structures
-            .iter()
-            .zip(other_logical_strucutres.iter())
+        to_versioned_json(OutputKind::LogicalComparison, logical_structures, vec![])
+    }
+
+    /// Compares the text subdocuments of the two documents, returning the byte ranges that differ
+    pub fn compare_to_text(&self, other_word_doc: &WordDocument) -> Value {
+        let mut text_comparisons = Vec::new();
+
+        for ((name, ref_text), (_, other_text)) in self.text.iter().zip(other_word_doc.text.iter())
         {
-            assert_eq!(ref_structure.name, other_structure.name);
-            let bool_arr = compute_structure_item_differences(
-                &ref_structure.structure,
-                &other_structure.structure,
-            );
-            let substructure_arr = match (
-                ref_structure.substructs.as_ref(),
-                other_structure.substructs.as_ref(),
-            ) {
-                (Some(ref_substructures), Some(other_substructures)) => {
-                    compute_subsctructure_differences(ref_substructures, other_substructures)
-                }
-                (None, Some(other_substructures)) => {
-                    let mut substructure_arr = Vec::new();
-                    for other_substructure in other_substructures.iter() {
-                        substructure_arr.push(ComparisonLogicalStructure {
-                            ref_structure: None,
-                            comp_structure: Some(other_substructure),
-                            structure_differences: vec![false; other_substructure.structure.len()],
-                            substructure_differences: Vec::new(),
-                        });
-                    }
-                    substructure_arr
-                }
-                (Some(ref_substructures), None) => {
-                    let mut substructure_arr = Vec::new();
-                    for ref_substructure in ref_substructures.iter() {
-                        substructure_arr.push(ComparisonLogicalStructure {
-                            ref_structure: Some(ref_substructure),
-                            comp_structure: None,
-                            structure_differences: vec![false; ref_substructure.structure.len()],
-                            substructure_differences: Vec::new(),
-                        });
-                    }
-                    substructure_arr
-                }
-                (None, None) => Vec::new(),
+            let (Some(ref_text), Some(other_text)) = (
+                ref_text.downcast_ref::<TextDoc>(),
+                other_text.downcast_ref::<TextDoc>(),
+            ) else {
+                continue;
             };
 
-            logical_structures.push(ComparisonLogicalStructure {
-                ref_structure: Some(other_structure),
-                comp_structure: Some(ref_structure),
-                structure_differences: bool_arr,
-                substructure_differences: substructure_arr,
+            text_comparisons.push(ComparisonText {
+                name: name.to_string(),
+                ref_text: other_text.to_fmt_string(),
+                comp_text: ref_text.to_fmt_string(),
+                difference_indices: compute_byte_differences(ref_text.bytes(), other_text.bytes()),
             });
         }
 
-        logical_structures.into()
+        to_versioned_json(OutputKind::TextComparison, text_comparisons, vec![])
     }
 
     pub fn print_cfb_structure(&self) {
+        // stdout is only unavailable if it has been closed, nothing to report to then
+        let _ = self.write_cfb_structure(&mut io::stdout());
+    }
+
+    /// Writes the flat `Stream:`/`Storage:` listing of the compound file to `writer`
+    pub fn write_cfb_structure<W: Write>(&self, writer: &mut W) -> io::Result<()> {
         let cfb = self.cfb.borrow();
         let entries = cfb.walk();
         for entry in entries {
             if entry.is_stream() {
-                println!("Stream: {}", entry.name());
+                writeln!(writer, "Stream: {}", entry.name())?;
             } else {
-                println!("Storage: {}", entry.name());
+                writeln!(writer, "Storage: {}", entry.name())?;
             }
         }
+        Ok(())
     }
 }
 
+/// Wraps `data` in the versioned envelope shared by all of the JSON output
+fn to_versioned_json<T: Serialize>(
+    kind: OutputKind,
+    data: T,
+    diagnostics: Vec<Diagnostic>,
+) -> Value {
+    serde_json::to_value(VersionedOutput::new(kind, data, diagnostics))
+        .expect("output is always serializable")
+}
+
+/// The JSON output of doc_anal, one variant per `OutputKind`
+#[derive(JsonSchema)]
+#[serde(untagged)]
+#[allow(dead_code)]
+enum DocAnalOutput<'a> {
+    Logical(VersionedOutput<Vec<Structure>>),
+    Physical(VersionedOutput<Vec<PhysicalStructure>>),
+    LogicalComparison(VersionedOutput<Vec<ComparisonLogicalStructure<'a, 'a>>>),
+    PhysicalComparison(VersionedOutput<Vec<ComparisonPhysicalStructure<'a, 'a>>>),
+    TextComparison(VersionedOutput<Vec<ComparisonText>>),
+    Coverage(VersionedOutput<Vec<StreamCoverage>>),
+}
+
+/// The JSON Schema of the output, published as `schema/doc_anal.schema.json`
+pub fn json_schema() -> Value {
+    serde_json::to_value(schemars::schema_for!(DocAnalOutput)).expect("schema is serializable")
+}
+
 fn compute_physical_differences(vec1: Vec<u8>, vec2: Vec<u8>) -> Vec<(usize, usize)> {
     // map to reflect the nibbles
     let mut nibble_vec_1 = Vec::with_capacity(vec1.len() * 2);
@@ -540,9 +1183,12 @@ fn compute_physical_differences(vec1: Vec<u8>, vec2: Vec<u8>) -> Vec<(usize, usi
         nibble_vec_2.push(byte & 0x0F);
     }
 
-    let vec1 = nibble_vec_1;
-    let vec2 = nibble_vec_2;
+    compute_byte_differences(&nibble_vec_1, &nibble_vec_2)
+}
 
+/// Returns the `(start, end)` index ranges where the two slices differ, including any
+/// difference in length
+fn compute_byte_differences(vec1: &[u8], vec2: &[u8]) -> Vec<(usize, usize)> {
     let mut differences = Vec::new();
     let mut start: Option<usize> = None;
 
@@ -576,23 +1222,24 @@ fn compute_physical_differences(vec1: Vec<u8>, vec2: Vec<u8>) -> Vec<(usize, usi
     differences
 }
 
+/// Compares the items of two structures by name, since e.g. the FIB of a Word 6.0/95 file has
+/// other items than a Word 97 FIB. There is a difference for every item of `items`, set if
+/// `other_items` has no item of that name or another value, followed by one set difference for
+/// every item that only `other_items` has
 fn compute_structure_item_differences(
     items: &Vec<StructureItem>,
     other_items: &Vec<StructureItem>,
 ) -> Vec<bool> {
     let mut differences = Vec::new();
 
-    let mut items_iter = items.iter();
-    let mut other_items_iter = other_items.iter();
-
-    while let (Some(item), Some(other_item)) = (items_iter.next(), other_items_iter.next()) {
-        assert_eq!(
-            item.name, other_item.name,
-            "Structure items should really be the same"
-        );
-        if item.value == other_item.value {
-            differences.push(false);
-        } else {
+    for item in items.iter() {
+        let other_item = other_items
+            .iter()
+            .find(|other_item| other_item.name == item.name);
+        differences.push(other_item.is_none_or(|other_item| other_item.value != item.value));
+    }
+    for other_item in other_items.iter() {
+        if !items.iter().any(|item| item.name == other_item.name) {
             differences.push(true);
         }
     }
@@ -600,65 +1247,96 @@ fn compute_structure_item_differences(
     differences
 }
 
-fn compute_subsctructure_differences<'a, 'b>(
-    substructures: &'a Vec<Structure>,
-    other_substructures: &'b Vec<Structure>,
-) -> Vec<ComparisonLogicalStructure<'a, 'b>> {
-    let mut differences = Vec::new();
+/// Pairs the elements of `items` and `other_items` that are the `same`, in the order of `items`
+/// followed by the elements only `other_items` has. Elements that are the same as several
+/// others are paired in the order they come in
+fn pair_same<'a, 'b, T>(
+    items: &'a [T],
+    other_items: &'b [T],
+    same: impl Fn(&T, &T) -> bool,
+) -> Vec<(Option<&'a T>, Option<&'b T>)> {
+    let mut paired = vec![false; other_items.len()];
+    let mut pairs = items
+        .iter()
+        .map(|item| {
+            let other_item = other_items
+                .iter()
+                .enumerate()
+                .position(|(index, other_item)| !paired[index] && same(item, other_item));
+            if let Some(index) = other_item {
+                paired[index] = true;
+            }
+            (Some(item), other_item.map(|index| &other_items[index]))
+        })
+        .collect::<Vec<_>>();
+    pairs.extend(
+        other_items
+            .iter()
+            .zip(paired)
+            .filter(|(_, paired)| !paired)
+            .map(|(other_item, _)| (None, Some(other_item))),
+    );
 
-    let mut compared_other_structures = Vec::new();
+    pairs
+}
 
-    for substructure in substructures.iter() {
-        let other_structure_matching_name = other_substructures
-            .iter()
-            .enumerate()
-            .find(|(_, s)| s.name == substructure.name);
-        match other_structure_matching_name {
-            Some((index, other_structure)) => {
-                let structure_differeces = compute_structure_item_differences(
-                    &substructure.structure,
-                    &other_structure.structure,
-                );
-
-                differences.push(ComparisonLogicalStructure {
-                    ref_structure: Some(&substructure),
-                    comp_structure: Some(&other_structure),
-                    structure_differences: structure_differeces,
-                    substructure_differences: Vec::new(),
-                });
-                compared_other_structures.push(index);
-            }
-            None => {
-                differences.push(ComparisonLogicalStructure {
-                    ref_structure: Some(substructure),
-                    comp_structure: None,
-                    structure_differences: vec![false; substructure.structure.len()],
-                    substructure_differences: Vec::new(),
-                });
-            }
-        }
+/// Physical structures of two documents are the same structure if they are in the same stream
+/// and have the same name, or the same description if they have no name
+fn same_physical_structure(structure: &PhysicalStructure, other: &PhysicalStructure) -> bool {
+    fn name(structure: &PhysicalStructure) -> Option<&str> {
+        structure
+            .structure_name
+            .as_deref()
+            .or(structure.description.as_deref())
     }
+    structure.stream_name == other.stream_name && name(structure) == name(other)
+}
 
-    for (_, other_structure) in other_substructures
-        .iter()
-        .enumerate()
-        .filter(|(i, _)| !compared_other_structures.contains(i))
-    {
-        differences.push(ComparisonLogicalStructure {
-            ref_structure: None,
+/// Compares the structures paired by name and their substructures down to the last level,
+/// `substructures` are the reference of the output
+fn compute_subsctructure_differences<'a, 'b>(
+    substructures: &'a [Structure],
+    other_substructures: &'b [Structure],
+) -> Vec<ComparisonLogicalStructure<'a, 'b>> {
+    pair_same(
+        substructures,
+        other_substructures,
+        |substructure, other_structure| substructure.name == other_structure.name,
+    )
+    .into_iter()
+    .map(|pair| match pair {
+        (Some(substructure), Some(other_structure)) => ComparisonLogicalStructure {
+            ref_structure: Some(substructure),
             comp_structure: Some(other_structure),
-            structure_differences: vec![false; other_structure.structure.len()],
-            substructure_differences: Vec::new(),
-        });
-    }
-
-    differences
+            structure_differences: compute_structure_item_differences(
+                &substructure.structure,
+                &other_structure.structure,
+            ),
+            substructure_differences: compute_subsctructure_differences(
+                substructure.substructs.as_deref().unwrap_or_default(),
+                other_structure.substructs.as_deref().unwrap_or_default(),
+            ),
+        },
+        (substructure, other_structure) => {
+            let structure = substructure.or(other_structure).unwrap();
+            ComparisonLogicalStructure {
+                ref_structure: substructure,
+                comp_structure: other_structure,
+                structure_differences: vec![false; structure.structure.len()],
+                substructure_differences: Vec::new(),
+            }
+        }
+    })
+    .collect()
 }
 
 // tests
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
+    pub(crate) use super::crypto::tests::obfuscated_document;
+    use super::from_reader::tests::fib_bytes;
     use super::*;
+    use serde_json::json;
     use std::fs::File;
 
     #[test]
@@ -805,6 +1483,59 @@ mod tests {
         assert!(differences[2].comp_structure.is_some());
     }
 
+    #[test]
+    fn test_nested_substructure_logical() {
+        let item = |name: &str, value: &str| StructureItem {
+            name: name.to_string(),
+            value: value.to_string(),
+            description: None,
+        };
+        let structure =
+            |name: &str, items: Vec<StructureItem>, substructs: Vec<Structure>| Structure {
+                name: name.to_string(),
+                structure: items,
+                substructs: Some(substructs),
+            };
+        // the same styles in another order, the paragraph properties of one differ in a sprm
+        let stylesheet = |istd_1_sprm: &str| {
+            vec![structure(
+                "StyleSheet",
+                vec![item("cstd", "2")],
+                vec![
+                    structure("Normal", vec![item("istd", "0")], vec![]),
+                    structure(
+                        "Heading 1",
+                        vec![item("istd", "1")],
+                        vec![structure(
+                            "UpxPapx",
+                            vec![item("istd", "1"), item("sprm", istd_1_sprm)],
+                            vec![],
+                        )],
+                    ),
+                ],
+            )]
+        };
+        let reference_stylesheet = stylesheet("0x2402");
+        let mut other_stylesheet = stylesheet("0x2403");
+        other_stylesheet[0].substructs.as_mut().unwrap().reverse();
+
+        let differences =
+            compute_subsctructure_differences(&reference_stylesheet, &other_stylesheet);
+        assert_eq!(differences.len(), 1);
+        assert_eq!(differences[0].structure_differences, vec![false]);
+        let styles = &differences[0].substructure_differences;
+        assert_eq!(styles.len(), 2);
+        assert_eq!(styles[0].comp_structure.unwrap().name, "Normal");
+        assert!(styles[0].substructure_differences.is_empty());
+        let heading = &styles[1];
+        assert_eq!(heading.comp_structure.unwrap().name, "Heading 1");
+        assert_eq!(heading.structure_differences, vec![false]);
+        assert_eq!(
+            heading.substructure_differences[0].structure_differences,
+            vec![false, true]
+        );
+    }
+
     #[test]
     fn test_files() {
         let file_1 = File::open("C:\\Users\\Thuthuka\\Downloads\\test1.doc").unwrap();
@@ -818,4 +1549,551 @@ mod tests {
         // let _ = wordpeg.compare_to_logical(&test1);
         let _ = wordpeg.compare_to_physical(&test1);
     }
+
+    /// The compound file of `streams` and of summary information property sets of a single
+    /// section without properties
+    fn compound_file(streams: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut cfb = CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+        for (name, bytes) in streams {
+            cfb.create_stream(name).unwrap().write_all(bytes).unwrap();
+        }
+
+        let mut property_set = vec![0xFE, 0xFF, 0, 0];
+        property_set.extend([0; 20]);
+        property_set.extend(1u32.to_le_bytes());
+        property_set.extend([0; 16]);
+        for value in [48u32, 8, 0] {
+            property_set.extend(value.to_le_bytes());
+        }
+        for name in ["\u{5}DocumentSummaryInformation", "\u{5}SummaryInformation"] {
+            cfb.create_stream(name)
+                .unwrap()
+                .write_all(&property_set)
+                .unwrap();
+        }
+
+        cfb.into_inner().into_inner()
+    }
+
+    /// A document of `fib` with `text` as its main text and without styles, `pairs` are
+    /// additional fc/lcb pairs given by their index in the FibRgFcLcb
+    pub(crate) fn document(fib: Vec<u8>, text: &[u8], pairs: &[(usize, i32, u32)]) -> Vec<u8> {
+        let fc_min = fib.len().max(0x400);
+        let mut word_document = fib;
+        word_document.resize(fc_min + text.len(), 0);
+        word_document[0x18..0x1C].copy_from_slice(&(fc_min as i32).to_le_bytes());
+        word_document[0x4C..0x50].copy_from_slice(&(text.len() as i32).to_le_bytes());
+        word_document[fc_min..].copy_from_slice(text);
+        // fcStshf/lcbStshf
+        for (index, fc, lcb) in [(1, 0, 14)].iter().chain(pairs) {
+            let offset = 0x9A + index * 8;
+            word_document[offset..offset + 4].copy_from_slice(&fc.to_le_bytes());
+            word_document[offset + 4..offset + 8].copy_from_slice(&lcb.to_le_bytes());
+        }
+
+        compound_file(&[
+            ("WordDocument", &word_document),
+            ("0Table", &EMPTY_STYLESHEET),
+        ])
+    }
+
+    /// A Word 6.0/95 document with `text` as its main text and without styles
+    fn word_6_document(text: &[u8]) -> Vec<u8> {
+        let mut word_document = vec![0; 0x500];
+        word_document[0..2].copy_from_slice(&0xA5DCu16.to_le_bytes());
+        word_document[2..4].copy_from_slice(&104u16.to_le_bytes());
+        word_document[0x18..0x1C].copy_from_slice(&0x300i32.to_le_bytes());
+        word_document[0x34..0x38].copy_from_slice(&(text.len() as i32).to_le_bytes());
+        word_document[0x300..0x300 + text.len()].copy_from_slice(text);
+        // fcStshf/lcbStshf, Word 6.0/95 files have no table stream
+        word_document[0x60..0x68].copy_from_slice(&[0, 0x05, 0, 0, 14, 0, 0, 0]);
+        word_document.extend(EMPTY_STYLESHEET);
+
+        compound_file(&[("WordDocument", &word_document)])
+    }
+
+    /// A Word 97 document with `text` as its main text and without styles
+    pub(crate) fn empty_document(text: &[u8]) -> Vec<u8> {
+        document(fib_bytes(0xC1, 0x5D, &[]), text, &[])
+    }
+
+    const EMPTY_STYLESHEET: [u8; 14] = [12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+    #[test]
+    fn test_compare_word_6_to_word_97() {
+        let word_97 = WordDocument::read_bytes(empty_document(b"Text\r")).unwrap();
+        let word_6 = WordDocument::read_bytes(word_6_document(b"Text\r")).unwrap();
+        assert!(word_6.fib6.is_some());
+        assert_eq!(
+            word_6.text.main_text.bytes(),
+            word_97.text.main_text.bytes()
+        );
+        assert_eq!(word_97.text.main_text.to_fmt_string(), "Text{0x0D}");
+
+        for (reference, compared) in [(&word_97, &word_6), (&word_6, &word_97)] {
+            let comparison = compared.compare_to_logical(reference);
+            let fib = &comparison["data"][0];
+            let names = |key: &str| {
+                fib[key]["structure"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|item| item["name"].as_str().unwrap())
+                    .collect::<Vec<_>>()
+            };
+            let (ref_names, comp_names) = (names("ref_structure"), names("comp_structure"));
+            let only_in_compared = comp_names
+                .iter()
+                .filter(|name| !ref_names.contains(name))
+                .count();
+            let differences = fib["structure_differences"].as_array().unwrap();
+            // the items of the reference, then those only the compared FIB has
+            assert_eq!(differences.len(), ref_names.len() + only_in_compared);
+            assert!(only_in_compared > 0);
+            let n_fib = ref_names.iter().position(|&name| name == "nFib").unwrap();
+            assert_eq!(differences[n_fib], json!(true));
+            let fc_mac = ref_names.iter().position(|&name| name == "fcMac").unwrap();
+            assert_eq!(differences[fc_mac], json!(false));
+            assert!(differences[ref_names.len()..]
+                .iter()
+                .all(|different| different == true));
+
+            compared.compare_to_text(reference);
+        }
+
+        // the FibBase of both documents, the blocks after it are only in one of them
+        let comparison = word_6.compare_to_physical(&word_97).unwrap();
+        let fib_base = physical_entry(&comparison, "FibBase");
+        assert_eq!(fib_base["ref_structure"]["start_index"], json!(0));
+        assert_eq!(fib_base["comp_structure"]["start_index"], json!(0));
+        assert!(!fib_base["difference_indices"]
+            .as_array()
+            .unwrap()
+            .is_empty());
+        assert!(physical_entry(&comparison, "FibRgLw6")["ref_structure"].is_null());
+        assert!(physical_entry(&comparison, "FibRgLw97")["comp_structure"].is_null());
+        assert_physical_entries_line_up(&comparison);
+    }
+
+    #[test]
+    fn test_compare_word_97_to_word_2003() {
+        let word_97 = WordDocument::read_bytes(empty_document(b"Text\r")).unwrap();
+        let word_2003 = WordDocument::read_bytes(document(
+            fib_bytes(0xC1, 0xA4, &[0x010B, 0]),
+            b"Text\r",
+            &[],
+        ))
+        .unwrap();
+        assert_eq!(word_2003.fib.effective_nfib(), 0x010B);
+
+        let comparison = word_2003.compare_to_physical(&word_97).unwrap();
+        assert_physical_entries_line_up(&comparison);
+        for name in [
+            "FibBase",
+            "FibRgW97",
+            "FibRgLw97",
+            "FibRgFcLcb97",
+            "FibRgCswNew",
+        ] {
+            let entry = physical_entry(&comparison, name);
+            assert!(!entry["ref_structure"].is_null(), "{name}");
+            assert!(!entry["comp_structure"].is_null(), "{name}");
+        }
+        for name in ["FibRgFcLcb2000", "FibRgFcLcb2002", "FibRgFcLcb2003"] {
+            let entry = physical_entry(&comparison, name);
+            assert!(entry["ref_structure"].is_null(), "{name}");
+            assert!(!entry["comp_structure"].is_null(), "{name}");
+        }
+        // the stylesheet pair is in both, the pairs of Word 2000 and later only in Word 2003
+        let fc_stshf = physical_entry(&comparison, "STSH structure");
+        assert_eq!(fc_stshf["difference_indices"], json!([]));
+        assert!(physical_entry(&comparison, "internal cache")["ref_structure"].is_null());
+    }
+
+    /// The entry of a physical comparison for the structure of the name or the description
+    fn physical_entry<'a>(comparison: &'a Value, name: &str) -> &'a Value {
+        comparison["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|entry| {
+                ["ref_structure", "comp_structure"].iter().any(|key| {
+                    entry[key]["structure_name"] == name
+                        || entry[key]["structure_name"].is_null()
+                            && entry[key]["description"] == name
+                })
+            })
+            .unwrap_or_else(|| panic!("no entry for {name}"))
+    }
+
+    /// Checks that the paired structures of a physical comparison are the same structure
+    fn assert_physical_entries_line_up(comparison: &Value) {
+        for entry in comparison["data"].as_array().unwrap() {
+            let (ref_structure, comp_structure) =
+                (&entry["ref_structure"], &entry["comp_structure"]);
+            if ref_structure.is_null() || comp_structure.is_null() {
+                assert!(!ref_structure.is_null() || !comp_structure.is_null());
+                continue;
+            }
+            for key in ["stream_name", "structure_name"] {
+                assert_eq!(ref_structure[key], comp_structure[key], "{entry}");
+            }
+            if ref_structure["structure_name"].is_null() {
+                assert_eq!(ref_structure["description"], comp_structure["description"]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_undecodable_parts_are_diagnostics() {
+        // fcDggInfo/lcbDggInfo point past the end of the table stream
+        let bytes = document(fib_bytes(0xC1, 0x5D, &[]), b"Text\r", &[(50, 0x100, 0x10)]);
+        let document = WordDocument::read_bytes(bytes).unwrap();
+        assert_eq!(document.text.main_text.to_fmt_string(), "Text{0x0D}");
+        assert!(document.office_art.is_none());
+        assert!(document.pictures.is_empty());
+        let failed = document
+            .get_diagnostics()
+            .into_iter()
+            .filter(|diagnostic| diagnostic.code == "decode_failed")
+            .collect::<Vec<_>>();
+        assert!(failed
+            .iter()
+            .all(|diagnostic| diagnostic.severity == Severity::Error));
+        let parts = ["OfficeArt drawings", "pictures"];
+        assert_eq!(failed.len(), parts.len());
+        for (diagnostic, part) in failed.iter().zip(parts) {
+            assert!(diagnostic
+                .message
+                .starts_with(&format!("the {part} could not be decoded")));
+        }
+    }
+
+    #[test]
+    fn test_out_of_bounds_pair_structure() {
+        // fcDggInfo/lcbDggInfo end past the 14 bytes of the table stream
+        let bytes = document(fib_bytes(0xC1, 0x5D, &[]), b"Text\r", &[(50, 0x08, 0x10)]);
+        let document = WordDocument::read_bytes(bytes).unwrap();
+
+        let physical = document.get_physical_sructures().unwrap();
+        let dgg_info = physical
+            .iter()
+            .find(|structure| {
+                structure.description.as_deref() == Some("Office Drawing object table data")
+            })
+            .unwrap();
+        assert_eq!((dgg_info.start_index, dgg_info.end_index), (0x08, 0x18));
+        assert_eq!(dgg_info.bytes.len(), 14 - 0x08);
+        let code = dgg_info.diagnostic.as_deref().unwrap();
+        let diagnostic = document
+            .get_diagnostics()
+            .into_iter()
+            .find(|diagnostic| diagnostic.code == code)
+            .unwrap();
+        assert_eq!(
+            diagnostic.range,
+            Some(ByteRange {
+                start: 0x08,
+                end: 0x18
+            })
+        );
+
+        let stylesheet = physical
+            .iter()
+            .find(|structure| structure.description.as_deref() == Some("STSH structure"))
+            .unwrap();
+        assert_eq!(stylesheet.diagnostic, None);
+    }
+
+    #[test]
+    fn test_missing_picture_stream() {
+        // a picture of a Data stream the compound file does not have
+        let mut document = WordDocument::read_bytes(empty_document(b"Text\r")).unwrap();
+        document.pictures.push(Picture {
+            source: "Data".to_string(),
+            stream_name: "Data".to_string(),
+            offset: 0,
+            len: 0x10,
+            blip_type: BlipType::Png,
+            size: 0,
+            sha256: String::new(),
+            picf: None,
+            fbse: None,
+            data: vec![],
+        });
+
+        let err = document.get_physical_sructures().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_encrypted_without_password() {
+        // only the FibBase of an encrypted document is known without the password
+        let bytes = obfuscated_document();
+
+        let word_doc = WordDocument::read_bytes(bytes.clone()).unwrap();
+        assert!(word_doc.needs_password());
+        assert_eq!(word_doc.fib.nFib, 0xC1);
+        assert!(word_doc.fib.fEncrypted);
+
+        let structures = word_doc.get_logical_structures();
+        let fib = &structures[0];
+        assert_eq!(fib.structure.last().unwrap().name, "fcMac");
+        assert_eq!(structures.last().unwrap().name, "Encryption");
+        let physical = word_doc.get_physical_sructures().unwrap();
+        assert_eq!(physical[0].structure_name.as_deref(), Some("FibBase"));
+        let codes = word_doc
+            .get_diagnostics()
+            .into_iter()
+            .map(|diagnostic| diagnostic.code)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            vec![
+                "encrypted_without_password",
+                "encrypted_sectors_not_scanned"
+            ]
+        );
+        assert!(word_doc.container.is_empty());
+
+        // the encryption is the only structure of just one of the documents
+        let plain = WordDocument::read_bytes(empty_document(b"Text\r")).unwrap();
+        let comparison = word_doc.compare_to_logical(&plain);
+        let entries = comparison["data"].as_array().unwrap();
+        let encryption = entries
+            .iter()
+            .find(|entry| entry["comp_structure"]["name"] == "Encryption")
+            .unwrap();
+        assert!(encryption["ref_structure"].is_null());
+        assert_eq!(
+            entries[0]["ref_structure"]["name"],
+            entries[0]["comp_structure"]["name"]
+        );
+        let one_sided = entries
+            .iter()
+            .filter(|entry| entry["ref_structure"].is_null() || entry["comp_structure"].is_null())
+            .count();
+        assert_eq!(one_sided, 1);
+
+        let err = WordDocument::read_bytes_with_password(bytes, Some("wrong")).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_decrypted_residual_text() {
+        // an XOR obfuscated document with text past the FIB that no piece refers to, obfuscating
+        // it is the same as deobfuscating it
+        let mut fib = fib_bytes(0xC1, 0x5D, &[]);
+        fib[0x0A..0x0C].copy_from_slice(&0x8100u16.to_le_bytes());
+        fib[0x0E..0x12].copy_from_slice(&0x83AFu32.to_le_bytes());
+        fib.resize(0x390, 0);
+        fib.extend(b"Deleted text");
+        let mut cfb = CompoundFile::open(Cursor::new(document(fib, b"Text\r", &[]))).unwrap();
+        let encryption = crypto::read_encryption(&mut cfb).unwrap().unwrap();
+        crypto::decrypt(&mut cfb, &encryption, "password").unwrap();
+        cfb.flush().unwrap();
+        let obfuscated = cfb.into_inner().into_inner();
+        assert!(!obfuscated
+            .windows(b"Deleted text".len())
+            .any(|window| window == b"Deleted text"));
+
+        // the bytes are scanned once they are decrypted, and not at all without the password
+        let document =
+            WordDocument::read_bytes_with_password(obfuscated.clone(), Some("password")).unwrap();
+        assert_eq!(document.text.main_text.to_fmt_string(), "Text{0x0D}");
+        assert!(!document.container.is_empty());
+        let residual_text = document
+            .residual_text
+            .iter()
+            .map(|run| (run.offset, run.encoding.as_str(), run.text.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(residual_text, vec![(0x390, "cp1252", "Deleted text")]);
+
+        let document = WordDocument::read_bytes(obfuscated).unwrap();
+        assert!(document.residual_text.is_empty());
+        assert!(document.container.is_empty());
+    }
+
+    #[test]
+    fn test_json_schema_is_up_to_date() {
+        let published: Value =
+            serde_json::from_str(include_str!("../../schema/doc_anal.schema.json")).unwrap();
+        assert!(
+            published == json_schema(),
+            "schema/doc_anal.schema.json is out of date, regenerate it with `doc_anal schema -o schema/doc_anal.schema.json`"
+        );
+        assert_eq!(
+            published["definitions"]["OutputKind"]["enum"]
+                .as_array()
+                .unwrap()
+                .len(),
+            6
+        );
+    }
+
+    #[test]
+    fn test_ts_bindings_are_up_to_date() {
+        use crate::server::{ApiError, DocumentUpload};
+        use ts_rs::{Dummy, TS};
+
+        // the export tests write to `TS_RS_EXPORT_DIR`, the committed bindings are left as they are
+        let bindings = [
+            ("Structure.ts", Structure::export_to_string()),
+            ("StructureItem.ts", StructureItem::export_to_string()),
+            (
+                "PhysicalStructure.ts",
+                PhysicalStructure::export_to_string(),
+            ),
+            (
+                "ComparisonPhysicalStructure.ts",
+                ComparisonPhysicalStructure::export_to_string(),
+            ),
+            (
+                "ComparisonLogicalStructure.ts",
+                ComparisonLogicalStructure::export_to_string(),
+            ),
+            ("ComparisonText.ts", ComparisonText::export_to_string()),
+            (
+                "VersionedOutput.ts",
+                VersionedOutput::<Dummy>::export_to_string(),
+            ),
+            ("OutputKind.ts", OutputKind::export_to_string()),
+            ("StreamCoverage.ts", StreamCoverage::export_to_string()),
+            ("ByteRange.ts", ByteRange::export_to_string()),
+            ("ClaimedRange.ts", ClaimedRange::export_to_string()),
+            ("Diagnostic.ts", Diagnostic::export_to_string()),
+            ("Severity.ts", Severity::export_to_string()),
+            ("DocumentUpload.ts", DocumentUpload::export_to_string()),
+            ("ApiError.ts", ApiError::export_to_string()),
+        ];
+        for (file, generated) in bindings {
+            let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("bindings")
+                .join(file);
+            let committed = std::fs::read_to_string(&path).unwrap_or_default();
+            assert!(
+                generated.unwrap() == committed,
+                "bindings/{file} is out of date, regenerate it with `TS_RS_EXPORT_DIR=bindings cargo test export_bindings`"
+            );
+        }
+    }
+
+    #[test]
+    fn test_json_matches_schema() {
+        let structure = Structure {
+            name: "Fib".to_string(),
+            structure: vec![
+                StructureItem {
+                    name: "wIdent".to_string(),
+                    value: "0xECA5".to_string(),
+                    description: Some("Magic Number".to_string()),
+                },
+                StructureItem {
+                    name: "nFib".to_string(),
+                    value: "193".to_string(),
+                    description: None,
+                },
+            ],
+            substructs: Some(vec![Structure {
+                name: "FibBase".to_string(),
+                structure: vec![],
+                substructs: None,
+            }]),
+        };
+        let physical_structure = PhysicalStructure::from_reader_range(
+            &mut Cursor::new(vec![0xA5, 0xEC]),
+            0,
+            2,
+            "WordDocument",
+        )
+        .unwrap()
+        .structure_name("Fib")
+        .description("magic number");
+        let bare_physical_structure =
+            PhysicalStructure::from_reader_range(&mut Cursor::new(vec![0]), 0, 1, "1Table")
+                .unwrap();
+        let reversed =
+            PhysicalStructure::from_reader_range(&mut Cursor::new(vec![0]), 1, 0, "1Table");
+        assert_eq!(reversed.unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        let physical_comparison = ComparisonPhysicalStructure {
+            ref_structure: Some(&physical_structure),
+            comp_structure: Some(&physical_structure),
+            difference_indices: vec![(1, 2)],
+        };
+        let one_sided_physical_comparison = ComparisonPhysicalStructure {
+            ref_structure: None,
+            comp_structure: Some(&bare_physical_structure),
+            difference_indices: vec![],
+        };
+        let logical_comparison = ComparisonLogicalStructure {
+            ref_structure: Some(&structure),
+            comp_structure: None,
+            structure_differences: vec![true, false],
+            substructure_differences: vec![ComparisonLogicalStructure {
+                ref_structure: None,
+                comp_structure: Some(&structure),
+                structure_differences: vec![],
+                substructure_differences: vec![],
+            }],
+        };
+        let text_comparison = ComparisonText {
+            name: "main_text".to_string(),
+            ref_text: "Hello".to_string(),
+            comp_text: "Hallo".to_string(),
+            difference_indices: vec![(1, 2)],
+        };
+        let samples = [
+            to_versioned_json(OutputKind::Logical, vec![&structure], vec![]),
+            to_versioned_json(
+                OutputKind::Physical,
+                vec![&physical_structure, &bare_physical_structure],
+                vec![],
+            ),
+            to_versioned_json(
+                OutputKind::PhysicalComparison,
+                vec![&physical_comparison, &one_sided_physical_comparison],
+                vec![],
+            ),
+            to_versioned_json(
+                OutputKind::LogicalComparison,
+                vec![&logical_comparison],
+                vec![],
+            ),
+            to_versioned_json(
+                OutputKind::TextComparison,
+                vec![&text_comparison],
+                vec![
+                    Diagnostic::new(Severity::Error, "test", "message".to_string())
+                        .range("1Table", 0, 1),
+                ],
+            ),
+            to_versioned_json(
+                OutputKind::Coverage,
+                vec![coverage::compute_stream_coverage(
+                    "WordDocument",
+                    4,
+                    &[&physical_structure],
+                )],
+                vec![],
+            ),
+        ];
+
+        let validator = jsonschema::validator_for(&json_schema()).unwrap();
+        for sample in samples {
+            let errors = validator
+                .iter_errors(&sample)
+                .map(|err| err.to_string())
+                .collect::<Vec<_>>();
+            assert!(
+                errors.is_empty(),
+                "the {} output does not match the schema: {errors:?}",
+                sample["kind"]
+            );
+        }
+        // the schema is strict enough to reject what it does not describe
+        let mut sample = to_versioned_json(OutputKind::Logical, vec![&structure], vec![]);
+        sample["data"][0]["structure"][0]["value"] = json!(1);
+        assert!(!validator.is_valid(&sample));
+    }
 }
@@ -1,6 +1,7 @@
-use std::io::{Read, Seek, SeekFrom};
+use std::io::{self, Read, Seek, SeekFrom};
 
-use json::{object, JsonValue};
+use schemars::JsonSchema;
+use serde::Serializer;
 use serde_derive::Serialize;
 use struct_iterable::Iterable;
 use ts_rs::TS;
@@ -9,9 +10,69 @@ use super::{from_c_struct::FromCStruct, to_structure::ToStructure};
 
 // region: Structs
 
+/// Version of the JSON output, see `schema/doc_anal.schema.json`.
+/// Bumped whenever the shape of the output changes
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// What a JSON output holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, rename_all = "snake_case")]
+pub enum OutputKind {
+    Logical,
+    Physical,
+    LogicalComparison,
+    PhysicalComparison,
+    TextComparison,
+    Coverage,
+}
+
+/// The envelope of every JSON output, `data` holds the structures or comparisons
+#[derive(Debug, Serialize, JsonSchema, TS)]
+#[ts(export)]
+pub struct VersionedOutput<T> {
+    pub schema_version: u32,
+    pub kind: OutputKind,
+    pub data: T,
+    /// Problems found while decoding the document, empty for comparisons
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl<T> VersionedOutput<T> {
+    pub fn new(kind: OutputKind, data: T, diagnostics: Vec<Diagnostic>) -> Self {
+        VersionedOutput {
+            schema_version: SCHEMA_VERSION,
+            kind,
+            data,
+            diagnostics,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, rename_all = "snake_case")]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+/// A problem found in a document, e.g. an fc/lcb pair that points past the end of its stream
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema, TS)]
+#[ts(export)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// Stable identifier of the check, e.g. `fc_lcb_out_of_bounds`
+    pub code: String,
+    pub message: String,
+    pub stream_name: Option<String>,
+    pub range: Option<ByteRange>,
+}
+
 /// A Struct that is used for storing the information of a structure
 /// within a document for the logical analysis
-#[derive(Debug, TS)]
+#[derive(Debug, Serialize, JsonSchema, TS)]
 #[ts(export)]
 pub struct Structure {
     pub name: String,
@@ -20,10 +81,14 @@ pub struct Structure {
 }
 
 /// A Struct that is used for storing the information of a field within a structure
-#[derive(Debug, TS)]
+#[derive(Debug, Serialize, JsonSchema, TS)]
+#[ts(export)]
 pub struct StructureItem {
     pub name: String,
     pub value: String,
+    // left out of the JSON when there is no description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub description: Option<String>,
 }
 
@@ -37,54 +102,29 @@ impl Structure {
     }
 }
 
-impl From<&Structure> for JsonValue {
-    fn from(structure: &Structure) -> JsonValue {
-        let mut items_structure = vec![];
-        for item in &structure.structure {
-            let mut item_json = object! {};
-            item_json["name"] = item.name.clone().into();
-            item_json["value"] = item.value.clone().into();
-            if let Some(description) = &item.description {
-                item_json["description"] = description.clone().into();
-            }
-            items_structure.push(item_json);
-        }
-
-        let substructs = if let Some(substructs) = &structure.substructs {
-            let mut substructs_structure: Vec<&Structure> = vec![];
-            for substruct in substructs {
-                substructs_structure.push(substruct.into());
-            }
-            Some(substructs_structure)
-        } else {
-            None
-        };
-
-        object! {
-            name: structure.name.clone(),
-            structure: items_structure,
-            substructs: substructs,
-        }
-    }
-}
-
-impl Into<JsonValue> for Structure {
-    fn into(self) -> JsonValue {
-        JsonValue::from(&self)
-    }
-}
-
 /// storing the physical bytes of a certain section of the document
-#[derive(Debug, TS)]
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
 #[ts(export)]
 pub struct PhysicalStructure {
     pub stream_name: String,
     pub structure_name: Option<String>,
-    // the below will be made into a string for TS
+    // the below will be made into a hex string for TS
+    #[serde(serialize_with = "serialize_upper_hex")]
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
     pub bytes: Vec<u8>,
+    #[ts(type = "number")]
     pub start_index: i64,
+    #[ts(type = "number")]
     pub end_index: i64,
     pub description: Option<String>,
+    /// the code of the diagnostic about the structure, e.g. `fc_lcb_out_of_bounds` for an fc/lcb
+    /// pair that ends past its stream
+    pub diagnostic: Option<String>,
+}
+
+fn serialize_upper_hex<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&hex::encode_upper(bytes))
 }
 
 impl PhysicalStructure {
@@ -93,19 +133,26 @@ impl PhysicalStructure {
         start: u64,
         end: u64,
         stream_name: &str,
-    ) -> Self {
+    ) -> io::Result<Self> {
+        let len = end.checked_sub(start).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{stream_name} range ends at {end:#X} before its start {start:#X}"),
+            )
+        })?;
         let mut bytes = vec![];
-        reader.seek(SeekFrom::Start(start)).unwrap();
-        reader.take(end - start).read_to_end(&mut bytes).unwrap();
+        reader.seek(SeekFrom::Start(start))?;
+        reader.take(len).read_to_end(&mut bytes)?;
 
-        PhysicalStructure {
+        Ok(PhysicalStructure {
             stream_name: stream_name.to_string(),
             bytes,
             start_index: start as i64,
             end_index: end as i64,
             description: None,
             structure_name: None,
-        }
+            diagnostic: None,
+        })
     }
 
     pub fn structure_name(mut self, name: &str) -> Self {
@@ -117,59 +164,22 @@ impl PhysicalStructure {
         self.description = Some(description.to_string());
         self
     }
-}
-
-impl From<&PhysicalStructure> for JsonValue {
-    fn from(value: &PhysicalStructure) -> Self {
-        let hex_string = hex::encode_upper(&value.bytes);
-
-        object! {
-            stream_name: value.stream_name.clone(),
-            structure_name: value.structure_name.clone(),
-            bytes: hex_string,
-            start_index: value.start_index,
-            end_index: value.end_index,
-            description: value.description.clone(),
-        }
-    }
-}
 
-impl Into<JsonValue> for PhysicalStructure {
-    fn into(self) -> JsonValue {
-        JsonValue::from(&self)
+    pub fn diagnostic(mut self, code: &str) -> Self {
+        self.diagnostic = Some(code.to_string());
+        self
     }
 }
 
-#[derive(Debug, TS)]
+#[derive(Debug, Serialize, JsonSchema, TS)]
 #[ts(export)]
 pub struct ComparisonPhysicalStructure<'a, 'b> {
-    pub ref_structure: &'b PhysicalStructure,
-    pub comp_structure: &'a PhysicalStructure,
+    pub ref_structure: Option<&'b PhysicalStructure>,
+    pub comp_structure: Option<&'a PhysicalStructure>,
     pub difference_indices: Vec<(usize, usize)>,
 }
 
-impl<'a, 'b> From<&ComparisonPhysicalStructure<'a, 'b>> for JsonValue {
-    fn from(value: &ComparisonPhysicalStructure) -> Self {
-        let mut difference_indices: Vec<JsonValue> = vec![];
-        for (ref_index, comp_index) in &value.difference_indices {
-            difference_indices.push(vec![*ref_index, *comp_index].into());
-        }
-
-        object! {
-            ref_structure: JsonValue::from(value.ref_structure),
-            comp_structure: JsonValue::from(value.comp_structure),
-            difference_indices: difference_indices,
-        }
-    }
-}
-
-impl<'a, 'b> Into<JsonValue> for ComparisonPhysicalStructure<'a, 'b> {
-    fn into(self) -> JsonValue {
-        JsonValue::from(&self)
-    }
-}
-
-#[derive(Debug, TS)]
+#[derive(Debug, Serialize, JsonSchema, TS)]
 #[ts(export)]
 pub struct ComparisonLogicalStructure<'a, 'b> {
     pub ref_structure: Option<&'a Structure>,
@@ -178,31 +188,54 @@ pub struct ComparisonLogicalStructure<'a, 'b> {
     pub substructure_differences: Vec<ComparisonLogicalStructure<'a, 'b>>,
 }
 
-impl<'a, 'b> From<&ComparisonLogicalStructure<'a, 'b>> for JsonValue {
-    fn from(value: &ComparisonLogicalStructure) -> Self {
-        let mut structure_differences: Vec<JsonValue> = vec![];
-        for difference in &value.structure_differences {
-            structure_differences.push(difference.clone().into());
-        }
+/// A range of bytes `[start, end)` within a stream
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema, TS)]
+#[ts(export)]
+pub struct ByteRange {
+    #[ts(type = "number")]
+    pub start: u64,
+    #[ts(type = "number")]
+    pub end: u64,
+}
 
-        let mut substructure_differences: Vec<JsonValue> = vec![];
-        for substructure in &value.substructure_differences {
-            substructure_differences.push(substructure.into());
-        }
+/// A range of bytes together with the structures that claim it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema, TS)]
+#[ts(export)]
+pub struct ClaimedRange {
+    #[ts(type = "number")]
+    pub start: u64,
+    #[ts(type = "number")]
+    pub end: u64,
+    pub structures: Vec<String>,
+}
 
-        object! {
-            ref_structure: JsonValue::from(value.ref_structure),
-            comp_structure: JsonValue::from(value.comp_structure),
-            structure_differences: structure_differences,
-            substructure_differences: substructure_differences,
-        }
-    }
+/// How much of a stream is explained by the `PhysicalStructure`s
+#[derive(Debug, Serialize, JsonSchema, TS)]
+#[ts(export)]
+pub struct StreamCoverage {
+    pub stream_name: String,
+    #[ts(type = "number")]
+    pub stream_len: u64,
+    /// Count of bytes claimed by at least one structure
+    #[ts(type = "number")]
+    pub covered_bytes: u64,
+    pub percent_explained: f64,
+    /// Ranges not claimed by any structure
+    pub gaps: Vec<ByteRange>,
+    /// Ranges claimed by more than one structure
+    pub overlaps: Vec<ClaimedRange>,
+    /// Structures that reach past the end of the stream
+    pub out_of_bounds: Vec<ClaimedRange>,
 }
 
-impl<'a, 'b> Into<JsonValue> for ComparisonLogicalStructure<'a, 'b> {
-    fn into(self) -> JsonValue {
-        JsonValue::from(&self)
-    }
+/// The comparison of one text subdocument (main text, footnotes, ...) of two documents
+#[derive(Debug, Serialize, JsonSchema, TS)]
+#[ts(export)]
+pub struct ComparisonText {
+    pub name: String,
+    pub ref_text: String,
+    pub comp_text: String,
+    pub difference_indices: Vec<(usize, usize)>,
 }
 
 #[allow(non_snake_case)]
@@ -219,6 +252,7 @@ pub struct _STSHI {
     pub mpstilsd: [u16; 0x0A], // 10
 }
 
+#[derive(Default)]
 pub struct Bytes {
     pub bytes: Vec<u8>,
 }
@@ -265,7 +299,7 @@ impl serde::Serialize for Bytes {
 }
 
 #[allow(non_snake_case, unused)]
-#[derive(Debug, Iterable, Serialize)]
+#[derive(Debug, Default, Iterable, Serialize)]
 pub struct Fib {
     pub wIdent: Bytes,
     pub nFib: u16,
@@ -527,6 +561,128 @@ pub struct Fib {
     pub lcbSttbListNames: u32,
     pub fcSttbfUssr: i32,
     pub lcbSttbfUssr: u32,
+    /* End of the Word 97 FC/LCB pairs, the blocks of later versions are only present if Cfclcb is large enough */
+    pub fibRgFcLcb2000: Option<FibRgFcLcb2000>,
+    pub fibRgFcLcb2002: Option<FibRgFcLcb2002>,
+    pub fibRgFcLcb2003: Option<FibRgFcLcb2003>,
+    pub fibRgFcLcb2007: Option<FibRgFcLcb2007>,
+    pub cswNew: u16,
+    /* Beginning of the array of new shorts, only present if cswNew is large enough */
+    pub nFibNew: Option<u16>,
+    pub cQuickSavesNew: Option<u16>,
+    pub lidThemeOther: Option<u16>,
+    pub lidThemeFE: Option<u16>,
+    pub lidThemeCS: Option<u16>,
+}
+
+/// Number of FC/LCB pairs in the FIB written by each version, the pairs of a version start with
+/// the pairs of the version before it
+pub const CB_RG_FC_LCB_97: u16 = 0x005D;
+pub const CB_RG_FC_LCB_2000: u16 = 0x006C;
+pub const CB_RG_FC_LCB_2002: u16 = 0x0088;
+pub const CB_RG_FC_LCB_2003: u16 = 0x00A4;
+pub const CB_RG_FC_LCB_2007: u16 = 0x00B7;
+
+#[allow(non_snake_case)]
+impl Fib {
+    /// The version of the file, nFibNew takes the place of nFib when cswNew is set
+    pub fn effective_nfib(&self) -> u16 {
+        self.nFibNew.unwrap_or(self.nFib)
+    }
+
+    /// The blocks of the FIB in the WordDocument stream as (name, description, start, end), sized
+    /// by Csw, Clw, Cfclcb and cswNew as they are in the file
+    pub fn blocks(&self) -> Vec<(&'static str, &'static str, u64, u64)> {
+        let fib_rg_w_end = 0x22 + self.Csw as u64 * 2;
+        let fib_rg_lw_end = fib_rg_w_end + 2 + self.Clw as u64 * 4;
+        let rg_fc_lcb_start = fib_rg_lw_end + 2;
+        let rg_fc_lcb_end = rg_fc_lcb_start + self.Cfclcb as u64 * 8;
+        let pair_offset = |count: u16| rg_fc_lcb_start + count as u64 * 8;
+
+        let mut blocks = vec![
+            ("FibBase", "wIdent - chsTables", 0, 0x20),
+            (
+                "FibRgW97",
+                "Csw and the array of shorts, wMagicCreated - lidFE",
+                0x20,
+                fib_rg_w_end,
+            ),
+            (
+                "FibRgLw97",
+                "Clw and the array of longs, cbMac - fcIslandLim",
+                fib_rg_w_end,
+                fib_rg_lw_end,
+            ),
+            (
+                "FibRgFcLcb97",
+                "Cfclcb and the Word 97 FC/LCB pairs, fcStshfOrig - lcbSttbfUssr",
+                fib_rg_lw_end,
+                pair_offset(CB_RG_FC_LCB_97),
+            ),
+        ];
+
+        let later_blocks = [
+            (
+                self.fibRgFcLcb2000.is_some(),
+                "FibRgFcLcb2000",
+                "Word 2000 FC/LCB pairs, fcPlcfTch - fcpgdEdnOld",
+                CB_RG_FC_LCB_97,
+                CB_RG_FC_LCB_2000,
+            ),
+            (
+                self.fibRgFcLcb2002.is_some(),
+                "FibRgFcLcb2002",
+                "Word 2002 FC/LCB pairs, fcUnused1 - lcbPlcflvcMixedXP",
+                CB_RG_FC_LCB_2000,
+                CB_RG_FC_LCB_2002,
+            ),
+            (
+                self.fibRgFcLcb2003.is_some(),
+                "FibRgFcLcb2003",
+                "Word 2003 FC/LCB pairs, fcHplxsdr - lcbAfd",
+                CB_RG_FC_LCB_2002,
+                CB_RG_FC_LCB_2003,
+            ),
+            (
+                self.fibRgFcLcb2007.is_some(),
+                "FibRgFcLcb2007",
+                "Word 2007 FC/LCB pairs, fcPlcfmthd - lcbColorSchemeMapping",
+                CB_RG_FC_LCB_2003,
+                CB_RG_FC_LCB_2007,
+            ),
+        ];
+        for (present, name, description, first, last) in later_blocks {
+            if present {
+                blocks.push((name, description, pair_offset(first), pair_offset(last)));
+            }
+        }
+
+        // pairs that are not part of a complete block known to us
+        let known_end = blocks.last().unwrap().3;
+        if known_end < rg_fc_lcb_end {
+            blocks.push((
+                "FibRgFcLcb unknown",
+                "FC/LCB pairs past the last known block",
+                known_end,
+                rg_fc_lcb_end,
+            ));
+        }
+
+        blocks.push((
+            "FibRgCswNew",
+            "cswNew and the array of new shorts, nFibNew - lidThemeCS",
+            rg_fc_lcb_end,
+            rg_fc_lcb_end + 2 + self.cswNew as u64 * 2,
+        ));
+
+        blocks
+    }
+}
+
+/// FC/LCB pairs added by Word 2000, follow the Word 97 pairs
+#[allow(non_snake_case, unused)]
+#[derive(Debug, Iterable, Serialize)]
+pub struct FibRgFcLcb2000 {
     pub fcPlcfTch: i32,
     pub lcbPlcfTch: u32,
     pub fcRmdfThreading: i32,
@@ -548,6 +704,12 @@ pub struct Fib {
     pub fcPgdMotherOld: FCPGDOLD,
     pub fcpgdFtnOld: FCPGDOLD,
     pub fcpgdEdnOld: FCPGDOLD,
+}
+
+/// FC/LCB pairs added by Word 2002, follow the Word 2000 pairs
+#[allow(non_snake_case, unused)]
+#[derive(Debug, Default, Iterable, Serialize)]
+pub struct FibRgFcLcb2002 {
     pub fcUnused1: i32,
     pub lcbUnused1: u32,
     pub fcPlcfPgp: i32,
@@ -582,6 +744,8 @@ pub struct Fib {
     pub lcbSttbfbkmkBPRepairs: u32,
     pub fcPlcfbkfBPRepairs: i32,
     pub lcbPlcfbkfBPRepairs: u32,
+    pub fcPlcfbklBPRepairs: i32,
+    pub lcbPlcfbklBPRepairs: u32,
     pub fcPmsNew: i32,
     pub lcbPmsNew: u32,
     pub fcODSO: i32,
@@ -602,6 +766,12 @@ pub struct Fib {
     pub lcbPlcflvcNewXP: u32,
     pub fcPlcflvcMixedXP: i32,
     pub lcbPlcflvcMixedXP: u32,
+}
+
+/// FC/LCB pairs added by Word 2003, follow the Word 2002 pairs
+#[allow(non_snake_case, unused)]
+#[derive(Debug, Iterable, Serialize)]
+pub struct FibRgFcLcb2003 {
     pub fcHplxsdr: i32,
     pub lcbHplxsdr: u32,
     pub fcSttbfBkmkSdt: i32,
@@ -638,18 +808,463 @@ pub struct Fib {
     pub lcbPlcflvcNew: u32,
     pub fcPlcflvcNewInline: i32,
     pub lcbPlcflvcNewInline: u32,
-    pub fcpgdMother: FCPGDOLD,
-    pub fcpgdFtn: FCPGDOLD,
-    pub fcpgdEdn: FCPGDOLD,
+    pub fcPgdMother: i32,
+    pub lcbPgdMother: u32,
+    pub fcBkdMother: i32,
+    pub lcbBkdMother: u32,
+    pub fcAfdMother: i32,
+    pub lcbAfdMother: u32,
+    pub fcPgdFtn: i32,
+    pub lcbPgdFtn: u32,
+    pub fcBkdFtn: i32,
+    pub lcbBkdFtn: u32,
+    pub fcAfdFtn: i32,
+    pub lcbAfdFtn: u32,
+    pub fcPgdEdn: i32,
+    pub lcbPgdEdn: u32,
+    pub fcBkdEdn: i32,
+    pub lcbBkdEdn: u32,
+    pub fcAfdEdn: i32,
+    pub lcbAfdEdn: u32,
     pub fcAfd: i32,
     pub lcbAfd: u32,
-    pub cswNew: u16,
-    pub actualNFib: u16,
-    pub cQuickSavesNew: u16,
 }
 
+/// FC/LCB pairs added by Word 2007, follow the Word 2003 pairs
 #[allow(non_snake_case, unused)]
-#[derive(Debug, Serialize)]
+#[derive(Debug, Iterable, Serialize)]
+pub struct FibRgFcLcb2007 {
+    pub fcPlcfmthd: i32,
+    pub lcbPlcfmthd: u32,
+    pub fcSttbfBkmkMoveFrom: i32,
+    pub lcbSttbfBkmkMoveFrom: u32,
+    pub fcPlcfBkfMoveFrom: i32,
+    pub lcbPlcfBkfMoveFrom: u32,
+    pub fcPlcfBklMoveFrom: i32,
+    pub lcbPlcfBklMoveFrom: u32,
+    pub fcSttbfBkmkMoveTo: i32,
+    pub lcbSttbfBkmkMoveTo: u32,
+    pub fcPlcfBkfMoveTo: i32,
+    pub lcbPlcfBkfMoveTo: u32,
+    pub fcPlcfBklMoveTo: i32,
+    pub lcbPlcfBklMoveTo: u32,
+    pub fcUnused1: i32,
+    pub lcbUnused1: u32,
+    pub fcUnused2: i32,
+    pub lcbUnused2: u32,
+    pub fcUnused3: i32,
+    pub lcbUnused3: u32,
+    pub fcSttbfBkmkArto: i32,
+    pub lcbSttbfBkmkArto: u32,
+    pub fcPlcfBkfArto: i32,
+    pub lcbPlcfBkfArto: u32,
+    pub fcPlcfBklArto: i32,
+    pub lcbPlcfBklArto: u32,
+    pub fcArtoData: i32,
+    pub lcbArtoData: u32,
+    pub fcUnused4: i32,
+    pub lcbUnused4: u32,
+    pub fcUnused5: i32,
+    pub lcbUnused5: u32,
+    pub fcUnused6: i32,
+    pub lcbUnused6: u32,
+    pub fcOssTheme: i32,
+    pub lcbOssTheme: u32,
+    pub fcColorSchemeMapping: i32,
+    pub lcbColorSchemeMapping: u32,
+}
+
+/// The FIB of Word 6.0 and Word 95 files (nFib 101 to 105). It has a fixed size and its fc/lcb
+/// pairs point into the WordDocument stream, as these files have no table stream
+#[allow(non_snake_case, unused)]
+#[derive(Debug, Iterable, Serialize)]
+pub struct Fib6 {
+    pub wIdent: Bytes,
+    pub nFib: u16,
+    pub nProduct: u16,
+    pub Lid: u16,
+    pub pnNext: i16,
+    pub fDot: bool,
+    pub fGlsy: bool,
+    pub fComplex: bool,
+    pub fHasPic: bool,
+    pub cQuickSaves: u8,
+    pub fEncrypted: bool,
+    pub fReserved: bool,
+    pub fReadOnlyRecommended: bool,
+    pub fWriteReservation: bool,
+    pub fExtChar: bool,
+    pub nFibBack: u16,
+    pub lKey: Bytes,
+    pub Envr: u8,
+    pub fMac: bool,
+    pub Chs: u16,
+    pub chsTables: u16,
+    pub fcMin: i32,
+    pub fcMac: i32,
+    pub cbMac: i32,
+    pub fcSpare0: i32,
+    pub fcSpare1: i32,
+    pub fcSpare2: i32,
+    pub fcSpare3: i32,
+    /* Lengths for the approprate Text Sections */
+    pub ccpText: i32,
+    pub ccpFtn: i32,
+    pub ccpHdr: i32,
+    pub ccpMcr: i32,
+    pub ccpAtn: i32,
+    pub ccpEdn: i32,
+    pub ccpTxbx: i32,
+    pub ccpHrdTxbx: i32,
+    pub ccpSpare2: i32,
+    /* Begining of FC/LCB pairs */
+    pub fcStshfOrig: i32,
+    pub lcbStshfOrig: u32,
+    pub fcStshf: i32,
+    pub lcbStshf: u32,
+    pub fcPlcffndRef: i32,
+    pub lcbPlcffndRef: u32,
+    pub fcPlcffndTxt: i32,
+    pub lcbPlcffndTxt: u32,
+    pub fcPlcfandRef: i32,
+    pub lcbPlcfandRef: u32,
+    pub fcPlcfandTxt: i32,
+    pub lcbPlcfandTxt: u32,
+    pub fcPlcfsed: i32,
+    pub lcbPlcfsed: u32,
+    pub fcPlcfpgd: i32,
+    pub lcbPlcfpgd: u32,
+    pub fcPlcfphe: i32,
+    pub lcbPlcfphe: u32,
+    pub fcSttbfglsy: i32,
+    pub lcbSttbfglsy: u32,
+    pub fcPlcfglsy: i32,
+    pub lcbPlcfglsy: u32,
+    pub fcPlcfhdd: i32,
+    pub lcbPlcfhdd: u32,
+    pub fcPlcfbteChpx: i32,
+    pub lcbPlcfbteChpx: u32,
+    pub fcPlcfbtePapx: i32,
+    pub lcbPlcfbtePapx: u32,
+    pub fcPlcfsea: i32,
+    pub lcbPlcfsea: u32,
+    pub fcsttbfffn: i32,
+    pub lcbsttbfffn: u32,
+    pub fcPlcffldMom: i32,
+    pub lcbPlcffldMom: u32,
+    pub fcPlcffldHdr: i32,
+    pub lcbPlcffldHdr: u32,
+    pub fcPlcffldFtn: i32,
+    pub lcbPlcffldFtn: u32,
+    pub fcPlcffldAtn: i32,
+    pub lcbPlcffldAtn: u32,
+    pub fcPlcffldMcr: i32,
+    pub lcbPlcffldMcr: u32,
+    pub fcSttbfbkmk: i32,
+    pub lcbSttbfbkmk: u32,
+    pub fcPlcfbkf: i32,
+    pub lcbPlcfbkf: u32,
+    pub fcPlcfbkl: i32,
+    pub lcbPlcfbkl: u32,
+    pub fcCmds: i32,
+    pub lcbCmds: u32,
+    pub fcPlcmcr: i32,
+    pub lcbPlcmcr: u32,
+    pub fcSttbfmcr: i32,
+    pub lcbSttbfmcr: u32,
+    pub fcPrDrvr: i32,
+    pub lcbPrDrvr: u32,
+    pub fcPrEnvPort: i32,
+    pub lcbPrEnvPort: u32,
+    pub fcPrEnvLand: i32,
+    pub lcbPrEnvLand: u32,
+    pub fcWss: i32,
+    pub lcbWss: u32,
+    pub fcDop: i32,
+    pub lcbDop: u32,
+    pub fcSttbfAssoc: i32,
+    pub lcbSttbfAssoc: u32,
+    pub fcClx: i32,
+    pub lcbClx: u32,
+    pub fcPlcfpgdFtn: i32,
+    pub lcbPlcfpgdFtn: u32,
+    pub fcAutosaveSource: i32,
+    pub lcbAutosaveSource: u32,
+    pub fcGrpStAtnOwners: i32,
+    pub lcbGrpStAtnOwners: u32,
+    pub fcSttbfAtnBkmk: i32,
+    pub lcbSttbfAtnBkmk: u32,
+    pub wSpare4Fib: u16,
+    pub pnChpFirst: u16,
+    pub pnPapFirst: u16,
+    pub cpnBteChp: u16,
+    pub cpnBtePap: u16,
+    pub fcPlcdoaMom: i32,
+    pub lcbPlcdoaMom: u32,
+    pub fcPlcdoaHdr: i32,
+    pub lcbPlcdoaHdr: u32,
+    pub fcUnused1: i32,
+    pub lcbUnused1: u32,
+    pub fcUnused2: i32,
+    pub lcbUnused2: u32,
+    pub fcPlcfAtnbkf: i32,
+    pub lcbPlcfAtnbkf: u32,
+    pub fcPlcfAtnbkl: i32,
+    pub lcbPlcfAtnbkl: u32,
+    pub fcPms: i32,
+    pub lcbPms: u32,
+    pub fcFormFldSttbs: i32,
+    pub lcbFormFldSttbs: u32,
+    pub fcPlcfendRef: i32,
+    pub lcbPlcfendRef: u32,
+    pub fcPlcfendTxt: i32,
+    pub lcbPlcfendTxt: u32,
+    pub fcPlcffldEdn: i32,
+    pub lcbPlcffldEdn: u32,
+    pub fcPlcfpgdEdn: i32,
+    pub lcbPlcfpgdEdn: u32,
+    pub fcUnused3: i32,
+    pub lcbUnused3: u32,
+    pub fcSttbfRMark: i32,
+    pub lcbSttbfRMark: u32,
+    pub fcSttbfCaption: i32,
+    pub lcbSttbfCaption: u32,
+    pub fcSttbfAutoCaption: i32,
+    pub lcbSttbfAutoCaption: u32,
+    pub fcPlcfWkb: i32,
+    pub lcbPlcfWkb: u32,
+    pub fcUnused4: i32,
+    pub lcbUnused4: u32,
+    pub fcPlcftxbxTxt: i32,
+    pub lcbPlcftxbxTxt: u32,
+    pub fcPlcffldTxbx: i32,
+    pub lcbPlcffldTxbx: u32,
+    pub fcPlcfhdrtxbxTxt: i32,
+    pub lcbPlcfhdrtxbxTxt: u32,
+    pub fcPlcffldHdrTxbx: i32,
+    pub lcbPlcffldHdrTxbx: u32,
+    pub fcStwUser: i32,
+    pub lcbStwUser: u32,
+    pub fcSttbTtmbd: i32,
+    pub lcbSttbTtmbd: u32,
+    pub fcUnused5: i32,
+    pub lcbUnused5: u32,
+    pub fcPgdMother: i32,
+    pub lcbPgdMother: u32,
+    pub fcBkdMother: i32,
+    pub lcbBkdMother: u32,
+    pub fcPgdFtn: i32,
+    pub lcbPgdFtn: u32,
+    pub fcBkdFtn: i32,
+    pub lcbBkdFtn: u32,
+    pub fcPgdEdn: i32,
+    pub lcbPgdEdn: u32,
+    pub fcBkdEdn: i32,
+    pub lcbBkdEdn: u32,
+    pub fcSttbfIntlFld: i32,
+    pub lcbSttbfIntlFld: u32,
+    pub fcRouteSlip: i32,
+    pub lcbRouteSlip: u32,
+    pub fcSttbSavedBy: i32,
+    pub lcbSttbSavedBy: u32,
+    pub fcSttbFnm: i32,
+    pub lcbSttbFnm: u32,
+}
+
+/// The fields the Word 6/95 FIB shares with the Word 97 FIB, so the rest of the decoder can read a
+/// Word 6/95 file like a modern one. Everything else is left zeroed or absent
+impl From<&Fib6> for Fib {
+    fn from(fib6: &Fib6) -> Self {
+        Fib {
+            wIdent: Bytes::new(fib6.wIdent.bytes.clone()),
+            nFib: fib6.nFib,
+            nProduct: fib6.nProduct,
+            Lid: fib6.Lid,
+            pnNext: fib6.pnNext,
+            fDot: fib6.fDot,
+            fGlsy: fib6.fGlsy,
+            fComplex: fib6.fComplex,
+            fHasPic: fib6.fHasPic,
+            cQuickSaves: fib6.cQuickSaves,
+            fEncrypted: fib6.fEncrypted,
+            fReadOnlyRecommended: fib6.fReadOnlyRecommended,
+            fWriteReservation: fib6.fWriteReservation,
+            fExtChar: fib6.fExtChar,
+            nFibBack: fib6.nFibBack,
+            lKey: Bytes::new(fib6.lKey.bytes.clone()),
+            Envr: fib6.Envr,
+            fMac: fib6.fMac,
+            Chs: fib6.Chs,
+            chsTables: fib6.chsTables,
+            fcMin: fib6.fcMin,
+            fcMac: fib6.fcMac,
+            cbMac: fib6.cbMac,
+            ccpText: fib6.ccpText,
+            ccpFtn: fib6.ccpFtn,
+            ccpHdr: fib6.ccpHdr,
+            ccpMcr: fib6.ccpMcr,
+            ccpAtn: fib6.ccpAtn,
+            ccpEdn: fib6.ccpEdn,
+            ccpTxbx: fib6.ccpTxbx,
+            ccpHrdTxbx: fib6.ccpHrdTxbx,
+            fcStshfOrig: fib6.fcStshfOrig,
+            lcbStshfOrig: fib6.lcbStshfOrig,
+            fcStshf: fib6.fcStshf,
+            lcbStshf: fib6.lcbStshf,
+            fcPlcffndRef: fib6.fcPlcffndRef,
+            lcbPlcffndRef: fib6.lcbPlcffndRef,
+            fcPlcffndTxt: fib6.fcPlcffndTxt,
+            lcbPlcffndTxt: fib6.lcbPlcffndTxt,
+            fcPlcfandRef: fib6.fcPlcfandRef,
+            lcbPlcfandRef: fib6.lcbPlcfandRef,
+            fcPlcfandTxt: fib6.fcPlcfandTxt,
+            lcbPlcfandTxt: fib6.lcbPlcfandTxt,
+            fcPlcfsed: fib6.fcPlcfsed,
+            lcbPlcfsed: fib6.lcbPlcfsed,
+            fcPlcfphe: fib6.fcPlcfphe,
+            lcbPlcfphe: fib6.lcbPlcfphe,
+            fcSttbfglsy: fib6.fcSttbfglsy,
+            lcbSttbfglsy: fib6.lcbSttbfglsy,
+            fcPlcfglsy: fib6.fcPlcfglsy,
+            lcbPlcfglsy: fib6.lcbPlcfglsy,
+            fcPlcfhdd: fib6.fcPlcfhdd,
+            lcbPlcfhdd: fib6.lcbPlcfhdd,
+            fcPlcfbteChpx: fib6.fcPlcfbteChpx,
+            lcbPlcfbteChpx: fib6.lcbPlcfbteChpx,
+            fcPlcfbtePapx: fib6.fcPlcfbtePapx,
+            lcbPlcfbtePapx: fib6.lcbPlcfbtePapx,
+            fcPlcfsea: fib6.fcPlcfsea,
+            lcbPlcfsea: fib6.lcbPlcfsea,
+            fcsttbfffn: fib6.fcsttbfffn,
+            lcbsttbfffn: fib6.lcbsttbfffn,
+            fcPlcffldMom: fib6.fcPlcffldMom,
+            lcbPlcffldMom: fib6.lcbPlcffldMom,
+            fcPlcffldHdr: fib6.fcPlcffldHdr,
+            lcbPlcffldHdr: fib6.lcbPlcffldHdr,
+            fcPlcffldFtn: fib6.fcPlcffldFtn,
+            lcbPlcffldFtn: fib6.lcbPlcffldFtn,
+            fcPlcffldAtn: fib6.fcPlcffldAtn,
+            lcbPlcffldAtn: fib6.lcbPlcffldAtn,
+            fcPlcffldMcr: fib6.fcPlcffldMcr,
+            lcbPlcffldMcr: fib6.lcbPlcffldMcr,
+            fcSttbfbkmk: fib6.fcSttbfbkmk,
+            lcbSttbfbkmk: fib6.lcbSttbfbkmk,
+            fcPlcfbkf: fib6.fcPlcfbkf,
+            lcbPlcfbkf: fib6.lcbPlcfbkf,
+            fcPlcfbkl: fib6.fcPlcfbkl,
+            lcbPlcfbkl: fib6.lcbPlcfbkl,
+            fcCmds: fib6.fcCmds,
+            lcbCmds: fib6.lcbCmds,
+            fcPlcmcr: fib6.fcPlcmcr,
+            lcbPlcmcr: fib6.lcbPlcmcr,
+            fcSttbfmcr: fib6.fcSttbfmcr,
+            lcbSttbfmcr: fib6.lcbSttbfmcr,
+            fcPrDrvr: fib6.fcPrDrvr,
+            lcbPrDrvr: fib6.lcbPrDrvr,
+            fcPrEnvPort: fib6.fcPrEnvPort,
+            lcbPrEnvPort: fib6.lcbPrEnvPort,
+            fcPrEnvLand: fib6.fcPrEnvLand,
+            lcbPrEnvLand: fib6.lcbPrEnvLand,
+            fcWss: fib6.fcWss,
+            lcbWss: fib6.lcbWss,
+            fcDop: fib6.fcDop,
+            lcbDop: fib6.lcbDop,
+            fcSttbfAssoc: fib6.fcSttbfAssoc,
+            lcbSttbfAssoc: fib6.lcbSttbfAssoc,
+            fcClx: fib6.fcClx,
+            lcbClx: fib6.lcbClx as i32,
+            fcPlcfpgdFtn: fib6.fcPlcfpgdFtn,
+            lcbPlcfpgdFtn: fib6.lcbPlcfpgdFtn,
+            fcAutosaveSource: fib6.fcAutosaveSource,
+            lcbAutosaveSource: fib6.lcbAutosaveSource,
+            fcSttbfAtnBkmk: fib6.fcSttbfAtnBkmk,
+            lcbSttbfAtnBkmk: fib6.lcbSttbfAtnBkmk,
+            pnChpFirst: fib6.pnChpFirst as i32,
+            pnPapFirst: fib6.pnPapFirst as i32,
+            cpnBteChp: fib6.cpnBteChp as i32,
+            cpnBtePap: fib6.cpnBtePap as i32,
+            fcPlcdoaMom: fib6.fcPlcdoaMom,
+            lcbPlcdoaMom: fib6.lcbPlcdoaMom,
+            fcPlcdoaHdr: fib6.fcPlcdoaHdr,
+            lcbPlcdoaHdr: fib6.lcbPlcdoaHdr,
+            fcPlcfAtnbkf: fib6.fcPlcfAtnbkf,
+            lcbPlcfAtnbkf: fib6.lcbPlcfAtnbkf,
+            fcPlcfAtnbkl: fib6.fcPlcfAtnbkl,
+            lcbPlcfAtnbkl: fib6.lcbPlcfAtnbkl,
+            fcPms: fib6.fcPms,
+            lcbPms: fib6.lcbPms,
+            fcFormFldSttbs: fib6.fcFormFldSttbs,
+            lcbFormFldSttbs: fib6.lcbFormFldSttbs,
+            fcPlcfendRef: fib6.fcPlcfendRef,
+            lcbPlcfendRef: fib6.lcbPlcfendRef,
+            fcPlcfendTxt: fib6.fcPlcfendTxt,
+            lcbPlcfendTxt: fib6.lcbPlcfendTxt,
+            fcPlcffldEdn: fib6.fcPlcffldEdn,
+            lcbPlcffldEdn: fib6.lcbPlcffldEdn,
+            fcPlcfpgdEdn: fib6.fcPlcfpgdEdn,
+            lcbPlcfpgdEdn: fib6.lcbPlcfpgdEdn,
+            fcSttbfRMark: fib6.fcSttbfRMark,
+            lcbSttbfRMark: fib6.lcbSttbfRMark,
+            fcSttbfCaption: fib6.fcSttbfCaption,
+            lcbSttbfCaption: fib6.lcbSttbfCaption,
+            fcSttbfAutoCaption: fib6.fcSttbfAutoCaption,
+            lcbSttbfAutoCaption: fib6.lcbSttbfAutoCaption,
+            fcPlcfWkb: fib6.fcPlcfWkb,
+            lcbPlcfWkb: fib6.lcbPlcfWkb,
+            fcPlcftxbxTxt: fib6.fcPlcftxbxTxt,
+            lcbPlcftxbxTxt: fib6.lcbPlcftxbxTxt,
+            fcPlcffldTxbx: fib6.fcPlcffldTxbx,
+            lcbPlcffldTxbx: fib6.lcbPlcffldTxbx,
+            fcPlcfhdrtxbxTxt: fib6.fcPlcfhdrtxbxTxt,
+            lcbPlcfhdrtxbxTxt: fib6.lcbPlcfhdrtxbxTxt,
+            fcPlcffldHdrTxbx: fib6.fcPlcffldHdrTxbx,
+            lcbPlcffldHdrTxbx: fib6.lcbPlcffldHdrTxbx,
+            fcStwUser: fib6.fcStwUser,
+            lcbStwUser: fib6.lcbStwUser,
+            fcSttbTtmbd: fib6.fcSttbTtmbd,
+            lcbSttbTtmbd: fib6.lcbSttbTtmbd,
+            fcSttbfIntlFld: fib6.fcSttbfIntlFld,
+            lcbSttbfIntlFld: fib6.lcbSttbfIntlFld,
+            fcRouteSlip: fib6.fcRouteSlip,
+            lcbRouteSlip: fib6.lcbRouteSlip,
+            fcSttbSavedBy: fib6.fcSttbSavedBy,
+            lcbSttbSavedBy: fib6.lcbSttbSavedBy,
+            fcSttbFnm: fib6.fcSttbFnm,
+            lcbSttbFnm: fib6.lcbSttbFnm,
+            ..Default::default()
+        }
+    }
+}
+
+/// The nFib values written by Word 6.0 and Word 95
+pub const NFIB_WORD_6: std::ops::RangeInclusive<u16> = 101..=105;
+
+impl Fib6 {
+    /// The blocks of the Word 6/95 FIB in the WordDocument stream as (name, description, start,
+    /// end). The FibBase is laid out the same as the one of the Word 97 FIB
+    pub fn blocks(&self) -> Vec<(&'static str, &'static str, u64, u64)> {
+        vec![
+            ("FibBase", "wIdent - fcMac", 0, 0x20),
+            ("FibRgLw6", "cbMac - ccpSpare2", 0x20, 0x58),
+            (
+                "FibRgFcLcb6",
+                "FC/LCB pairs, fcStshfOrig - lcbSttbfAtnBkmk",
+                0x58,
+                0x188,
+            ),
+            ("FibRgW6", "wSpare4Fib - cpnBtePap", 0x188, 0x192),
+            (
+                "FibRgFcLcb6 cont.",
+                "FC/LCB pairs, fcPlcdoaMom - lcbSttbFnm",
+                0x192,
+                0x2AA,
+            ),
+        ]
+    }
+}
+
+#[allow(non_snake_case, unused)]
+#[derive(Debug, Default, Serialize)]
 pub struct FCPGDOLD {
     pub fcPgd: i32,
     pub lcbPgd: u32,
@@ -657,9 +1272,835 @@ pub struct FCPGDOLD {
     pub lcbBkd: u32,
 }
 
-/// List Tables
+/// The ways a binary Word document can be protected with a password, see [MS-OFFCRYPTO]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EncryptionType {
+    /// XOR obfuscation, the password verifier is stored in `Fib.lKey`
+    XorObfuscation,
+    /// Office binary document RC4 encryption
+    Rc4,
+    /// RC4 CryptoAPI encryption
+    Rc4CryptoApi,
+}
+
+impl std::fmt::Display for EncryptionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EncryptionType::XorObfuscation => write!(f, "XOR obfuscation"),
+            EncryptionType::Rc4 => write!(f, "RC4"),
+            EncryptionType::Rc4CryptoApi => write!(f, "RC4 CryptoAPI"),
+        }
+    }
+}
+
+/// How an encrypted document is protected, read from the FIB and the EncryptionHeader at the
+/// start of the table stream
+#[allow(non_snake_case, unused)]
+#[derive(Debug, Iterable, Serialize)]
+pub struct Encryption {
+    pub encryptionType: EncryptionType,
+    /// Size of the EncryptionHeader, these bytes of the table stream are not encrypted
+    pub cbHeader: u32,
+    pub vMajor: Option<u16>,
+    pub vMinor: Option<u16>,
+    /* Only present for RC4 CryptoAPI */
+    pub AlgID: Option<u32>,
+    pub AlgIDHash: Option<u32>,
+    pub KeySize: Option<u32>,
+    pub ProviderType: Option<u32>,
+    pub CSPName: Option<String>,
+    /* Not present for XOR obfuscation */
+    pub Salt: Option<Bytes>,
+    pub EncryptedVerifier: Option<Bytes>,
+    pub EncryptedVerifierHash: Option<Bytes>,
+    /// Whether the streams were decrypted with a password before they were decoded
+    pub decrypted: bool,
+}
+
+/// Bin table entry of the PlcBteChpx, the page of the WordDocument stream with a ChpxFkp
+#[allow(non_snake_case, unused)]
+#[derive(Debug, Serialize)]
+pub struct PnFkpChpx {
+    pub pn: u32,
+}
+
+/// Footnote or endnote reference descriptor of the PlcffndRef and PlcfendRef
+#[allow(non_snake_case)]
+#[derive(Debug, Serialize)]
+pub struct FRD {
+    /// Zero if the reference uses a custom mark, auto-numbered otherwise
+    pub nAuto: i16,
+}
+
+/// Annotation reference descriptor of the PlcfandRef as written before Word 2002
+#[allow(non_snake_case)]
+#[derive(Debug, Serialize)]
+pub struct ATRDPre10 {
+    /// Initials of the author
+    pub xstUsrInitl: String,
+    /// Index of the author in the GrpXstAtnOwners
+    pub ibst: i16,
+    /// lTag of the bookmark of the commented range in the SttbfAtnBkmk, -1 if there is none
+    pub lTagBkmk: i32,
+}
+
+/// Field character descriptor of the PlcFld of a subdocument
+#[derive(Debug, Serialize)]
+pub struct FLD {
+    /// 0x13, 0x14 or 0x15 for the field begin, separator and end
+    pub ch: u8,
+    /// The flt of a field begin, the flags of a field end
+    pub grffld: u8,
+}
+
+/// Bookmark start descriptor of the PlcfBkf and PlcfAtnBkf
+#[derive(Debug, Serialize)]
+pub struct FBKF {
+    /// Index of the end of the bookmark in the PlcfBkl
+    pub ibkl: u16,
+    /// The columns of a table the bookmark spans
+    pub bkc: u16,
+}
+
+/// A single property modifier, the sprm and its operand
+#[derive(Debug, Clone, Serialize)]
+pub struct Prl {
+    pub sprm: u16,
+    pub operand: Vec<u8>,
+}
+
+/// The character properties of a run of text, from a ChpxFkp
+#[derive(Debug)]
+pub struct Chpx {
+    /// Offset of the first character of the run in the WordDocument stream
+    pub fc_start: i32,
+    /// Offset after the last character of the run
+    pub fc_end: i32,
+    pub grpprl: Vec<Prl>,
+}
+
+/// The paragraph properties of a run of text, from a PapxFkp
+#[derive(Debug)]
+pub struct Papx {
+    /// Offset of the first character of the run in the WordDocument stream
+    pub fc_start: i32,
+    /// Offset after the last character of the run
+    pub fc_end: i32,
+    /// Style of the paragraph
+    pub istd: u16,
+    pub grpprl: Vec<Prl>,
+}
+
+/// sprmCPicLocation, the offset of a picture or of binary data in the Data stream
+pub const SPRM_C_PIC_LOCATION: u16 = 0x6A03;
+/// sprmCFData, set when sprmCPicLocation points at binary data of a field instead of a picture
+pub const SPRM_C_F_DATA: u16 = 0x0806;
+/// sprmCFOle2, set when sprmCPicLocation is the id of an embedded object in the ObjectPool
+pub const SPRM_C_F_OLE2: u16 = 0x080A;
+
+impl Chpx {
+    /// Whether the toggle property `sprm` is set to 1
+    pub fn is_set(&self, sprm: u16) -> bool {
+        self.grpprl
+            .iter()
+            .any(|prl| prl.sprm == sprm && prl.operand.first() == Some(&1))
+    }
+
+    /// The operand of sprmCPicLocation, if the run has one
+    pub fn pic_location(&self) -> Option<u32> {
+        self.grpprl.iter().find_map(|prl| {
+            (prl.sprm == SPRM_C_PIC_LOCATION && prl.operand.len() == 4).then(|| {
+                u32::from_le_bytes([
+                    prl.operand[0],
+                    prl.operand[1],
+                    prl.operand[2],
+                    prl.operand[3],
+                ])
+            })
+        })
+    }
+}
+
+/// The header of every OfficeArt record
+#[allow(non_snake_case, unused)]
+#[derive(Debug, Clone, Iterable, Serialize)]
+pub struct OfficeArtRecordHeader {
+    /// 0xF for containers
+    pub recVer: u8,
+    pub recInstance: u16,
+    pub recType: u16,
+    /// Length of the record data after the header
+    pub recLen: u32,
+}
+
+impl OfficeArtRecordHeader {
+    /// Name of the record type as used in [MS-ODRAW]
+    pub fn record_name(&self) -> &'static str {
+        match self.recType {
+            0xF000 => "OfficeArtDggContainer",
+            0xF001 => "OfficeArtBStoreContainer",
+            0xF002 => "OfficeArtDgContainer",
+            0xF003 => "OfficeArtSpgrContainer",
+            0xF004 => "OfficeArtSpContainer",
+            0xF005 => "OfficeArtSolverContainer",
+            0xF006 => "OfficeArtFDGGBlock",
+            0xF007 => "OfficeArtFBSE",
+            0xF008 => "OfficeArtFDG",
+            0xF009 => "OfficeArtFSPGR",
+            0xF00A => "OfficeArtFSP",
+            0xF00B => "OfficeArtFOPT",
+            0xF00D => "OfficeArtClientTextbox",
+            0xF00F => "OfficeArtChildAnchor",
+            0xF010 => "OfficeArtClientAnchor",
+            0xF011 => "OfficeArtClientData",
+            0xF012 => "OfficeArtFConnectorRule",
+            0xF014 => "OfficeArtFArcRule",
+            0xF017 => "OfficeArtFCalloutRule",
+            0xF018..=0xF117 => "OfficeArtBlip",
+            0xF118 => "OfficeArtFRITContainer",
+            0xF119 => "OfficeArtFDGSL",
+            0xF11A => "OfficeArtColorMRUContainer",
+            0xF11D => "OfficeArtFPSPL",
+            0xF11E => "OfficeArtSplitMenuColorContainer",
+            0xF121 => "OfficeArtSecondaryFOPT",
+            0xF122 => "OfficeArtTertiaryFOPT",
+            _ => "OfficeArtRecord",
+        }
+    }
+}
+
+/// Name of an OfficeArt property, for the properties that are most often set in Word documents
+pub fn office_art_property_name(pid: u16) -> Option<&'static str> {
+    match pid {
+        0x0004 => Some("rotation"),
+        0x007F => Some("Protection Boolean Properties"),
+        0x0080 => Some("lTxid"),
+        0x0081 => Some("dxTextLeft"),
+        0x0082 => Some("dyTextTop"),
+        0x0083 => Some("dxTextRight"),
+        0x0084 => Some("dyTextBottom"),
+        0x0085 => Some("WrapText"),
+        0x0087 => Some("anchorText"),
+        0x0088 => Some("txflTextFlow"),
+        0x008A => Some("hspNext"),
+        0x00BF => Some("Text Boolean Properties"),
+        0x00C0 => Some("gtextUNICODE"),
+        0x00C5 => Some("gtextFont"),
+        0x00FF => Some("Geometry Text Boolean Properties"),
+        0x0104 => Some("pib"),
+        0x0105 => Some("pibName"),
+        0x0106 => Some("pibFlags"),
+        0x013F => Some("Blip Boolean Properties"),
+        0x0144 => Some("shapePath"),
+        0x0145 => Some("pVertices"),
+        0x0146 => Some("pSegmentInfo"),
+        0x017F => Some("Geometry Boolean Properties"),
+        0x0180 => Some("fillType"),
+        0x0181 => Some("fillColor"),
+        0x0182 => Some("fillOpacity"),
+        0x0183 => Some("fillBackColor"),
+        0x0186 => Some("fillBlip"),
+        0x01BF => Some("Fill Style Boolean Properties"),
+        0x01C0 => Some("lineColor"),
+        0x01CB => Some("lineWidth"),
+        0x01CE => Some("lineDashing"),
+        0x01FF => Some("Line Style Boolean Properties"),
+        0x023F => Some("Shadow Style Boolean Properties"),
+        0x0304 => Some("bWMode"),
+        0x033F => Some("Shape Boolean Properties"),
+        0x0380 => Some("wzName"),
+        0x0381 => Some("wzDescription"),
+        0x0382 => Some("pihlShape"),
+        0x038D => Some("wzTooltip"),
+        0x038F => Some("posh"),
+        0x0390 => Some("posrelh"),
+        0x0391 => Some("posv"),
+        0x0392 => Some("posrelv"),
+        0x03A9 => Some("metroBlob"),
+        0x03BF => Some("Group Shape Boolean Properties"),
+        _ => None,
+    }
+}
+
+/// File BLIP Store Entry, describes a picture of the OfficeArt BLIP store
+#[allow(non_snake_case, unused)]
+#[derive(Debug, Iterable, Serialize)]
+pub struct OfficeArtFBSE {
+    pub btWin32: u8,
+    pub btMacOS: u8,
+    /// MD4 of the picture
+    pub rgbUid: Bytes,
+    pub tag: u16,
+    /// Size of the BLIP record in the delay stream
+    pub size: u32,
+    pub cRef: u32,
+    /// Offset of the BLIP record in the delay stream, the WordDocument stream for Word
+    pub foDelay: u32,
+    pub cbName: u8,
+    pub nameData: String,
+}
+
+/// The kinds of pictures stored as OfficeArt BLIPs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BlipType {
+    Emf,
+    Wmf,
+    Pict,
+    Jpeg,
+    Png,
+    Dib,
+    Tiff,
+    /// A picture of the Data stream whose PICF has an unknown `mm`
+    Unknown,
+}
+
+impl BlipType {
+    /// The BLIP type of an OfficeArt record type
+    pub fn from_rec_type(rec_type: u16) -> Option<Self> {
+        match rec_type {
+            0xF01A => Some(BlipType::Emf),
+            0xF01B => Some(BlipType::Wmf),
+            0xF01C => Some(BlipType::Pict),
+            0xF01D | 0xF02A => Some(BlipType::Jpeg),
+            0xF01E => Some(BlipType::Png),
+            0xF01F => Some(BlipType::Dib),
+            0xF029 => Some(BlipType::Tiff),
+            _ => None,
+        }
+    }
+
+    /// File extension of pictures of this type
+    pub fn extension(&self) -> &'static str {
+        match self {
+            BlipType::Emf => "emf",
+            BlipType::Wmf => "wmf",
+            BlipType::Pict => "pict",
+            BlipType::Jpeg => "jpg",
+            BlipType::Png => "png",
+            BlipType::Dib => "dib",
+            BlipType::Tiff => "tiff",
+            BlipType::Unknown => "bin",
+        }
+    }
+}
+
+/// Header of the PICFAndOfficeArtData of a picture in the Data stream
+#[allow(non_snake_case, unused)]
+#[derive(Debug, Iterable, Serialize)]
+pub struct PICF {
+    /// Size of the PICF and the picture data that follows it
+    pub lcb: u32,
+    pub cbHeader: u16,
+    /// Mapping mode of the picture, 0x64 or 0x66 when it is stored as OfficeArt
+    pub mm: u16,
+    pub xExt: u16,
+    pub yExt: u16,
+    pub swHMF: u16,
+    pub dxaGoal: i16,
+    pub dyaGoal: i16,
+    pub mx: u16,
+    pub my: u16,
+    pub bpp: u8,
+    pub cProps: u16,
+    /// Only present when mm is 0x66
+    pub stPicName: Option<String>,
+}
+
+/// A picture found in the document
+#[derive(Debug, Iterable, Serialize)]
+pub struct Picture {
+    /// Where the picture was found, `Data` for the Data stream or `BStore` for the BLIP store
+    pub source: String,
+    pub stream_name: String,
+    /// Offset of the PICF or the BLIP record in the stream
+    pub offset: u64,
+    /// Length of the PICF or the BLIP record in the stream
+    pub len: u64,
+    pub blip_type: BlipType,
+    /// Size of the picture in bytes, after decompression
+    pub size: usize,
+    /// SHA-256 of the picture, the same picture in another document has the same hash
+    pub sha256: String,
+    pub picf: Option<PICF>,
+    pub fbse: Option<OfficeArtFBSE>,
+    #[serde(skip)]
+    pub data: Vec<u8>,
+}
+
+/// The OfficeArtContent at `fcDggInfo`, the drawing group followed by the drawings of the main
+/// document and the headers
+#[derive(Debug, Serialize)]
+pub struct OfficeArtContent {
+    pub dgg_container: OfficeArtRecord,
+    pub drawings: Vec<OfficeArtWordDrawing>,
+}
+
+/// The drawing of the main document or of the headers
+#[allow(non_snake_case, unused)]
+#[derive(Debug, Serialize)]
+pub struct OfficeArtWordDrawing {
+    /// 0 for the main document, 1 for the headers
+    pub dgglbl: u8,
+    pub container: OfficeArtRecord,
+}
+
+/// An OfficeArt record, with the records of a container and the decoded fields of the atoms
+/// used to link shapes to the text
+#[allow(non_snake_case, unused)]
+#[derive(Debug, Serialize)]
+pub struct OfficeArtRecord {
+    /// Offset of the record header in the table stream
+    pub offset: u64,
+    pub header: OfficeArtRecordHeader,
+    pub children: Vec<OfficeArtRecord>,
+    /// The properties of an OfficeArtFOPT, OfficeArtSecondaryFOPT or OfficeArtTertiaryFOPT
+    pub properties: Vec<OfficeArtFOPTE>,
+    /// Shape identifier of an OfficeArtFSP
+    pub spid: Option<u32>,
+    /// Flags of an OfficeArtFSP, e.g. fGroup, fDeleted and fHaveAnchor
+    pub grfPersistent: Option<u32>,
+    /// Value of an OfficeArtClientTextbox, the high 16 bits are the 1-based index of the text box
+    pub clientTextbox: Option<u32>,
+}
+
+/// A property of an OfficeArt property table
+#[allow(non_snake_case, unused)]
+#[derive(Debug, Iterable, Serialize)]
+pub struct OfficeArtFOPTE {
+    pub pid: u16,
+    /// `op` is a BLIP identifier
+    pub fBid: bool,
+    /// `op` is the length of the complex data that follows the property table
+    pub fComplex: bool,
+    pub op: i32,
+    pub complexData: Option<Bytes>,
+}
+
+/// File Shape Address, anchors a shape to a character position
+#[allow(non_snake_case, unused)]
+#[derive(Debug, Iterable, Serialize)]
+pub struct FSPA {
+    /// Shape identifier, the spid of the OfficeArtFSP of the shape
+    pub spid: i32,
+    pub xaLeft: i32,
+    pub yaTop: i32,
+    pub xaRight: i32,
+    pub yaBottom: i32,
+    pub fHdr: bool,
+    pub bx: u8,
+    pub by: u8,
+    pub wr: u8,
+    pub wrk: u8,
+    pub fRcaSimple: bool,
+    pub fBelowText: bool,
+    pub fAnchorLock: bool,
+    pub cTxbx: i32,
+}
+
+/// Text box story, describes a text box of the PlcftxbxTxt or the PlcfHdrtxbxTxt
+#[allow(non_snake_case, unused)]
+#[derive(Debug, Iterable, Serialize)]
+pub struct FTXBXS {
+    /// cTxbx, the number of text boxes in the chain, or iNextReuse for a reusable entry
+    pub reusable01: i32,
+    /// cReusable, the number of reusable entries, for a reusable entry
+    pub reusable02: i32,
+    /// The entry is not used by a shape and may be reused
+    pub fReusable: i16,
+    /// Shape identifier of the shape of the text box
+    pub lid: i32,
+    pub txidUndo: i32,
+}
+
+/// A shape anchored in the main document or in the headers, with the text of its text box
+#[derive(Debug, Serialize)]
+pub struct Shape {
+    /// `main` or `header`
+    pub story: String,
+    /// Character position of the anchor
+    pub cp: i32,
+    pub fspa: FSPA,
+    /// Text of the text box of the shape in `textbox_text` or `header_textbox_text`
+    pub textbox_text: Option<String>,
+}
+
+/// The `\x01CompObj` stream of an OLE object
 #[allow(non_snake_case, unused)]
 #[derive(Debug)]
+pub struct CompObjStream {
+    pub AnsiUserType: String,
+    pub AnsiClipboardFormat: Option<String>,
+    /// The ProgID of the object
+    pub Reserved1: Option<String>,
+}
+
+/// An object embedded in the document, stored in a storage of the ObjectPool
+#[derive(Debug, Iterable, Serialize)]
+pub struct EmbeddedObject {
+    /// Name of the storage, `_` followed by the object id
+    pub storage_name: String,
+    /// Path of the storage in the compound file
+    pub storage_path: String,
+    pub clsid: String,
+    /// Display name of the object type, from the CompObj stream
+    pub user_type: Option<String>,
+    /// Clipboard format of the object, from the CompObj stream
+    pub clipboard_format: Option<String>,
+    /// Programmatic identifier, e.g. `Excel.Sheet.8` or `Package`, from the CompObj stream
+    pub prog_id: Option<String>,
+    /// The object is linked to a file instead of embedded, from the Ole stream
+    pub linked: Option<bool>,
+    /// Flags of the ObjInfo stream, e.g. fLink, fIcon and fOCX
+    pub odt: Option<u16>,
+    /// Label of an OLE 1.0 Package
+    pub package_label: Option<String>,
+    /// Original path of the file in an OLE 1.0 Package
+    pub package_path: Option<String>,
+    /// Where the payload comes from, a stream of the storage or `storage` when it is the
+    /// storage itself saved as a compound file
+    pub payload_source: Option<String>,
+    pub payload_size: Option<usize>,
+    pub payload_sha256: Option<String>,
+    /// Character position of the object in the main document
+    pub cp: Option<i32>,
+    /// Instructions of the field the object belongs to, e.g. `EMBED Excel.Sheet.8`
+    pub field_code: Option<String>,
+    /// Streams of the storage with their sizes
+    pub streams: Vec<(String, u64)>,
+    #[serde(skip)]
+    pub payload: Vec<u8>,
+}
+
+impl EmbeddedObject {
+    /// File extension used when the payload is extracted
+    pub fn extension(&self) -> String {
+        let label_extension = self
+            .package_label
+            .as_deref()
+            .and_then(|label| label.rsplit_once('.'))
+            .map(|(_, extension)| extension)
+            .filter(|extension| extension.chars().all(|c| c.is_ascii_alphanumeric()));
+
+        match (label_extension, self.payload_source.as_deref()) {
+            (Some(extension), _) => extension.to_string(),
+            (None, Some("storage")) => "cfb".to_string(),
+            _ => "bin".to_string(),
+        }
+    }
+}
+
+/// The VBA project of the `Macros` storage, from its `dir` stream
+#[derive(Debug, Iterable, Serialize)]
+pub struct VbaProject {
+    /// Path of the VBA storage in the compound file
+    pub storage_path: String,
+    pub name: Option<String>,
+    pub doc_string: Option<String>,
+    /// 0 for 16-bit Windows, 1 for 32-bit Windows, 2 for the Macintosh and 3 for 64-bit Windows
+    pub sys_kind: Option<u32>,
+    pub code_page: Option<u16>,
+    /// Version of the project as `major.minor`
+    pub version: Option<String>,
+    /// Conditional compilation constants
+    pub constants: Option<String>,
+    /// Names of the referenced libraries and projects
+    pub references: Vec<String>,
+    pub modules: Vec<VbaModule>,
+}
+
+/// A module of a VBA project with its decompressed source code
+#[derive(Debug, Iterable, Serialize)]
+pub struct VbaModule {
+    pub name: String,
+    pub stream_name: String,
+    /// `procedural` for modules, `document` for document, class and form modules
+    pub module_type: String,
+    /// Offset of the compressed source code in the module stream, the p-code comes before it
+    pub text_offset: u32,
+    pub read_only: bool,
+    pub private: bool,
+    /// Procedures that run without user interaction, e.g. AutoOpen or Document_Open
+    pub auto_exec: Vec<String>,
+    pub source_size: usize,
+    pub source_sha256: String,
+    pub source: String,
+}
+
+/// A story of the header document, a separator of the notes or a header or footer of a section
+#[derive(Debug, Iterable, Serialize)]
+pub struct HeaderStory {
+    /// e.g. `Footnote separator` or `Section 2 – odd footer`
+    pub name: String,
+    /// The section of a header or footer, from 1
+    pub section: Option<u32>,
+    /// e.g. `footnote separator` or `odd footer`
+    pub kind: String,
+    /// First CP of the story in the header document
+    pub cp_start: i32,
+    /// CP past the end of the story, the story is inherited from the section before if it is
+    /// `cp_start`
+    pub cp_end: i32,
+    pub text: String,
+}
+
+/// A footnote or endnote with its reference in the main document
+#[derive(Debug, Iterable, Serialize)]
+pub struct Note {
+    /// `footnote` or `endnote`
+    pub kind: String,
+    /// Whether the reference is numbered by Word or uses a custom mark
+    pub auto_numbered: bool,
+    /// Ordinal of the note among the auto-numbered notes of its kind, from 1
+    pub number: Option<u32>,
+    /// CP of the reference in the main document
+    pub anchor_cp: i32,
+    /// The character at the reference, 0x02 for auto-numbered notes
+    pub mark: String,
+    /// The main text before the reference
+    pub context_before: String,
+    /// The main text after the reference
+    pub context_after: String,
+    /// First CP of the note in the footnote or endnote document
+    pub cp_start: i32,
+    /// CP past the end of the note in the footnote or endnote document
+    pub cp_end: i32,
+    pub text: String,
+}
+
+/// A comment of the annotation document with its author and the range it comments on
+#[derive(Debug, Iterable, Serialize)]
+pub struct Annotation {
+    /// Author from the GrpXstAtnOwners
+    pub author: Option<String>,
+    pub initials: String,
+    /// Date of the comment from the AtrdExtra, written by Word 2002 and later
+    pub date: Option<String>,
+    /// CP of the reference in the main document
+    pub anchor_cp: i32,
+    /// First CP of the commented range in the main document
+    pub range_start: Option<i32>,
+    /// CP past the end of the commented range
+    pub range_end: Option<i32>,
+    /// The main text of the commented range
+    pub commented_text: String,
+    /// Index of the comment this one replies to
+    pub parent: Option<usize>,
+    /// Depth of the comment in its thread, 0 if it is not a reply
+    pub depth: u32,
+    /// First CP of the comment in the annotation document
+    pub cp_start: i32,
+    /// CP past the end of the comment in the annotation document
+    pub cp_end: i32,
+    pub text: String,
+}
+
+/// A bookmark of the main document, a named range of CPs
+#[derive(Debug, Iterable, Serialize)]
+pub struct Bookmark {
+    pub name: String,
+    /// Whether the name starts with `_`, which hides the bookmark in Word
+    pub hidden: bool,
+    /// What Word adds the hidden bookmark for, e.g. `table of contents entry` for `_Toc`
+    pub hidden_kind: Option<String>,
+    pub start_cp: i32,
+    /// CP past the end of the bookmark, `None` if the PlcfBkl has no entry for it
+    pub end_cp: Option<i32>,
+    /// First column of a bookmark of table columns
+    pub column_first: Option<u8>,
+    /// Column past the last column of a bookmark of table columns
+    pub column_lim: Option<u8>,
+    /// The main text of the bookmark
+    pub text: String,
+}
+
+/// A field of a subdocument, with the fields nested in it as children
+#[derive(Debug, Iterable, Serialize)]
+pub struct Field {
+    /// The subdocument of the field, e.g. `main` or `footnote`
+    pub story: String,
+    /// Field type of the field begin
+    pub flt: u8,
+    /// Name of the field type, e.g. `HYPERLINK`
+    pub field_type: Option<String>,
+    /// CP of the field begin in the subdocument
+    pub begin_cp: i32,
+    /// CP of the field separator, `None` if the field has no result
+    pub separator_cp: Option<i32>,
+    /// CP of the field end, `None` if the field is not ended
+    pub end_cp: Option<i32>,
+    /// Number of fields the field is nested in
+    pub depth: u32,
+    /// Whether the field is locked against updates
+    pub locked: bool,
+    /// Whether the result was changed by formatting since the last update
+    pub result_dirty: bool,
+    /// Whether the result was edited since the last update
+    pub result_edited: bool,
+    /// The field code between the field begin and the separator
+    pub instruction: String,
+    /// The text between the separator and the field end
+    pub result: String,
+    pub children: Vec<Field>,
+}
+
+/// The tracked changes of a document
+#[derive(Debug, Default, Serialize)]
+pub struct Revisions {
+    pub settings: RevisionSettings,
+    /// Authors of the revisions from the SttbfRMark
+    pub authors: Vec<String>,
+    pub revisions: Vec<Revision>,
+}
+
+/// The revision flags of the DOP
+#[derive(Debug, Default, Iterable, Serialize)]
+pub struct RevisionSettings {
+    /// fRevMarking, changes are tracked
+    pub track_changes: bool,
+    /// fRMView, revisions are shown on screen
+    pub show_markup: bool,
+    /// fRMPrint, revisions are printed
+    pub print_markup: bool,
+    /// fLockRev, tracking can not be turned off without the password
+    pub lock_revisions: bool,
+}
+
+/// An insertion, deletion or property change of a range of the document
+#[derive(Debug, Iterable, Serialize)]
+pub struct Revision {
+    /// `insertion`, `deletion`, `character properties` or `paragraph properties`
+    pub kind: String,
+    /// Author from the SttbfRMark
+    pub author: Option<String>,
+    pub date: Option<String>,
+    /// First CP of the revision, CPs of the subdocuments follow those of the main document
+    pub cp_start: i32,
+    /// CP past the end of the revision
+    pub cp_end: i32,
+    pub text: String,
+}
+
+/// The rsids of the editing sessions of a document and the runs changed in them
+#[derive(Debug, Default, Serialize)]
+pub struct Rsids {
+    /// The sessions of the Plrsid, in its order
+    pub table: Vec<RsidSession>,
+    pub runs: Vec<RsidRun>,
+}
+
+/// An editing session of the Plrsid
+#[derive(Debug, Iterable, Serialize)]
+pub struct RsidSession {
+    /// The rsid as 8 hexadecimal digits, as in `w:rsid` of .docx files
+    pub rsid: String,
+    /// Number of runs changed in the session
+    pub runs: usize,
+    /// Number of CPs of those runs
+    pub chars: i32,
+    /// Names of the styles changed in the session
+    pub styles: Vec<String>,
+}
+
+/// A range of CPs whose text, character or paragraph properties were changed in a session
+#[derive(Debug, Iterable, Serialize)]
+pub struct RsidRun {
+    /// `text`, `properties`, `deletion` or `paragraph`
+    pub kind: String,
+    pub rsid: String,
+    /// Whether the Plrsid lists the rsid
+    pub in_table: bool,
+    pub cp_start: i32,
+    pub cp_end: i32,
+}
+
+/// The rsids two documents share
+#[derive(Debug, Serialize)]
+pub struct RsidOverlap {
+    pub shared: Vec<String>,
+    pub only_in_reference: usize,
+    pub only_in_compared: usize,
+    /// Shared rsids divided by the rsids of both documents, 1 for the same editing history
+    pub overlap: f64,
+}
+
+/// The authors and paths of the last saves of a document and its associated strings
+#[derive(Debug, Default, Iterable, Serialize)]
+pub struct SaveHistory {
+    /// Path of the attached template, `ibstAssocDot` of the SttbfAssoc
+    pub template: Option<String>,
+    /// The saves of the SttbSavedBy, the last save last
+    pub saves: Vec<Save>,
+    /// The other non-empty strings of the SttbfAssoc
+    pub associated: Vec<AssociatedString>,
+}
+
+/// A save of the SttbSavedBy
+#[derive(Debug, Iterable, Serialize)]
+pub struct Save {
+    pub author: String,
+    /// Path the document was saved to
+    pub path: String,
+}
+
+/// A string of the SttbfAssoc
+#[derive(Debug, Iterable, Serialize)]
+pub struct AssociatedString {
+    /// What the string holds, e.g. `Title` or `Mail merge data source`
+    pub name: String,
+    pub value: String,
+}
+
+/// A run of text in bytes that the document no longer refers to, e.g. text a fast save left
+/// behind or the old content of a free sector
+#[derive(Debug, Iterable, Serialize)]
+pub struct ResidualText {
+    /// `WordDocument` or `<container>`
+    pub stream_name: String,
+    /// `unreferenced` for bytes of the WordDocument stream outside the pieces, otherwise the
+    /// container structure the bytes belong to, e.g. `FreeSectorData` or `StreamSlack`
+    pub source: String,
+    /// Offset of the run in the stream
+    pub offset: u64,
+    /// Length of the run in bytes
+    pub len: u64,
+    /// `cp1252` or `utf-16le`
+    pub encoding: String,
+    pub text: String,
+}
+
+/// An entry of the directory of the compound file, as it is stored in the directory sectors
+#[derive(Debug, Iterable, Serialize)]
+pub struct DirectoryEntry {
+    /// Stream identifier, the index of the entry in the directory
+    pub sid: u32,
+    pub name: String,
+    /// Path from the root storage, `None` for entries no storage links to
+    pub path: Option<String>,
+    /// `root`, `storage`, `stream` or `unallocated`
+    pub object_type: String,
+    /// `red` or `black`, the colour of the entry in the red-black tree of its storage
+    pub color: String,
+    pub left_sibling: Option<u32>,
+    pub right_sibling: Option<u32>,
+    /// Root of the red-black tree of the entries of a storage
+    pub child: Option<u32>,
+    pub clsid: String,
+    pub state_bits: u32,
+    /// FILETIME of the creation of a storage
+    pub creation_time: u64,
+    /// FILETIME of the last modification of a storage
+    pub modified_time: u64,
+    /// First sector of the stream, in the mini stream if the stream is smaller than the cutoff.
+    /// For the root entry the first sector of the mini stream
+    pub starting_sector: u32,
+    pub size: u64,
+}
+
+/// List Tables
+#[allow(non_snake_case, unused)]
+#[derive(Debug, Default)]
 pub struct LSTs {
     pub num_LSTs: u16,
     pub LSTs: Vec<LST>,
@@ -738,38 +2179,40 @@ pub struct STD {
     pub istdNext: u16,
     /// Offset to end of upx's, start of upe's
     pub bchUpe: u16,
+    /* Only present if cbSTDBaseInFile is at least 10 (Word 97) */
     /// Auto redefine style when appropriate
-    pub fAutoRedef: bool,
+    pub fAutoRedef: Option<bool>,
     /// Hidden from UI?
-    pub fHidden: bool,
+    pub fHidden: Option<bool>,
     /// Style already has valid sprmCRgLidX_80 in it
-    pub f97LidsSet: bool,
+    pub f97LidsSet: Option<bool>,
     /// Copied the lid from sprmCRgLidX into sprmCRgLidX_80
-    pub fCopyLang: bool,
+    pub fCopyLang: Option<bool>,
     /// HTML Threading compose style
-    pub fPersonalCompose: bool,
+    pub fPersonalCompose: Option<bool>,
     /// HTML Threading reply style
-    pub fPersonalReply: bool,
+    pub fPersonalReply: Option<bool>,
     /// HTML Threading - another user's personal style
-    pub fPersonal: bool,
+    pub fPersonal: Option<bool>,
     /// pub Do not export this style to HTML/CSS
-    pub fNoHtmlExport: bool,
+    pub fNoHtmlExport: Option<bool>,
     /// Do not show this style in long style lists
-    pub fSemiHidden: bool,
+    pub fSemiHidden: Option<bool>,
     /// Locked style?
-    pub fLocked: bool,
+    pub fLocked: Option<bool>,
     /// Style is used by a word feature, e.g. footnote
-    pub fInternalUse: bool,
+    pub fInternalUse: Option<bool>,
     /// Unused bits
     pub unused_bits: u16,
+    /* Only present if cbSTDBaseInFile is at least 18 (Word 2000) */
     /// Is this style linked to another?
-    pub istdLink: u16,
+    pub istdLink: Option<u16>,
     /// 4 Spare bits
-    pub fSpare: u16,
+    pub fSpare: Option<u16>,
     /// Marks during merge which doc's style changed
-    pub rsid: i32,
+    pub rsid: Option<i32>,
     /// Used temporarily during HTML export
-    pub iftcHtml: u16,
+    pub iftcHtml: Option<u16>,
     /// Unused bits
     pub unused: u16,
     /// Sub-names are separated by chDelimStyle
@@ -788,7 +2231,7 @@ pub struct PCD {
     pub prm: u16,
 }
 #[allow(non_snake_case, unused)]
-#[derive(Debug, Iterable, Serialize)]
+#[derive(Debug, Default, Iterable, Serialize)]
 pub struct SHSHI {
     // real count of bytes of the STSH
     pub cbStshi: u16,
@@ -804,9 +2247,9 @@ pub struct SHSHI {
     /// Current version of build-in stylenames
     pub nVerBuiltInNamesWhenSaved: u16,
     /// rgfts used by StandardChpStsh
-    pub rgftcStandardChpStsh: [u16; 4],
+    pub rgftcStandardChpStsh: Vec<u16>,
     /// size of each lsd in mpstilsd
-    pub cbLSD: u16,
+    pub cbLSD: Option<u16>,
     /// latent style data
     pub mpstilsd: Vec<Bytes>,
     pub styles: Vec<STD>,
@@ -820,9 +2263,53 @@ pub struct PLCF<T: FromCStruct> {
     pub rgstruct: Vec<T>,
 }
 
+impl PLCF<PCD> {
+    /// The character position of the character at `fc` in the WordDocument stream, `None` if
+    /// no piece holds it
+    pub fn fc_to_cp(&self, fc: i32) -> Option<i32> {
+        self.pieces().find_map(|(cp, start, end, cb_char)| {
+            (start..end)
+                .contains(&fc)
+                .then(|| cp + (fc - start) / cb_char)
+        })
+    }
+
+    /// The CP ranges of the characters at `fc_start` to `fc_end` in the WordDocument stream, one
+    /// for every piece that holds some of them
+    pub fn fc_range_to_cps(&self, fc_start: i32, fc_end: i32) -> Vec<(i32, i32)> {
+        self.pieces()
+            .filter(|&(_, start, end, _)| start < fc_end && fc_start < end)
+            .map(|(cp, start, end, cb_char)| {
+                let cp_of = |fc: i32| cp + (fc.clamp(start, end) - start) / cb_char;
+                (cp_of(fc_start), cp_of(fc_end))
+            })
+            .collect()
+    }
+
+    /// The ranges of the WordDocument stream that hold the text of the pieces
+    pub fn fc_ranges(&self) -> Vec<(i32, i32)> {
+        self.pieces()
+            .map(|(_, start, end, _)| (start, end))
+            .collect()
+    }
+
+    /// The first CP, the range of bytes and the bytes per character of every piece
+    fn pieces(&self) -> impl Iterator<Item = (i32, i32, i32, i32)> + '_ {
+        self.rgfc.windows(2).zip(&self.rgstruct).map(|(cps, pcd)| {
+            // fCompressed pieces hold 8-bit characters at half the fc
+            let (start, cb_char) = if pcd.fc & 0x4000_0000 != 0 {
+                ((pcd.fc & !0x4000_0000) / 2, 1)
+            } else {
+                (pcd.fc, 2)
+            };
+            (cps[0], start, start + (cps[1] - cps[0]) * cb_char, cb_char)
+        })
+    }
+}
+
 /// The different text subdocument of the word document
 #[allow(unused)]
-#[derive(Debug, Iterable, Serialize)]
+#[derive(Debug, Default, Iterable, Serialize)]
 pub struct Text {
     pub main_text: TextDoc,
     pub footnote_text: TextDoc,
@@ -833,7 +2320,7 @@ pub struct Text {
     pub header_textbox_text: TextDoc,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct TextDoc {
     bytes: Vec<u8>,
     _text: Vec<String>,
@@ -848,7 +2335,13 @@ impl TextDoc {
         }
     }
 
-    fn to_fmt_string(&self) -> String {
+    /// The raw bytes of the subdocument as stored in the WordDocument stream
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// The text with non printable characters escaped as `{0xNN}`
+    pub fn to_fmt_string(&self) -> String {
         let mut text = String::new();
 
         for byte in &self.bytes {
@@ -881,7 +2374,7 @@ impl serde::Serialize for TextDoc {
 
 // The first few bytes of the PropertySetStream
 #[allow(non_snake_case, unused)]
-#[derive(Debug, Iterable, Serialize)]
+#[derive(Debug, Default, Iterable, Serialize)]
 pub struct PropertySetStreamStart {
     pub byteOrder: u16,
     pub version: u16,
@@ -971,7 +2464,7 @@ pub enum NormalPropertyType {
 }
 
 #[allow(non_snake_case, unused)]
-#[derive(Debug, Iterable, Serialize)]
+#[derive(Debug, Default, Iterable, Serialize)]
 pub struct DocumentSummaryInfoStream {
     pub codepage: Option<NormalPropertyType>,
     pub category: Option<NormalPropertyType>,
@@ -1006,7 +2499,7 @@ pub struct DocumentSummaryInfoStream {
 // Specification at: https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-oleps/f7933d28-2cc4-4b36-bc23-8861cbcd37c4
 // Spec help at: https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-oleps/3f9119dc-faa2-4bb9-af95-5cf128fa5fbd
 #[allow(non_snake_case, unused)]
-#[derive(Debug, Iterable, Serialize)]
+#[derive(Debug, Default, Iterable, Serialize)]
 pub struct SummaryInformation {
     #[serde(flatten)]
     pub propertysetStreamHeader: PropertySetStreamStart,
@@ -1133,6 +2626,22 @@ impl LSTF {
     }
 }
 
+/// The date and time of a DTTM as `YYYY-MM-DD HH:MM`, `None` if it is not set
+pub fn dttm_to_string(dttm: u32) -> Option<String> {
+    if dttm == 0 {
+        return None;
+    }
+
+    let minute = dttm & 0x3F;
+    let hour = (dttm >> 6) & 0x1F;
+    let day = (dttm >> 11) & 0x1F;
+    let month = (dttm >> 16) & 0x0F;
+    let year = 1900 + ((dttm >> 20) & 0x1FF);
+    Some(format!(
+        "{year:04}-{month:02}-{day:02} {hour:02}:{minute:02}"
+    ))
+}
+
 fn byte_to_string_arr(butes: &[u8]) -> Vec<String> {
     let mut strings = Vec::new();
 
@@ -0,0 +1,312 @@
+//! Objects embedded in the document. Each one is a storage of the ObjectPool named after the
+//! object id, which the main text refers to by a sprmCPicLocation on a run with sprmCFOle2
+use std::{
+    collections::HashMap,
+    io::{self, Cursor, Read, Seek, Write},
+    path::Path,
+};
+
+use cfb::CompoundFile;
+use sha2::{Digest, Sha256};
+
+use super::{
+    from_reader::{ole10_native_package_from_bytes, FromReader},
+    model::*,
+    pictures::read_chpxs,
+};
+
+/// The storage holding the embedded objects
+pub const OBJECT_POOL: &str = "/ObjectPool";
+
+/// Streams that hold the native data of an object, in order of preference
+const PAYLOAD_STREAMS: [&str; 3] = ["\x01Ole10Native", "CONTENTS", "Package"];
+
+/// Reads every object of the ObjectPool and finds where it is anchored in the main document
+pub fn read_embedded_objects<F: Read + Seek>(
+    cfb: &mut CompoundFile<F>,
+    fib: &Fib,
+    table_stream_name: &str,
+    piece_table: &PLCF<PCD>,
+    main_text: &TextDoc,
+) -> io::Result<Vec<EmbeddedObject>> {
+    if !cfb.is_storage(OBJECT_POOL) {
+        return Ok(vec![]);
+    }
+
+    let word_document = read_stream(cfb, "WordDocument")?;
+    let table_stream = read_stream(cfb, table_stream_name)?;
+    let chpxs = read_chpxs(&word_document, &table_stream, fib);
+
+    // the runs of embedded objects point at their storage by object id
+    let anchors = chpxs
+        .iter()
+        .filter(|chpx| chpx.is_set(SPRM_C_F_OLE2))
+        .filter_map(|chpx| Some((format!("_{}", chpx.pic_location()?), chpx.fc_start)))
+        .collect::<HashMap<_, _>>();
+
+    let storages = cfb
+        .read_storage(OBJECT_POOL)?
+        .filter(|entry| entry.is_storage())
+        .map(|entry| entry.name().to_string())
+        .collect::<Vec<_>>();
+
+    let mut objects = vec![];
+    for storage_name in storages {
+        let mut object = read_embedded_object(cfb, &storage_name)?;
+        if let Some(&fc) = anchors.get(&storage_name) {
+            // files that are not complex have no piece table, their text starts at fcMin
+            let cp = piece_table
+                .fc_to_cp(fc)
+                .or_else(|| piece_table.rgstruct.is_empty().then(|| fc - fib.fcMin));
+            object.field_code = cp.and_then(|cp| field_code_at(main_text.bytes(), cp));
+            object.cp = cp;
+        }
+        objects.push(object);
+    }
+
+    Ok(objects)
+}
+
+/// Reads the object in the storage `storage_name` of the ObjectPool
+pub fn read_embedded_object<F: Read + Seek>(
+    cfb: &mut CompoundFile<F>,
+    storage_name: &str,
+) -> io::Result<EmbeddedObject> {
+    let storage_path = format!("{OBJECT_POOL}/{storage_name}");
+    let clsid = cfb.entry(&storage_path)?.clsid().to_string();
+    let streams = cfb
+        .walk_storage(&storage_path)?
+        .filter(|entry| entry.is_stream())
+        .map(|entry| {
+            let path = entry.path().to_string_lossy().to_string();
+            let name = path[storage_path.len() + 1..].to_string();
+            (name, entry.len())
+        })
+        .collect::<Vec<_>>();
+    let has_stream = |name: &str| streams.iter().any(|(stream, _)| stream == name);
+
+    let comp_obj = if has_stream("\x01CompObj") {
+        let mut stream = cfb.open_stream(format!("{storage_path}/\x01CompObj"))?;
+        CompObjStream::from_reader(&mut stream).ok()
+    } else {
+        None
+    };
+
+    // the flags of the Ole stream follow its version
+    let linked = if has_stream("\x01Ole") {
+        let ole = read_stream(cfb, &format!("{storage_path}/\x01Ole"))?;
+        ole.get(4..8)
+            .map(|flags| u32::from_le_bytes([flags[0], flags[1], flags[2], flags[3]]) & 1 == 1)
+    } else {
+        None
+    };
+
+    let odt = if has_stream("\x03ObjInfo") {
+        let obj_info = read_stream(cfb, &format!("{storage_path}/\x03ObjInfo"))?;
+        obj_info
+            .get(0..2)
+            .map(|odt| u16::from_le_bytes([odt[0], odt[1]]))
+    } else {
+        None
+    };
+
+    let mut package_label = None;
+    let mut package_path = None;
+    let (payload_source, payload) = match PAYLOAD_STREAMS.iter().find(|name| has_stream(name)) {
+        Some(&name) => {
+            let data = read_stream(cfb, &format!("{storage_path}/{name}"))?;
+            if name == "\x01Ole10Native" {
+                match ole10_native_package_from_bytes(&data) {
+                    Some((label, path, file)) => {
+                        package_label = Some(label);
+                        package_path = Some(path);
+                        (Some("Ole10Native"), file)
+                    }
+                    // the native data follows its size
+                    None => (
+                        Some("Ole10Native"),
+                        data.get(4..).unwrap_or_default().to_vec(),
+                    ),
+                }
+            } else {
+                (Some(name), data)
+            }
+        }
+        // e.g. a Word document or an Excel workbook, the storage is a document of its own
+        None if streams.iter().any(|(name, _)| is_content_stream(name)) => (
+            Some("storage"),
+            storage_to_compound_file(cfb, &storage_path)?,
+        ),
+        None => (None, vec![]),
+    };
+
+    Ok(EmbeddedObject {
+        storage_name: storage_name.to_string(),
+        storage_path: storage_path.trim_start_matches('/').to_string(),
+        clsid,
+        user_type: comp_obj
+            .as_ref()
+            .map(|comp_obj| comp_obj.AnsiUserType.clone()),
+        clipboard_format: comp_obj
+            .as_ref()
+            .and_then(|comp_obj| comp_obj.AnsiClipboardFormat.clone()),
+        prog_id: comp_obj.and_then(|comp_obj| comp_obj.Reserved1),
+        linked,
+        odt,
+        package_label,
+        package_path,
+        payload_source: payload_source.map(str::to_string),
+        payload_size: payload_source.map(|_| payload.len()),
+        payload_sha256: payload_source.map(|_| hex::encode(Sha256::digest(&payload))),
+        cp: None,
+        field_code: None,
+        streams,
+        payload,
+    })
+}
+
+fn read_stream<F: Read + Seek>(cfb: &mut CompoundFile<F>, path: &str) -> io::Result<Vec<u8>> {
+    let mut bytes = vec![];
+    cfb.open_stream(path)?.read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Streams that are not part of the OLE plumbing, `\x01CompObj`, `\x03ObjInfo`, the property
+/// sets and the like all start with a control character
+fn is_content_stream(name: &str) -> bool {
+    !name.starts_with(|c: char| c.is_control())
+}
+
+/// Copies the storage at `storage_path` into a compound file of its own
+fn storage_to_compound_file<F: Read + Seek>(
+    cfb: &mut CompoundFile<F>,
+    storage_path: &str,
+) -> io::Result<Vec<u8>> {
+    let entries = cfb
+        .walk_storage(storage_path)?
+        .map(|entry| {
+            (
+                entry.path().to_path_buf(),
+                entry.is_stream(),
+                *entry.clsid(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut compound_file = CompoundFile::create(Cursor::new(vec![]))?;
+    for (path, is_stream, clsid) in entries {
+        let relative_path = Path::new("/").join(path.strip_prefix(storage_path).unwrap_or(&path));
+        if is_stream {
+            let data = read_stream(cfb, &path.to_string_lossy())?;
+            compound_file
+                .create_stream(&relative_path)?
+                .write_all(&data)?;
+        } else {
+            if relative_path != Path::new("/") {
+                compound_file.create_storage(&relative_path)?;
+            }
+            compound_file.set_storage_clsid(&relative_path, clsid)?;
+        }
+    }
+    compound_file.flush()?;
+
+    Ok(compound_file.into_inner().into_inner())
+}
+
+/// The instructions of the field whose result holds the character at `cp`, read back from its
+/// field begin (0x13) up to its field separator (0x14)
+pub fn field_code_at(text: &[u8], cp: i32) -> Option<String> {
+    let before = text.get(..cp as usize)?;
+    let begin = before.iter().rposition(|&byte| byte == 0x13)?;
+    let separator = begin + before[begin..].iter().position(|&byte| byte == 0x14)?;
+
+    let instructions = before[begin + 1..separator]
+        .iter()
+        .map(|&byte| byte as char)
+        .collect::<String>();
+    Some(instructions.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embedded_objects() {
+        let mut comp_obj = vec![0; 28];
+        for string in ["Package\0", "", "Package\0"] {
+            comp_obj.extend((string.len() as u32).to_le_bytes());
+            comp_obj.extend(string.as_bytes());
+        }
+        let mut native = vec![];
+        native.extend(2u16.to_le_bytes());
+        native.extend(b"evil.bat\0C:\\evil.bat\0");
+        native.extend(0x0003_0000u32.to_le_bytes());
+        native.extend(4u32.to_le_bytes());
+        native.extend(b"tmp\0");
+        native.extend(5u32.to_le_bytes());
+        native.extend(b"echo!");
+        let mut ole10_native = (native.len() as u32).to_le_bytes().to_vec();
+        ole10_native.extend(&native);
+
+        let mut cfb = CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+        cfb.create_storage_all("/ObjectPool/_1234").unwrap();
+        cfb.create_storage("/ObjectPool/_5678").unwrap();
+        for (path, data) in [
+            ("/ObjectPool/_1234/\x01CompObj", comp_obj),
+            ("/ObjectPool/_1234/\x01Ole", vec![1, 0, 0, 2, 0, 0, 0, 0]),
+            ("/ObjectPool/_1234/\x03ObjInfo", vec![0x40, 0, 3, 0]),
+            ("/ObjectPool/_1234/\x01Ole10Native", ole10_native),
+            ("/ObjectPool/_5678/WordDocument", b"embedded".to_vec()),
+        ] {
+            cfb.create_stream(path).unwrap().write_all(&data).unwrap();
+        }
+
+        let package = read_embedded_object(&mut cfb, "_1234").unwrap();
+        assert_eq!(package.storage_path, "ObjectPool/_1234");
+        assert_eq!(package.prog_id.as_deref(), Some("Package"));
+        assert_eq!(package.user_type.as_deref(), Some("Package"));
+        assert_eq!(package.clipboard_format, None);
+        assert_eq!((package.linked, package.odt), (Some(false), Some(0x40)));
+        assert_eq!(package.package_label.as_deref(), Some("evil.bat"));
+        assert_eq!(package.package_path.as_deref(), Some("C:\\evil.bat"));
+        assert_eq!(package.payload_source.as_deref(), Some("Ole10Native"));
+        assert_eq!(package.payload, b"echo!");
+        assert_eq!(package.extension(), "bat");
+        assert_eq!(package.streams.len(), 4);
+
+        // an embedded document is extracted as a compound file of its own
+        let document = read_embedded_object(&mut cfb, "_5678").unwrap();
+        assert_eq!(document.payload_source.as_deref(), Some("storage"));
+        assert_eq!(document.extension(), "cfb");
+        let mut embedded = CompoundFile::open(Cursor::new(document.payload)).unwrap();
+        let mut bytes = vec![];
+        embedded
+            .open_stream("/WordDocument")
+            .unwrap()
+            .read_to_end(&mut bytes)
+            .unwrap();
+        assert_eq!(bytes, b"embedded");
+
+        // the object character is in the result of its EMBED field
+        let text = b"A \x13 EMBED Package \x14\x01\x15 B";
+        assert_eq!(field_code_at(text, 19).as_deref(), Some("EMBED Package"));
+        assert_eq!(field_code_at(text, 1), None);
+
+        let piece_table = PLCF {
+            rgfc: vec![0, 10, 20],
+            rgstruct: [0x4000_0800, 0x1000]
+                .map(|fc| PCD {
+                    fNoParaLast: false,
+                    rest_of_bitfield: [false; 3],
+                    fn_val: 0,
+                    fc,
+                    prm: 0,
+                })
+                .into(),
+        };
+        assert_eq!(piece_table.fc_to_cp(0x405), Some(5));
+        assert_eq!(piece_table.fc_to_cp(0x1004), Some(12));
+        assert_eq!(piece_table.fc_to_cp(0x1020), None);
+    }
+}
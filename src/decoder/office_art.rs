@@ -0,0 +1,202 @@
+//! The OfficeArt drawings of a document at `fcDggInfo` and the shapes anchored in its text by
+//! the PlcfSpa of the main document and the headers
+use std::io::{self, Read, Seek, SeekFrom};
+
+use super::{from_c_struct::FromCStruct, from_reader::office_art_content_from_read_impl, model::*};
+
+/// Reads the OfficeArtContent of the table stream, `None` if the document has no drawings
+pub fn read_office_art<R: Read + Seek>(
+    table_stream: &mut R,
+    fib: &Fib,
+) -> io::Result<Option<OfficeArtContent>> {
+    if fib.lcbDggInfo == 0 {
+        return Ok(None);
+    }
+
+    table_stream.seek(SeekFrom::Start(fib.fcDggInfo as u64))?;
+    let end = fib.fcDggInfo as u64 + fib.lcbDggInfo as u64;
+    office_art_content_from_read_impl(table_stream, end).map(Some)
+}
+
+/// Reads the shapes of the main document and the headers. A shape is linked to its text box by
+/// the FTXBXS whose `lid` is the spid of the shape
+pub fn read_shapes<R: Read + Seek>(
+    table_stream: &mut R,
+    fib: &Fib,
+    text: &Text,
+) -> io::Result<Vec<Shape>> {
+    let mut shapes = story_shapes(
+        table_stream,
+        "main",
+        (fib.fcPlcspaMom, fib.lcbPlcspaMom),
+        (fib.fcPlcftxbxTxt, fib.lcbPlcftxbxTxt),
+        &text.textbox_text,
+    )?;
+    shapes.extend(story_shapes(
+        table_stream,
+        "header",
+        (fib.fcPlcspaHdr, fib.lcbPlcspaHdr),
+        (fib.fcPlcfhdrtxbxTxt, fib.lcbPlcfhdrtxbxTxt),
+        &text.header_textbox_text,
+    )?);
+
+    Ok(shapes)
+}
+
+/// Reads the shapes of one story from its PlcfSpa and links them to the text boxes of its
+/// PlcftxbxTxt, whose CPs are in `textbox_text`
+pub fn story_shapes<R: Read + Seek>(
+    table_stream: &mut R,
+    story: &str,
+    plcspa: (i32, u32),
+    plcftxbx_txt: (i32, u32),
+    textbox_text: &TextDoc,
+) -> io::Result<Vec<Shape>> {
+    let Some(plcspa) = read_plcf::<FSPA, _>(table_stream, plcspa)? else {
+        return Ok(vec![]);
+    };
+    let textboxes = read_plcf::<FTXBXS, _>(table_stream, plcftxbx_txt)?;
+
+    let shapes = plcspa
+        .rgfc
+        .iter()
+        .zip(plcspa.rgstruct)
+        .map(|(&cp, fspa)| {
+            let textbox_text = textboxes.as_ref().and_then(|textboxes| {
+                let i = textboxes
+                    .rgstruct
+                    .iter()
+                    .position(|ftxbxs| ftxbxs.fReusable == 0 && ftxbxs.lid == fspa.spid)?;
+                let start = textboxes.rgfc[i] as usize;
+                let end = textboxes.rgfc[i + 1] as usize;
+                let bytes = textbox_text.bytes().get(start..end)?;
+                Some(TextDoc::from(bytes).to_fmt_string())
+            });
+
+            Shape {
+                story: story.to_string(),
+                cp,
+                fspa,
+                textbox_text,
+            }
+        })
+        .collect();
+
+    Ok(shapes)
+}
+
+/// Reads the PLC at `fc` of the table stream, `None` if it does not hold a single entry
+pub fn read_plcf<T: FromCStruct, R: Read + Seek>(
+    table_stream: &mut R,
+    (fc, lcb): (i32, u32),
+) -> io::Result<Option<PLCF<T>>> {
+    if lcb < 4 + 4 + T::c_size() as u32 {
+        return Ok(None);
+    }
+
+    let mut bytes = vec![0; lcb as usize];
+    table_stream.seek(SeekFrom::Start(fc as u64))?;
+    table_stream.read_exact(&mut bytes)?;
+    Ok(Some(PLCF::from_bytes(&bytes)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    use crate::decoder::from_reader;
+
+    #[test]
+    fn test_office_art() {
+        let record_header = |ver_instance: u16, rec_type: u16, len: usize| {
+            let mut header = ver_instance.to_le_bytes().to_vec();
+            header.extend(rec_type.to_le_bytes());
+            header.extend((len as u32).to_le_bytes());
+            header
+        };
+
+        // a shape with a property table holding lTxid and a complex wzName
+        let name = "Box\0"
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect::<Vec<_>>();
+        let mut fopt = record_header(0x0023, 0xF00B, 12 + name.len());
+        fopt.extend(0x0080u16.to_le_bytes());
+        fopt.extend(0x0001_0000i32.to_le_bytes());
+        fopt.extend((0x0380u16 | 0x8000).to_le_bytes());
+        fopt.extend((name.len() as i32).to_le_bytes());
+        fopt.extend(&name);
+        let mut fsp = record_header(0x0CA2, 0xF00A, 8);
+        fsp.extend(0x0401u32.to_le_bytes());
+        fsp.extend(0x0A00u32.to_le_bytes());
+        let mut client_textbox = record_header(0x0000, 0xF00D, 4);
+        client_textbox.extend(0x0001_0000u32.to_le_bytes());
+        let sp_container_len = fsp.len() + fopt.len() + client_textbox.len();
+        let mut dg_container = record_header(0x000F, 0xF002, 8 + sp_container_len);
+        dg_container.extend(record_header(0x000F, 0xF004, sp_container_len));
+        dg_container.extend(&fsp);
+        dg_container.extend(&fopt);
+        dg_container.extend(&client_textbox);
+
+        let mut table_stream = vec![0; 0x10];
+        table_stream.extend(record_header(0x000F, 0xF000, 0));
+        table_stream.push(0);
+        table_stream.extend(&dg_container);
+        let end = table_stream.len() as u64;
+        let mut reader = Cursor::new(&table_stream);
+        reader.seek(SeekFrom::Start(0x10)).unwrap();
+        let content = from_reader::office_art_content_from_read_impl(&mut reader, end).unwrap();
+        assert_eq!(content.dgg_container.header.recType, 0xF000);
+        assert_eq!(content.drawings.len(), 1);
+        assert_eq!(content.drawings[0].dgglbl, 0);
+        let sp_container = &content.drawings[0].container.children[0];
+        assert_eq!(sp_container.offset, 0x10 + 8 + 1 + 8);
+        assert_eq!(sp_container.header.record_name(), "OfficeArtSpContainer");
+        assert_eq!(sp_container.children.len(), 3);
+        assert_eq!(sp_container.children[0].spid, Some(0x0401));
+        assert_eq!(sp_container.children[2].clientTextbox, Some(0x0001_0000));
+        let properties = &sp_container.children[1].properties;
+        assert_eq!(properties.len(), 2);
+        assert_eq!((properties[0].pid, properties[0].op), (0x0080, 0x0001_0000));
+        assert!(properties[1].fComplex);
+        assert_eq!(properties[1].complexData.as_ref().unwrap().bytes, name);
+
+        // the FSPA of the shape and the FTXBXS of its text box, linked by the spid
+        let mut table_stream = vec![];
+        for cp in [5i32, 6] {
+            table_stream.extend(cp.to_le_bytes());
+        }
+        for value in [0x0401i32, 0, 0, 1440, 720] {
+            table_stream.extend(value.to_le_bytes());
+        }
+        table_stream.extend(0x4000u16.to_le_bytes());
+        table_stream.extend(0i32.to_le_bytes());
+        let plcftxbx_txt = table_stream.len();
+        for cp in [0i32, 4, 5] {
+            table_stream.extend(cp.to_le_bytes());
+        }
+        for lid in [0x0401i32, 0] {
+            table_stream.extend([1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+            table_stream.extend(lid.to_le_bytes());
+            table_stream.extend(0i32.to_le_bytes());
+        }
+        let lcb = (table_stream.len() - plcftxbx_txt) as u32;
+        let shapes = story_shapes(
+            &mut Cursor::new(&table_stream),
+            "main",
+            (0, plcftxbx_txt as u32),
+            (plcftxbx_txt as i32, lcb),
+            &TextDoc::from(b"Box!\r"),
+        )
+        .unwrap();
+        assert_eq!(shapes.len(), 1);
+        assert_eq!(shapes[0].cp, 5);
+        assert_eq!(
+            (shapes[0].fspa.spid, shapes[0].fspa.xaRight),
+            (0x0401, 1440)
+        );
+        assert!(shapes[0].fspa.fBelowText);
+        assert_eq!(shapes[0].textbox_text.as_deref(), Some("Box!"));
+    }
+}
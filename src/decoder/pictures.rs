@@ -0,0 +1,461 @@
+//! Pictures of a document. Inline pictures are stored in the Data stream as
+//! PICFAndOfficeArtData, found through the sprmCPicLocation of their character run, and
+//! floating pictures in the OfficeArt BLIP store of `fcDggInfo`
+use std::{
+    collections::BTreeSet,
+    io::{self, Cursor, Read, Seek, SeekFrom},
+};
+
+use cfb::CompoundFile;
+use flate2::read::ZlibDecoder;
+use sha2::{Digest, Sha256};
+
+use super::{
+    from_reader::{chpx_fkp_from_bytes, FromReader},
+    model::*,
+};
+
+/// OfficeArtDggContainer, the drawing group of the document
+const RT_DGG_CONTAINER: u16 = 0xF000;
+/// OfficeArtBStoreContainer, the BLIP store
+const RT_BSTORE_CONTAINER: u16 = 0xF001;
+/// OfficeArtFBSE, an entry of the BLIP store
+const RT_FBSE: u16 = 0xF007;
+
+/// Size of the fixed part of an OfficeArtFBSE, before its name and embedded BLIP
+const CB_FBSE: u64 = 36;
+/// Size of the OfficeArtMetafileHeader of EMF, WMF and PICT BLIPs
+const CB_METAFILE_HEADER: usize = 34;
+
+/// Reads all pictures of the Data stream and the BLIP store. Metafiles that can not be
+/// decompressed are left out and reported in `diagnostics`
+pub fn read_pictures<F: Read + Seek>(
+    cfb: &mut CompoundFile<F>,
+    fib: &Fib,
+    table_stream_name: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> io::Result<Vec<Picture>> {
+    let word_document = read_stream(cfb, "WordDocument")?;
+    let table_stream = read_stream(cfb, table_stream_name)?;
+
+    let mut pictures = vec![];
+
+    if cfb.is_stream("Data") {
+        let data_stream = read_stream(cfb, "Data")?;
+        for offset in picture_locations(&word_document, &table_stream, fib) {
+            pictures.extend(data_stream_pictures(
+                &data_stream,
+                offset as u64,
+                diagnostics,
+            )?);
+        }
+    }
+
+    if fib.lcbDggInfo > 0 {
+        let dgg_info = slice_of(&table_stream, fib.fcDggInfo as u64, fib.lcbDggInfo as u64)?;
+        pictures.extend(blip_store_pictures(
+            dgg_info,
+            fib.fcDggInfo as u64,
+            table_stream_name,
+            &word_document,
+            diagnostics,
+        )?);
+    }
+
+    Ok(pictures)
+}
+
+fn read_stream<F: Read + Seek>(cfb: &mut CompoundFile<F>, name: &str) -> io::Result<Vec<u8>> {
+    let mut bytes = vec![];
+    cfb.open_stream(name)?.read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn slice_of(bytes: &[u8], start: u64, len: u64) -> io::Result<&[u8]> {
+    bytes
+        .get(start as usize..(start + len) as usize)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!("{start:#X}..{:#X} is out of bounds", start + len),
+            )
+        })
+}
+
+/// Reads the CHPXs of all ChpxFkp pages of the PlcBteChpx
+pub fn read_chpxs(word_document: &[u8], table_stream: &[u8], fib: &Fib) -> Vec<Chpx> {
+    let Ok(plcf_bte_chpx) = slice_of(
+        table_stream,
+        fib.fcPlcfbteChpx as u64,
+        fib.lcbPlcfbteChpx as u64,
+    ) else {
+        return vec![];
+    };
+    if plcf_bte_chpx.len() < 4 {
+        return vec![];
+    }
+
+    PLCF::<PnFkpChpx>::from_bytes(plcf_bte_chpx)
+        .rgstruct
+        .iter()
+        .filter_map(|pn_fkp| slice_of(word_document, pn_fkp.pn as u64 * 512, 512).ok())
+        .flat_map(chpx_fkp_from_bytes)
+        .collect()
+}
+
+/// The Data stream offsets of the pictures, from the sprmCPicLocation of every CHPX
+fn picture_locations(word_document: &[u8], table_stream: &[u8], fib: &Fib) -> BTreeSet<u32> {
+    read_chpxs(word_document, table_stream, fib)
+        .iter()
+        // sprmCFData marks binary data of a field and sprmCFOle2 an embedded object
+        .filter(|chpx| !chpx.is_set(SPRM_C_F_DATA) && !chpx.is_set(SPRM_C_F_OLE2))
+        .filter_map(Chpx::pic_location)
+        .collect()
+}
+
+/// Reads the PICFAndOfficeArtData at `offset` of the Data stream
+pub fn data_stream_pictures(
+    data_stream: &[u8],
+    offset: u64,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> io::Result<Vec<Picture>> {
+    let mut reader = Cursor::new(data_stream);
+    reader.seek(SeekFrom::Start(offset))?;
+    let picf = PICF::from_reader(&mut reader)?;
+    let len = picf.lcb as u64;
+    let end = offset + len;
+    let picture_of = |picf, fbse, blip_type, data| {
+        picture("Data", "Data", offset, len, picf, fbse, blip_type, data)
+    };
+
+    // MM_SHAPE and MM_SHAPEFILE are stored as OfficeArt, the mapping modes MM_TEXT to
+    // MM_ANISOTROPIC of older documents are followed by a WMF
+    if picf.mm != 0x0064 && picf.mm != 0x0066 {
+        let data = slice_of(
+            data_stream,
+            reader.position(),
+            end.saturating_sub(reader.position()),
+        )?;
+        let blip_type = match picf.mm {
+            0x0001..=0x0008 => BlipType::Wmf,
+            _ => BlipType::Unknown,
+        };
+        return Ok(vec![picture_of(Some(picf), None, blip_type, data.to_vec())]);
+    }
+
+    // an OfficeArtInlineSpContainer, the shape followed by the FBSEs of its pictures
+    let mut pictures = vec![];
+    let mut picf = Some(picf);
+    while reader.position() + 8 <= end {
+        let header = OfficeArtRecordHeader::from_reader(&mut reader)?;
+        let record_end = reader.position() + header.recLen as u64;
+        if header.recType == RT_FBSE {
+            let fbse = OfficeArtFBSE::from_reader(&mut reader)?;
+            let location = ("Data", 0);
+            if let Some((blip_type, data)) =
+                read_blip(&mut reader, record_end, location, diagnostics)?
+            {
+                pictures.push(picture_of(picf.take(), Some(fbse), blip_type, data));
+            }
+        }
+        reader.seek(SeekFrom::Start(record_end))?;
+    }
+
+    Ok(pictures)
+}
+
+/// Reads the pictures of the BLIP store in the OfficeArtContent at `fcDggInfo`. The BLIPs are
+/// either embedded in their FBSE or stored in the WordDocument stream at `foDelay`
+pub fn blip_store_pictures(
+    dgg_info: &[u8],
+    fc_dgg_info: u64,
+    table_stream_name: &str,
+    word_document: &[u8],
+    diagnostics: &mut Vec<Diagnostic>,
+) -> io::Result<Vec<Picture>> {
+    let mut reader = Cursor::new(dgg_info);
+    let dgg_header = OfficeArtRecordHeader::from_reader(&mut reader)?;
+    if dgg_header.recType != RT_DGG_CONTAINER {
+        return Ok(vec![]);
+    }
+    let dgg_end = reader.position() + dgg_header.recLen as u64;
+
+    let mut pictures = vec![];
+    while reader.position() + 8 <= dgg_end {
+        let header = OfficeArtRecordHeader::from_reader(&mut reader)?;
+        let record_end = reader.position() + header.recLen as u64;
+        if header.recType != RT_BSTORE_CONTAINER {
+            reader.seek(SeekFrom::Start(record_end))?;
+            continue;
+        }
+
+        while reader.position() + 8 <= record_end {
+            let fbse_offset = reader.position();
+            let fbse_header = OfficeArtRecordHeader::from_reader(&mut reader)?;
+            let fbse_end = reader.position() + fbse_header.recLen as u64;
+            if fbse_header.recType != RT_FBSE {
+                reader.seek(SeekFrom::Start(fbse_end))?;
+                continue;
+            }
+
+            let fbse = OfficeArtFBSE::from_reader(&mut reader)?;
+            if fbse_header.recLen as u64 > CB_FBSE + fbse.cbName as u64 {
+                let location = (table_stream_name, fc_dgg_info);
+                if let Some((blip_type, data)) =
+                    read_blip(&mut reader, fbse_end, location, diagnostics)?
+                {
+                    pictures.push(picture(
+                        "BStore",
+                        table_stream_name,
+                        fc_dgg_info + fbse_offset,
+                        fbse_end - fbse_offset,
+                        None,
+                        Some(fbse),
+                        blip_type,
+                        data,
+                    ));
+                }
+            } else if fbse.size > 0 {
+                let mut delay_reader = Cursor::new(word_document);
+                delay_reader.seek(SeekFrom::Start(fbse.foDelay as u64))?;
+                let delay_end = fbse.foDelay as u64 + fbse.size as u64;
+                let location = ("WordDocument", 0);
+                if let Some((blip_type, data)) =
+                    read_blip(&mut delay_reader, delay_end, location, diagnostics)?
+                {
+                    pictures.push(picture(
+                        "BStore",
+                        "WordDocument",
+                        fbse.foDelay as u64,
+                        fbse.size as u64,
+                        None,
+                        Some(fbse),
+                        blip_type,
+                        data,
+                    ));
+                }
+            }
+            reader.seek(SeekFrom::Start(fbse_end))?;
+        }
+    }
+
+    Ok(pictures)
+}
+
+/// Reads a BLIP record and returns the picture in it, metafiles are decompressed. `None` when
+/// the record is not a BLIP or its metafile can not be decompressed, which is reported in
+/// `diagnostics`. `location` is the stream of the reader and the offset of its bytes in it
+fn read_blip(
+    reader: &mut Cursor<&[u8]>,
+    end: u64,
+    location: (&str, u64),
+    diagnostics: &mut Vec<Diagnostic>,
+) -> io::Result<Option<(BlipType, Vec<u8>)>> {
+    if reader.position() + 8 > end {
+        return Ok(None);
+    }
+    let blip_offset = location.1 + reader.position();
+    let header = OfficeArtRecordHeader::from_reader(reader)?;
+    let Some(blip_type) = BlipType::from_rec_type(header.recType) else {
+        return Ok(None);
+    };
+    let mut body = vec![0; header.recLen as usize];
+    reader.read_exact(&mut body)?;
+
+    // a second UID follows the first one for odd instances
+    let cb_uid = if header.recInstance & 1 == 1 { 32 } else { 16 };
+    let data = match blip_type {
+        BlipType::Emf | BlipType::Wmf | BlipType::Pict => {
+            let Some(metafile_header) = body.get(cb_uid..cb_uid + CB_METAFILE_HEADER) else {
+                return Ok(None);
+            };
+            let compressed = &body[cb_uid + CB_METAFILE_HEADER..];
+            // fCompression is 0 for DEFLATE and 0xFE for none
+            if metafile_header[32] == 0 {
+                let mut data = vec![];
+                if let Err(err) = ZlibDecoder::new(compressed).read_to_end(&mut data) {
+                    diagnostics.push(
+                        Diagnostic::new(
+                            Severity::Error,
+                            "blip_corrupt",
+                            format!("the {blip_type:?} BLIP can not be decompressed: {err}"),
+                        )
+                        .range(
+                            location.0,
+                            blip_offset,
+                            blip_offset + 8 + body.len() as u64,
+                        ),
+                    );
+                    return Ok(None);
+                }
+                data
+            } else {
+                compressed.to_vec()
+            }
+        }
+        // the bitmaps start with a tag byte
+        _ => match body.get(cb_uid + 1..) {
+            Some(data) => data.to_vec(),
+            None => return Ok(None),
+        },
+    };
+
+    Ok(Some((blip_type, data)))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn picture(
+    source: &str,
+    stream_name: &str,
+    offset: u64,
+    len: u64,
+    picf: Option<PICF>,
+    fbse: Option<OfficeArtFBSE>,
+    blip_type: BlipType,
+    data: Vec<u8>,
+) -> Picture {
+    Picture {
+        source: source.to_string(),
+        stream_name: stream_name.to_string(),
+        offset,
+        len,
+        blip_type,
+        size: data.len(),
+        sha256: hex::encode(Sha256::digest(&data)),
+        picf,
+        fbse,
+        data,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    use crate::decoder::from_reader;
+
+    #[test]
+    fn test_pictures() {
+        let record_header = |ver_instance: u16, rec_type: u16, len: usize| {
+            let mut header = ver_instance.to_le_bytes().to_vec();
+            header.extend(rec_type.to_le_bytes());
+            header.extend((len as u32).to_le_bytes());
+            header
+        };
+        let fbse = |bt: u8, size: usize, fo_delay: u32| {
+            let mut fbse = vec![bt, bt];
+            fbse.extend([0; 16 + 2]);
+            fbse.extend((size as u32).to_le_bytes());
+            fbse.extend(1u32.to_le_bytes());
+            fbse.extend(fo_delay.to_le_bytes());
+            fbse.extend([0; 4]);
+            fbse
+        };
+
+        // a CHPX with sprmCPicLocation and sprmCFSpec
+        let mut page = vec![0; 512];
+        page[0..4].copy_from_slice(&0x400i32.to_le_bytes());
+        page[4..8].copy_from_slice(&0x402i32.to_le_bytes());
+        page[8] = (0x1F0 / 2) as u8;
+        page[0x1F0..0x1FA].copy_from_slice(&[9, 0x03, 0x6A, 0x10, 0, 0, 0, 0x55, 0x08, 1]);
+        page[511] = 1;
+        let chpxs = from_reader::chpx_fkp_from_bytes(&page);
+        assert_eq!(chpxs.len(), 1);
+        assert_eq!((chpxs[0].fc_start, chpxs[0].fc_end), (0x400, 0x402));
+        assert_eq!(chpxs[0].grpprl.len(), 2);
+        assert_eq!(chpxs[0].grpprl[0].sprm, SPRM_C_PIC_LOCATION);
+        assert_eq!(chpxs[0].grpprl[0].operand, [0x10, 0, 0, 0]);
+
+        // an inline PNG in the Data stream, after the PICF and the shape
+        let png = b"\x89PNG\r\n\x1a\n not really";
+        let mut blip = record_header(0x6E00, 0xF01E, 16 + 1 + png.len());
+        blip.extend([0; 16 + 1]);
+        blip.extend(png);
+        let mut office_art = record_header(0x000F, 0xF004, 0);
+        office_art.extend(record_header(0x0062, 0xF007, 36 + blip.len()));
+        office_art.extend(fbse(6, blip.len(), 0));
+        office_art.extend(&blip);
+        let mut data_stream = vec![0; 0x10];
+        data_stream.extend(((0x44 + office_art.len()) as u32).to_le_bytes());
+        data_stream.extend(0x44u16.to_le_bytes());
+        data_stream.extend(0x64u16.to_le_bytes());
+        data_stream.extend([0; 0x44 - 8]);
+        data_stream.extend(&office_art);
+
+        let mut diagnostics = vec![];
+        let pictures = data_stream_pictures(&data_stream, 0x10, &mut diagnostics).unwrap();
+        assert_eq!(pictures.len(), 1);
+        assert_eq!(pictures[0].blip_type, BlipType::Png);
+        assert_eq!(pictures[0].data, png);
+        assert_eq!(pictures[0].len, 0x44 + office_art.len() as u64);
+        assert_eq!(pictures[0].picf.as_ref().unwrap().mm, 0x64);
+        assert_eq!(pictures[0].fbse.as_ref().unwrap().btWin32, 6);
+
+        // older documents store a WMF after the PICF, other mapping modes are unknown
+        for (mm, blip_type) in [(0x0008u16, BlipType::Wmf), (0x0099, BlipType::Unknown)] {
+            let mut data_stream = (0x44u32 + 4).to_le_bytes().to_vec();
+            data_stream.extend(0x44u16.to_le_bytes());
+            data_stream.extend(mm.to_le_bytes());
+            data_stream.extend([0; 0x44 - 8]);
+            data_stream.extend(b"WMF?");
+            let pictures = data_stream_pictures(&data_stream, 0, &mut diagnostics).unwrap();
+            assert_eq!(pictures[0].blip_type, blip_type);
+            assert_eq!(pictures[0].data, b"WMF?");
+        }
+
+        // a compressed EMF of the BLIP store, stored at foDelay in the WordDocument stream
+        let emf = b" EMF not really".repeat(8);
+        let mut compressed = vec![];
+        flate2::write::ZlibEncoder::new(&mut compressed, flate2::Compression::default())
+            .write_all(&emf)
+            .unwrap();
+        let mut blip = record_header(0x3D40, 0xF01A, 16 + 34 + compressed.len());
+        blip.extend([0; 16 + 32]);
+        blip.extend([0x00, 0xFE]);
+        blip.extend(&compressed);
+        let mut word_document = vec![0; 0x20];
+        word_document.extend(&blip);
+        let mut bstore = record_header(0x002F, 0xF007, 36);
+        bstore.extend(fbse(2, blip.len(), 0x20));
+        let mut dgg_info = record_header(0x000F, 0xF000, 8 + 8 + bstore.len());
+        dgg_info.extend(record_header(0x0000, 0xF006, 0));
+        dgg_info.extend(record_header(0x001F, 0xF001, bstore.len()));
+        dgg_info.extend(&bstore);
+
+        let pictures =
+            blip_store_pictures(&dgg_info, 0x100, "1Table", &word_document, &mut diagnostics)
+                .unwrap();
+        assert_eq!(pictures.len(), 1);
+        assert_eq!(pictures[0].blip_type, BlipType::Emf);
+        assert_eq!(pictures[0].data, emf);
+        assert_eq!(pictures[0].stream_name, "WordDocument");
+        assert_eq!(
+            (pictures[0].offset, pictures[0].len),
+            (0x20, blip.len() as u64)
+        );
+        assert_eq!(
+            pictures[0].sha256,
+            hex::encode(<sha2::Sha256 as sha2::Digest>::digest(&emf))
+        );
+        assert!(diagnostics.is_empty());
+
+        // a metafile that can not be decompressed is left out and reported
+        word_document[0x20 + 8 + 16 + 34..].fill(0xFF);
+        let pictures =
+            blip_store_pictures(&dgg_info, 0x100, "1Table", &word_document, &mut diagnostics)
+                .unwrap();
+        assert!(pictures.is_empty());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert_eq!(diagnostics[0].code, "blip_corrupt");
+        assert_eq!(diagnostics[0].stream_name.as_deref(), Some("WordDocument"));
+        assert_eq!(
+            diagnostics[0].range,
+            Some(ByteRange {
+                start: 0x20,
+                end: 0x20 + blip.len() as u64
+            })
+        );
+    }
+}
@@ -0,0 +1,270 @@
+//! Text left behind in bytes the document no longer refers to: the parts of the WordDocument
+//! stream outside the pieces of the piece table, which fast saves leave behind, and the free,
+//! orphaned and slack regions of the compound file
+use std::{
+    collections::HashSet,
+    io::{self, Read, Seek},
+};
+
+use cfb::CompoundFile;
+
+use super::{compound_file::CONTAINER, model::*};
+
+/// Shorter runs are too likely to be the bytes of other structures that happen to be printable
+const MIN_RUN_CHARS: usize = 8;
+
+/// The container structures that hold bytes no stream refers to
+const CONTAINER_SOURCES: [&str; 5] = [
+    "FreeSectorData",
+    "FreeMiniSectorData",
+    "OrphanedSectors",
+    "OrphanedMiniSectors",
+    "StreamSlack",
+];
+
+/// The characters of CP1252 from 0x80 to 0x9F, `None` for the bytes it leaves undefined
+pub const CP1252_80_9F: [Option<char>; 32] = [
+    Some('€'),
+    None,
+    Some('‚'),
+    Some('ƒ'),
+    Some('„'),
+    Some('…'),
+    Some('†'),
+    Some('‡'),
+    Some('ˆ'),
+    Some('‰'),
+    Some('Š'),
+    Some('‹'),
+    Some('Œ'),
+    None,
+    Some('Ž'),
+    None,
+    None,
+    Some('‘'),
+    Some('’'),
+    Some('“'),
+    Some('”'),
+    Some('•'),
+    Some('–'),
+    Some('—'),
+    Some('˜'),
+    Some('™'),
+    Some('š'),
+    Some('›'),
+    Some('œ'),
+    None,
+    Some('ž'),
+    Some('Ÿ'),
+];
+
+/// Scans the bytes of the WordDocument stream that neither the FIB, which ends at `fib_end`, nor
+/// a piece refers to, and the container structures that no stream refers to, for text
+pub fn read_residual_text<F: Read + Seek>(
+    cfb: &mut CompoundFile<F>,
+    fib: &Fib,
+    fib_end: u64,
+    piece_table: &PLCF<PCD>,
+    container: &[PhysicalStructure],
+) -> io::Result<Vec<ResidualText>> {
+    let mut word_document = vec![];
+    cfb.open_stream("WordDocument")?
+        .read_to_end(&mut word_document)?;
+
+    // files that are not complex have no piece table, their text is between fcMin and fcMac
+    let mut referenced = if piece_table.rgstruct.is_empty() {
+        vec![(fib.fcMin, fib.fcMac)]
+    } else {
+        piece_table.fc_ranges()
+    };
+    referenced.push((0, fib_end as i32));
+
+    let mut residual_text = vec![];
+    for (start, end) in unreferenced_ranges(word_document.len(), referenced) {
+        residual_text.extend(text_runs(
+            &word_document[start..end],
+            start as u64,
+            "WordDocument",
+            "unreferenced",
+        ));
+    }
+    for structure in container {
+        let Some(source) = structure
+            .structure_name
+            .as_deref()
+            .filter(|name| CONTAINER_SOURCES.contains(name))
+        else {
+            continue;
+        };
+        residual_text.extend(text_runs(
+            &structure.bytes,
+            structure.start_index as u64,
+            CONTAINER,
+            source,
+        ));
+    }
+
+    Ok(residual_text)
+}
+
+/// The ranges of a stream of `len` bytes that none of the `referenced` ranges cover
+pub fn unreferenced_ranges(len: usize, mut referenced: Vec<(i32, i32)>) -> Vec<(usize, usize)> {
+    referenced.sort_unstable();
+
+    let mut ranges = vec![];
+    let mut position = 0;
+    for (start, end) in referenced {
+        let start = (start.max(0) as usize).min(len);
+        let end = (end.max(0) as usize).min(len);
+        // e.g. an fcMac before fcMin, the range refers to nothing
+        if end <= start {
+            continue;
+        }
+        if start > position {
+            ranges.push((position, start));
+        }
+        position = position.max(end);
+    }
+    if position < len {
+        ranges.push((position, len));
+    }
+    ranges
+}
+
+/// The CP1252 and UTF-16LE text runs of `bytes`, which start at `offset` in `stream_name`
+pub fn text_runs(bytes: &[u8], offset: u64, stream_name: &str, source: &str) -> Vec<ResidualText> {
+    let mut runs = runs_of(bytes, 1, |unit| cp1252_char(unit[0]))
+        .into_iter()
+        .map(|run| (run, "cp1252"))
+        .collect::<Vec<_>>();
+    // UTF-16 text is only 2-byte aligned within its own stream, slack can hold it at any offset
+    for alignment in 0..2 {
+        let units = bytes.get(alignment..).unwrap_or_default();
+        let utf_16_runs = runs_of(units, 2, |unit| {
+            utf_16_char(u16::from_le_bytes([unit[0], unit[1]]))
+        });
+        runs.extend(
+            utf_16_runs
+                .into_iter()
+                .map(|(start, len, text)| ((start + alignment, len, text), "utf-16le")),
+        );
+    }
+    runs.sort_by_key(|((start, _, _), _)| *start);
+
+    runs.into_iter()
+        .map(|((start, len, text), encoding)| ResidualText {
+            stream_name: stream_name.to_string(),
+            source: source.to_string(),
+            offset: offset + start as u64,
+            len: len as u64,
+            encoding: encoding.to_string(),
+            text,
+        })
+        .collect()
+}
+
+/// The runs of characters of `width` bytes that `decode` accepts as start, length in bytes and
+/// text. A run has to be long enough, mostly letters and digits and not a single repeated
+/// character to count as text
+fn runs_of(
+    bytes: &[u8],
+    width: usize,
+    decode: impl Fn(&[u8]) -> Option<char>,
+) -> Vec<(usize, usize, String)> {
+    let is_text = |text: &str| {
+        let chars = text.chars().count();
+        let alphanumeric = text.chars().filter(|c| c.is_alphanumeric()).count();
+        let distinct = text.chars().collect::<HashSet<_>>().len();
+        chars >= MIN_RUN_CHARS && alphanumeric * 2 >= chars && distinct >= 3
+    };
+
+    let mut runs = vec![];
+    let mut run_start = 0;
+    let mut text = String::new();
+    for (i, unit) in bytes.chunks_exact(width).enumerate() {
+        if let Some(c) = decode(unit) {
+            if text.is_empty() {
+                run_start = i * width;
+            }
+            text.push(c);
+            continue;
+        }
+        if is_text(&text) {
+            runs.push((run_start, i * width - run_start, text.clone()));
+        }
+        text.clear();
+    }
+    if is_text(&text) {
+        let end = bytes.len() / width * width;
+        runs.push((run_start, end - run_start, text));
+    }
+    runs
+}
+
+/// The character of a CP1252 byte that can be part of text, tabs and paragraph and line breaks
+/// included
+fn cp1252_char(byte: u8) -> Option<char> {
+    match byte {
+        0x09 | 0x0B | 0x0D | 0x20..=0x7E | 0xA0..=0xFF => Some(byte as char),
+        0x80..=0x9F => CP1252_80_9F[byte as usize - 0x80],
+        _ => None,
+    }
+}
+
+/// The character of a UTF-16 code unit that can be part of text. Only Latin, Greek, Cyrillic,
+/// Hebrew, Arabic and typographic punctuation are accepted, pairs of CP1252 bytes would pass as
+/// CJK characters otherwise
+fn utf_16_char(unit: u16) -> Option<char> {
+    match unit {
+        0x09 | 0x0B | 0x0D | 0x20..=0x7E | 0xA0..=0x06FF | 0x2010..=0x2027 => {
+            char::from_u32(unit as u32)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_residual_text() {
+        let mut bytes = b"\x00\x07Old draft \x93text\x94\x00".to_vec();
+        bytes.extend("Gelöschter Text".encode_utf16().flat_map(u16::to_le_bytes));
+        bytes.extend([0xFF; 12]);
+        bytes.extend(b"\x00\x01\x02abc\x00");
+
+        let runs = text_runs(&bytes, 0x100, "WordDocument", "unreferenced")
+            .into_iter()
+            .map(|run| (run.offset, run.len, run.encoding, run.text))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            runs,
+            [
+                (
+                    0x102,
+                    16,
+                    "cp1252".to_string(),
+                    "Old draft “text”".to_string()
+                ),
+                (
+                    0x113,
+                    30,
+                    "utf-16le".to_string(),
+                    "Gelöschter Text".to_string()
+                ),
+            ]
+        );
+
+        // the FIB and two pieces, one of them past the end of the stream
+        assert_eq!(
+            unreferenced_ranges(1000, vec![(600, 700), (0, 0x44), (900, 1200), (100, 200)]),
+            [(0x44, 100), (200, 600), (700, 900)]
+        );
+        // a range that ends before its start refers to nothing
+        assert_eq!(
+            unreferenced_ranges(1000, vec![(0, 0x44), (0x400, 0)]),
+            [(0x44, 1000)]
+        );
+    }
+}
@@ -0,0 +1,581 @@
+//! Tracked changes of a document: the revision marks of the character and paragraph properties,
+//! the authors of the SttbfRMark and the revision settings of the DOP, and the rsids of the
+//! editing sessions that the runs of text were changed in
+use std::{
+    collections::{BTreeSet, HashMap},
+    io::{self, Read, Seek},
+};
+
+use cfb::CompoundFile;
+
+use super::{
+    from_reader::papx_fkp_from_bytes, model::*, pictures::read_chpxs, sttb::sttb_from_bytes,
+};
+
+/// sprmCFRMarkDel, set on deleted text
+const SPRM_C_F_R_MARK_DEL: u16 = 0x0800;
+/// sprmCFRMarkIns, set on inserted text
+const SPRM_C_F_R_MARK_INS: u16 = 0x0801;
+/// sprmCIbstRMark, the author of the insertion in the SttbfRMark
+const SPRM_C_IBST_R_MARK: u16 = 0x4804;
+/// sprmCDttmRMark, the DTTM of the insertion
+const SPRM_C_DTTM_R_MARK: u16 = 0x6805;
+/// sprmCIbstRMarkDel, the author of the deletion
+const SPRM_C_IBST_R_MARK_DEL: u16 = 0x4863;
+/// sprmCDttmRMarkDel, the DTTM of the deletion
+const SPRM_C_DTTM_R_MARK_DEL: u16 = 0x6864;
+/// sprmCPropRMark as written by Word 97 and by later versions, a PropRMark of a change of the
+/// character properties
+const SPRM_C_PROP_R_MARK: [u16; 2] = [0xCA57, 0xCA89];
+/// sprmPPropRMark, a PropRMark of a change of the paragraph properties
+const SPRM_P_PROP_R_MARK: u16 = 0xC63F;
+/// The rsid sprms of the character properties: sprmCRsidProp, sprmCRsidText and sprmCRsidRMDel
+const SPRM_C_RSIDS: [(u16, &str); 3] = [
+    (0x6815, "properties"),
+    (0x6816, "text"),
+    (0x6817, "deletion"),
+];
+/// sprmPRsid, the rsid of the paragraph properties
+const SPRM_P_RSID: u16 = 0x6467;
+
+/// Reads the revision settings of the DOP, the authors of the SttbfRMark and the revisions of the
+/// CHPXs and PAPXs. Runs of the same revision that follow each other are merged
+pub fn read_revisions<F: Read + Seek>(
+    cfb: &mut CompoundFile<F>,
+    fib: &Fib,
+    table_stream_name: &str,
+    piece_table: &PLCF<PCD>,
+    text: &Text,
+) -> io::Result<Revisions> {
+    let word_document = read_stream(cfb, "WordDocument")?;
+    let table_stream = read_stream(cfb, table_stream_name)?;
+    let slice = |fc: i32, lcb: u32| table_stream.get(fc as usize..fc as usize + lcb as usize);
+
+    let settings = revision_settings(slice(fib.fcDop, fib.lcbDop).unwrap_or_default());
+    let authors = match slice(fib.fcSttbfRMark, fib.lcbSttbfRMark) {
+        Some(sttb) if sttb.len() >= 4 => sttb_from_bytes(sttb)?
+            .into_iter()
+            .map(|(author, _)| author)
+            .collect(),
+        _ => vec![],
+    };
+
+    let cp_ranges = |fc_start, fc_end| cp_ranges(fib, piece_table, fc_start, fc_end);
+    let mut marks = vec![];
+    for chpx in read_chpxs(&word_document, &table_stream, fib) {
+        for mark in character_revisions(&chpx.grpprl) {
+            for cps in cp_ranges(chpx.fc_start, chpx.fc_end) {
+                marks.push((mark.clone(), cps));
+            }
+        }
+    }
+    for papx in read_papxs(&word_document, &table_stream, fib) {
+        let Some(mark) = prop_r_mark(&papx.grpprl, &[SPRM_P_PROP_R_MARK], "paragraph properties")
+        else {
+            continue;
+        };
+        for cps in cp_ranges(papx.fc_start, papx.fc_end) {
+            marks.push((mark.clone(), cps));
+        }
+    }
+
+    // the CPs of all subdocuments follow each other in this order
+    let document = [
+        &text.main_text,
+        &text.footnote_text,
+        &text.header_text,
+        &text.annotation_text,
+        &text.endnote_text,
+        &text.textbox_text,
+        &text.header_textbox_text,
+    ]
+    .iter()
+    .flat_map(|text_doc| text_doc.bytes().to_vec())
+    .collect::<Vec<_>>();
+    let revisions = merge_revisions(marks, &authors, &document);
+
+    Ok(Revisions {
+        settings,
+        authors,
+        revisions,
+    })
+}
+
+/// The CP ranges of the characters at `fc_start` to `fc_end` in the WordDocument stream
+fn cp_ranges(fib: &Fib, piece_table: &PLCF<PCD>, fc_start: i32, fc_end: i32) -> Vec<(i32, i32)> {
+    // files that are not complex have no piece table, their text starts at fcMin
+    if piece_table.rgstruct.is_empty() {
+        vec![(fc_start - fib.fcMin, fc_end - fib.fcMin)]
+    } else {
+        piece_table.fc_range_to_cps(fc_start, fc_end)
+    }
+}
+
+fn read_stream<F: Read + Seek>(cfb: &mut CompoundFile<F>, name: &str) -> io::Result<Vec<u8>> {
+    let mut bytes = vec![];
+    cfb.open_stream(name)?.read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Reads the PAPXs of all PapxFkp pages of the PlcBtePapx, whose bin table entries are the same
+/// as those of the PlcBteChpx
+fn read_papxs(word_document: &[u8], table_stream: &[u8], fib: &Fib) -> Vec<Papx> {
+    let start = fib.fcPlcfbtePapx as usize;
+    let Some(plcf_bte_papx) = table_stream.get(start..start + fib.lcbPlcfbtePapx as usize) else {
+        return vec![];
+    };
+    if plcf_bte_papx.len() < 4 {
+        return vec![];
+    }
+
+    PLCF::<PnFkpChpx>::from_bytes(plcf_bte_papx)
+        .rgstruct
+        .iter()
+        .filter_map(|pn_fkp| {
+            let start = pn_fkp.pn as usize * 512;
+            word_document.get(start..start + 512)
+        })
+        .flat_map(papx_fkp_from_bytes)
+        .collect()
+}
+
+/// The revision settings of the start of the DOP, which Word 6.0 to Word 2003 share
+pub fn revision_settings(dop: &[u8]) -> RevisionSettings {
+    let flag = |offset: usize, mask: u8| dop.get(offset).is_some_and(|byte| byte & mask != 0);
+
+    RevisionSettings {
+        track_changes: flag(5, 0x80),
+        show_markup: flag(7, 0x04),
+        print_markup: flag(7, 0x08),
+        lock_revisions: flag(7, 0x20),
+    }
+}
+
+/// A revision of a run before it gets its author and CPs: the type, the index of the author and
+/// the DTTM
+#[derive(Clone, Debug, PartialEq)]
+pub struct RevisionMark {
+    pub kind: &'static str,
+    pub ibst: Option<u16>,
+    pub dttm: Option<u32>,
+}
+
+/// The insertions, deletions and property changes that the grpprl of a CHPX marks
+pub fn character_revisions(grpprl: &[Prl]) -> Vec<RevisionMark> {
+    let operand = |sprm: u16| {
+        grpprl
+            .iter()
+            .rev()
+            .find(|prl| prl.sprm == sprm)
+            .map(|prl| prl.operand.as_slice())
+    };
+    let ibst = |sprm: u16| {
+        let op = operand(sprm)?.get(0..2)?;
+        Some(u16::from_le_bytes([op[0], op[1]]))
+    };
+    let dttm = |sprm: u16| {
+        let op = operand(sprm)?.get(0..4)?;
+        Some(u32::from_le_bytes([op[0], op[1], op[2], op[3]]))
+    };
+    let is_set = |sprm: u16| operand(sprm).and_then(|op| op.first()) == Some(&1);
+
+    let mut marks = vec![];
+    if is_set(SPRM_C_F_R_MARK_INS) {
+        marks.push(RevisionMark {
+            kind: "insertion",
+            ibst: ibst(SPRM_C_IBST_R_MARK),
+            dttm: dttm(SPRM_C_DTTM_R_MARK),
+        });
+    }
+    if is_set(SPRM_C_F_R_MARK_DEL) {
+        // Word 97 marks the author and date of deletions with the sprms of insertions
+        marks.push(RevisionMark {
+            kind: "deletion",
+            ibst: ibst(SPRM_C_IBST_R_MARK_DEL).or_else(|| ibst(SPRM_C_IBST_R_MARK)),
+            dttm: dttm(SPRM_C_DTTM_R_MARK_DEL).or_else(|| dttm(SPRM_C_DTTM_R_MARK)),
+        });
+    }
+    marks.extend(prop_r_mark(
+        grpprl,
+        &SPRM_C_PROP_R_MARK,
+        "character properties",
+    ));
+    marks
+}
+
+/// The property change of a PropRMark operand, its size followed by fPropRMark, the index of the
+/// author and the DTTM
+fn prop_r_mark(grpprl: &[Prl], sprms: &[u16], kind: &'static str) -> Option<RevisionMark> {
+    let operand = &grpprl
+        .iter()
+        .rev()
+        .find(|prl| sprms.contains(&prl.sprm))?
+        .operand;
+    let prop_r_mark = operand.get(1..8)?;
+    (prop_r_mark[0] != 0).then(|| RevisionMark {
+        kind,
+        ibst: Some(u16::from_le_bytes([prop_r_mark[1], prop_r_mark[2]])),
+        dttm: Some(u32::from_le_bytes([
+            prop_r_mark[3],
+            prop_r_mark[4],
+            prop_r_mark[5],
+            prop_r_mark[6],
+        ])),
+    })
+}
+
+/// Sorts the revision marks of the runs by CP and merges those of the same revision that follow
+/// each other, `document` holds the text of all subdocuments
+pub fn merge_revisions(
+    mut marks: Vec<(RevisionMark, (i32, i32))>,
+    authors: &[String],
+    document: &[u8],
+) -> Vec<Revision> {
+    marks.sort_by_key(|(mark, (cp_start, _))| (mark.kind, *cp_start));
+
+    let mut merged: Vec<(RevisionMark, (i32, i32))> = vec![];
+    for (mark, (cp_start, cp_end)) in marks {
+        match merged.last_mut() {
+            Some((last, (_, last_end))) if *last == mark && *last_end == cp_start => {
+                *last_end = cp_end;
+            }
+            _ => merged.push((mark, (cp_start, cp_end))),
+        }
+    }
+    merged.sort_by_key(|(_, (cp_start, _))| *cp_start);
+
+    merged
+        .into_iter()
+        .map(|(mark, (cp_start, cp_end))| Revision {
+            kind: mark.kind.to_string(),
+            author: mark
+                .ibst
+                .and_then(|ibst| authors.get(ibst as usize).cloned()),
+            date: mark.dttm.and_then(dttm_to_string),
+            cp_start,
+            cp_end,
+            text: document
+                .get(cp_start.max(0) as usize..cp_end.max(0) as usize)
+                .map(|bytes| TextDoc::from(bytes).to_fmt_string())
+                .unwrap_or_default(),
+        })
+        .collect()
+}
+
+/// Reads the rsids of the Plrsid and links them to the runs and styles that were changed in their
+/// editing session
+pub fn read_rsids<F: Read + Seek>(
+    cfb: &mut CompoundFile<F>,
+    fib: &Fib,
+    table_stream_name: &str,
+    piece_table: &PLCF<PCD>,
+    stylesheet: &SHSHI,
+) -> io::Result<Rsids> {
+    let word_document = read_stream(cfb, "WordDocument")?;
+    let table_stream = read_stream(cfb, table_stream_name)?;
+
+    // the Plrsid is a sorted array of rsids, which Word 2002 and later write
+    let plrsid = fib
+        .fibRgFcLcb2002
+        .as_ref()
+        .and_then(|fib_2002| {
+            let start = fib_2002.fcPlrsid as usize;
+            table_stream.get(start..start + fib_2002.lcbPlrsid as usize)
+        })
+        .unwrap_or_default();
+    let table = plrsid
+        .chunks_exact(4)
+        .map(|rsid| u32::from_le_bytes([rsid[0], rsid[1], rsid[2], rsid[3]]))
+        .collect::<Vec<_>>();
+
+    let mut runs = vec![];
+    for chpx in read_chpxs(&word_document, &table_stream, fib) {
+        for (kind, rsid) in character_rsids(&chpx.grpprl) {
+            for cps in cp_ranges(fib, piece_table, chpx.fc_start, chpx.fc_end) {
+                runs.push((kind, rsid, cps));
+            }
+        }
+    }
+    for papx in read_papxs(&word_document, &table_stream, fib) {
+        let Some(rsid) = rsid_operand(&papx.grpprl, SPRM_P_RSID) else {
+            continue;
+        };
+        for cps in cp_ranges(fib, piece_table, papx.fc_start, papx.fc_end) {
+            runs.push(("paragraph", rsid, cps));
+        }
+    }
+    let styles = stylesheet
+        .styles
+        .iter()
+        .filter_map(|style| Some((style.rsid? as u32, style.xstzName.clone())))
+        .collect::<Vec<_>>();
+
+    Ok(rsids(&table, runs, &styles))
+}
+
+/// The rsids of the grpprl of a CHPX with what was changed in their session
+pub fn character_rsids(grpprl: &[Prl]) -> Vec<(&'static str, u32)> {
+    SPRM_C_RSIDS
+        .iter()
+        .filter_map(|&(sprm, kind)| Some((kind, rsid_operand(grpprl, sprm)?)))
+        .collect()
+}
+
+fn rsid_operand(grpprl: &[Prl], sprm: u16) -> Option<u32> {
+    let operand = grpprl
+        .iter()
+        .rev()
+        .find(|prl| prl.sprm == sprm)?
+        .operand
+        .get(0..4)?;
+    Some(u32::from_le_bytes([
+        operand[0], operand[1], operand[2], operand[3],
+    ]))
+}
+
+/// Links the rsids of the `table` to the `runs` and `styles` of their session. Runs of the same
+/// rsid that follow each other are merged
+pub fn rsids(
+    table: &[u32],
+    mut runs: Vec<(&str, u32, (i32, i32))>,
+    styles: &[(u32, String)],
+) -> Rsids {
+    runs.sort_by_key(|&(kind, rsid, (cp_start, _))| (kind, rsid, cp_start));
+    let mut merged: Vec<(&str, u32, (i32, i32))> = vec![];
+    for (kind, rsid, (cp_start, cp_end)) in runs {
+        match merged.last_mut() {
+            Some((last_kind, last_rsid, (_, last_end)))
+                if *last_kind == kind && *last_rsid == rsid && *last_end == cp_start =>
+            {
+                *last_end = cp_end;
+            }
+            _ => merged.push((kind, rsid, (cp_start, cp_end))),
+        }
+    }
+    merged.sort_by_key(|&(_, _, (cp_start, _))| cp_start);
+
+    let mut sessions = table
+        .iter()
+        .map(|&rsid| {
+            (
+                rsid,
+                RsidSession {
+                    rsid: rsid_to_string(rsid),
+                    runs: 0,
+                    chars: 0,
+                    styles: vec![],
+                },
+            )
+        })
+        .collect::<HashMap<_, _>>();
+    for &(_, rsid, (cp_start, cp_end)) in &merged {
+        if let Some(session) = sessions.get_mut(&rsid) {
+            session.runs += 1;
+            session.chars += cp_end - cp_start;
+        }
+    }
+    for (rsid, style) in styles {
+        if let Some(session) = sessions.get_mut(rsid) {
+            session.styles.push(style.clone());
+        }
+    }
+
+    Rsids {
+        table: table
+            .iter()
+            .filter_map(|rsid| sessions.remove(rsid))
+            .collect(),
+        runs: merged
+            .into_iter()
+            .map(|(kind, rsid, (cp_start, cp_end))| RsidRun {
+                kind: kind.to_string(),
+                rsid: rsid_to_string(rsid),
+                in_table: table.contains(&rsid),
+                cp_start,
+                cp_end,
+            })
+            .collect(),
+    }
+}
+
+/// An rsid as the 8 hexadecimal digits that .docx files use
+pub fn rsid_to_string(rsid: u32) -> String {
+    format!("{rsid:08X}")
+}
+
+/// How many rsids two documents share, the editing sessions of their common history
+pub fn rsid_overlap(reference: &[String], compared: &[String]) -> RsidOverlap {
+    let reference = reference.iter().collect::<BTreeSet<_>>();
+    let compared = compared.iter().collect::<BTreeSet<_>>();
+    let shared = reference
+        .intersection(&compared)
+        .map(|rsid| rsid.to_string())
+        .collect::<Vec<_>>();
+    let union = reference.len() + compared.len() - shared.len();
+
+    RsidOverlap {
+        only_in_reference: reference.len() - shared.len(),
+        only_in_compared: compared.len() - shared.len(),
+        overlap: if union == 0 {
+            0.0
+        } else {
+            shared.len() as f64 / union as f64
+        },
+        shared,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::from_reader;
+
+    #[test]
+    fn test_revisions() {
+        let prl = |sprm: u16, operand: &[u8]| Prl {
+            sprm,
+            operand: operand.to_vec(),
+        };
+        // 2024-03-05 14:30
+        let dttm: u32 = 30 | 14 << 6 | 5 << 11 | 3 << 16 | 124 << 20;
+        let inserted = [
+            prl(0x0801, &[1]),
+            prl(0x4804, &[1, 0]),
+            prl(0x6805, &dttm.to_le_bytes()),
+        ];
+        let mut prop_r_mark = vec![7, 1, 0, 0];
+        prop_r_mark.extend(dttm.to_le_bytes());
+        let deleted_and_formatted = [
+            prl(0x0800, &[1]),
+            prl(0x4804, &[0, 0]),
+            prl(0xCA89, &prop_r_mark),
+        ];
+
+        let insertion = character_revisions(&inserted);
+        let deletion = character_revisions(&deleted_and_formatted);
+        assert_eq!(insertion.len(), 1);
+        assert_eq!(
+            deletion.iter().map(|mark| mark.kind).collect::<Vec<_>>(),
+            ["deletion", "character properties"]
+        );
+        assert!(character_revisions(&[prl(0x0801, &[0])]).is_empty());
+
+        // two runs of the same insertion follow each other
+        let marks = vec![
+            (insertion[0].clone(), (4, 6)),
+            (deletion[0].clone(), (0, 4)),
+            (insertion[0].clone(), (6, 9)),
+        ];
+        let authors = ["Ann Berg".to_string(), "Carl Dahl".to_string()];
+        let revisions = merge_revisions(marks, &authors, b"Old new text\r");
+        let summary = revisions
+            .iter()
+            .map(|revision| {
+                (
+                    revision.kind.as_str(),
+                    revision.author.as_deref(),
+                    revision.date.as_deref(),
+                    revision.cp_start,
+                    revision.cp_end,
+                    revision.text.as_str(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            [
+                ("deletion", Some("Ann Berg"), None, 0, 4, "Old{0x20}"),
+                (
+                    "insertion",
+                    Some("Carl Dahl"),
+                    Some("2024-03-05 14:30"),
+                    4,
+                    9,
+                    "new{0x20}t"
+                ),
+            ]
+        );
+
+        // a PAPX of style 1 with sprmPJc
+        let mut page = vec![0; 512];
+        page[0..4].copy_from_slice(&0x400i32.to_le_bytes());
+        page[4..8].copy_from_slice(&0x410i32.to_le_bytes());
+        page[8] = (0x1F0 / 2) as u8;
+        page[0x1F0..0x1F6].copy_from_slice(&[3, 1, 0, 0x03, 0x24, 1]);
+        page[511] = 1;
+        let papxs = from_reader::papx_fkp_from_bytes(&page);
+        assert_eq!(papxs.len(), 1);
+        assert_eq!(
+            (papxs[0].fc_start, papxs[0].fc_end, papxs[0].istd),
+            (0x400, 0x410, 1)
+        );
+        assert_eq!(papxs[0].grpprl.len(), 1);
+        assert_eq!(papxs[0].grpprl[0].sprm, 0x2403);
+
+        let settings = revision_settings(&[0, 0, 0, 0, 0, 0x80, 0, 0x04]);
+        assert!(settings.track_changes && settings.show_markup);
+        assert!(!settings.print_markup && !settings.lock_revisions);
+    }
+
+    #[test]
+    fn test_rsids() {
+        let prl = |sprm: u16, rsid: u32| Prl {
+            sprm,
+            operand: rsid.to_le_bytes().to_vec(),
+        };
+        let grpprl = [prl(0x6816, 0x00A1_2B3C), prl(0x6815, 0x0011_2233)];
+        assert_eq!(
+            character_rsids(&grpprl),
+            [("properties", 0x0011_2233), ("text", 0x00A1_2B3C)]
+        );
+
+        // two runs of the same session follow each other, one rsid is not in the Plrsid
+        let runs = vec![
+            ("text", 0x00A1_2B3C, (5, 9)),
+            ("text", 0x00A1_2B3C, (0, 5)),
+            ("paragraph", 0x0011_2233, (0, 12)),
+            ("text", 0x00FF_0000, (9, 12)),
+        ];
+        let styles = [(0x0011_2233, "Heading 1".to_string())];
+        let rsids = rsids(&[0x0011_2233, 0x00A1_2B3C], runs, &styles);
+        let sessions = rsids
+            .table
+            .iter()
+            .map(|session| {
+                (
+                    session.rsid.as_str(),
+                    session.runs,
+                    session.chars,
+                    &session.styles[..],
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sessions,
+            [
+                ("00112233", 1, 12, &["Heading 1".to_string()][..]),
+                ("00A12B3C", 1, 9, &[][..]),
+            ]
+        );
+        assert_eq!(rsids.runs.len(), 3);
+        assert!(
+            !rsids
+                .runs
+                .iter()
+                .find(|run| run.rsid == "00FF0000")
+                .unwrap()
+                .in_table
+        );
+
+        let overlap = rsid_overlap(
+            &["00112233".to_string(), "00A12B3C".to_string()],
+            &["00A12B3C".to_string(), "00C0FFEE".to_string()],
+        );
+        assert_eq!(overlap.shared, ["00A12B3C"]);
+        assert_eq!(
+            (overlap.only_in_reference, overlap.only_in_compared),
+            (1, 1)
+        );
+        assert!((overlap.overlap - 1.0 / 3.0).abs() < 1e-9);
+    }
+}
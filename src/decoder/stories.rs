@@ -0,0 +1,519 @@
+//! The stories that the PLCs of the table stream split the subdocuments into, e.g. the headers
+//! and footers of every section in the header document, the footnotes or the comments and their
+//! references
+use std::io::{self, Read, Seek, SeekFrom};
+
+use super::{model::*, office_art::read_plcf, sttb::read_sttb};
+
+/// The CPs of main text shown on either side of the reference of a note
+const CONTEXT_CPS: i32 = 20;
+
+/// The stories at the start of the header document, before those of the sections
+const SEPARATOR_STORIES: [&str; 6] = [
+    "footnote separator",
+    "footnote continuation separator",
+    "footnote continuation notice",
+    "endnote separator",
+    "endnote continuation separator",
+    "endnote continuation notice",
+];
+
+/// The stories of every section of the header document, in order
+const SECTION_STORIES: [&str; 6] = [
+    "even header",
+    "odd header",
+    "even footer",
+    "odd footer",
+    "first page header",
+    "first page footer",
+];
+
+/// Splits the header document into the stories of the Plcfhdd, the note separators followed
+/// by six headers and footers per section. Empty stories are inherited from the section before
+pub fn read_header_stories<R: Read + Seek>(
+    table_stream: &mut R,
+    fib: &Fib,
+    header_text: &TextDoc,
+) -> io::Result<Vec<HeaderStory>> {
+    let cps = read_cps(table_stream, (fib.fcPlcfhdd, fib.lcbPlcfhdd))?;
+    Ok(header_stories(&cps, header_text))
+}
+
+/// The stories of the header document between the CPs of its Plcfhdd
+pub fn header_stories(cps: &[i32], header_text: &TextDoc) -> Vec<HeaderStory> {
+    let story_count = cps.len().saturating_sub(1);
+    // only complete sections are named, Word ends the header document with one more story
+    let section_count = story_count.saturating_sub(SEPARATOR_STORIES.len()) / SECTION_STORIES.len();
+
+    cps.windows(2)
+        .enumerate()
+        .map(|(i, cps)| {
+            let section_story = i.checked_sub(SEPARATOR_STORIES.len());
+            let (section, kind) = match section_story {
+                None => (None, SEPARATOR_STORIES[i]),
+                Some(j) if j < section_count * SECTION_STORIES.len() => (
+                    Some((j / SECTION_STORIES.len()) as u32 + 1),
+                    SECTION_STORIES[j % SECTION_STORIES.len()],
+                ),
+                Some(_) => (None, "end of the header document"),
+            };
+            let name = match section {
+                Some(section) => format!("Section {section} – {kind}"),
+                None => capitalize(kind),
+            };
+
+            HeaderStory {
+                name,
+                section,
+                kind: kind.to_string(),
+                cp_start: cps[0],
+                cp_end: cps[1],
+                text: story_text(header_text, cps[0], cps[1]),
+            }
+        })
+        .collect()
+}
+
+/// Reads the notes of the reference PLC `plc_ref` and the text PLC `plc_txt`, the footnotes or
+/// the endnotes depending on `kind`
+pub fn read_notes<R: Read + Seek>(
+    table_stream: &mut R,
+    kind: &str,
+    plc_ref: (i32, u32),
+    plc_txt: (i32, u32),
+    main_text: &TextDoc,
+    note_text: &TextDoc,
+) -> io::Result<Vec<Note>> {
+    let Some(references) = read_plcf::<FRD, _>(table_stream, plc_ref)? else {
+        return Ok(vec![]);
+    };
+    let text_cps = read_cps(table_stream, plc_txt)?;
+    Ok(notes(kind, &references, &text_cps, main_text, note_text))
+}
+
+/// Pairs the references of the notes with their stories in the note document. The text PLC
+/// ends with one more CP than there are notes, for the end of the note document
+pub fn notes(
+    kind: &str,
+    references: &PLCF<FRD>,
+    text_cps: &[i32],
+    main_text: &TextDoc,
+    note_text: &TextDoc,
+) -> Vec<Note> {
+    let mut auto_numbered_count = 0;
+
+    references
+        .rgfc
+        .iter()
+        .zip(&references.rgstruct)
+        .enumerate()
+        .map(|(i, (&anchor_cp, frd))| {
+            let auto_numbered = frd.nAuto != 0;
+            if auto_numbered {
+                auto_numbered_count += 1;
+            }
+            let (cp_start, cp_end) = match text_cps.get(i..i + 2) {
+                Some(cps) => (cps[0], cps[1]),
+                None => (0, 0),
+            };
+
+            Note {
+                kind: kind.to_string(),
+                auto_numbered,
+                number: auto_numbered.then_some(auto_numbered_count),
+                anchor_cp,
+                mark: story_text(main_text, anchor_cp, anchor_cp + 1),
+                context_before: story_text(main_text, anchor_cp - CONTEXT_CPS, anchor_cp),
+                context_after: story_text(main_text, anchor_cp + 1, anchor_cp + 1 + CONTEXT_CPS),
+                cp_start,
+                cp_end,
+                text: story_text(note_text, cp_start, cp_end),
+            }
+        })
+        .collect()
+}
+
+/// Warns about the notes whose mark does not match how they are numbered, an auto-numbered note
+/// has to be referenced by the 0x02 character and a custom mark must not be it
+pub fn note_diagnostics(notes: &[Note]) -> Vec<Diagnostic> {
+    const AUTO_NUMBER_MARK: &str = "{0x02}";
+
+    notes
+        .iter()
+        .filter(|note| note.auto_numbered != (note.mark == AUTO_NUMBER_MARK))
+        .map(|note| {
+            let numbering = if note.auto_numbered {
+                "auto-numbered"
+            } else {
+                "custom mark"
+            };
+            let message = format!(
+                "{} {} at CP {} is referenced by {}",
+                numbering, note.kind, note.anchor_cp, note.mark
+            );
+            Diagnostic::new(Severity::Warning, "note_mark_mismatch", message)
+        })
+        .collect()
+}
+
+/// Reads the comments of the annotation document with their authors from the GrpXstAtnOwners,
+/// the ranges they comment on from the annotation bookmarks and their dates and threads from the
+/// AtrdExtra
+pub fn read_annotations<R: Read + Seek>(
+    table_stream: &mut R,
+    fib: &Fib,
+    main_text: &TextDoc,
+    annotation_text: &TextDoc,
+) -> io::Result<Vec<Annotation>> {
+    let Some(references) =
+        read_plcf::<ATRDPre10, _>(table_stream, (fib.fcPlcfandRef, fib.lcbPlcfandRef))?
+    else {
+        return Ok(vec![]);
+    };
+    let text_cps = read_cps(table_stream, (fib.fcPlcfandTxt, fib.lcbPlcfandTxt))?;
+    let owners = read_xsts(
+        table_stream,
+        (fib.fcGrpXstAtnOwners, fib.lcbGrpXstAtnOwners),
+    )?;
+    let atrd_extra = fib
+        .fibRgFcLcb2002
+        .as_ref()
+        .map(|fib_2002| (fib_2002.fcAtrdExtra, fib_2002.lcbAtrdExtra))
+        .unwrap_or_default();
+    let extra = read_bytes(table_stream, atrd_extra)?;
+
+    // the lTag of every annotation bookmark is in the extra data of its name, an ATNBE
+    let bookmark_tags = read_sttb(table_stream, (fib.fcSttbfAtnBkmk, fib.lcbSttbfAtnBkmk))?
+        .into_iter()
+        .map(|(_, atnbe)| {
+            atnbe
+                .get(2..6)
+                .map(|tag| i32::from_le_bytes([tag[0], tag[1], tag[2], tag[3]]))
+        })
+        .collect::<Vec<_>>();
+    let bookmark_starts =
+        read_plcf::<FBKF, _>(table_stream, (fib.fcPlcfAtnbkf, fib.lcbPlcfAtnbkf))?;
+    let bookmark_ends = read_cps(table_stream, (fib.fcPlcfAtnbkl, fib.lcbPlcfAtnbkl))?;
+    let range_of = |tag: i32| {
+        let bookmark = bookmark_tags.iter().position(|&l_tag| l_tag == Some(tag))?;
+        let starts = bookmark_starts.as_ref()?;
+        let start = *starts.rgfc.get(bookmark)?;
+        let end = *bookmark_ends.get(starts.rgstruct.get(bookmark)?.ibkl as usize)?;
+        Some((start, end))
+    };
+
+    let annotations = references
+        .rgfc
+        .iter()
+        .zip(&references.rgstruct)
+        .enumerate()
+        .map(|(i, (&anchor_cp, atrd))| {
+            let (cp_start, cp_end) = match text_cps.get(i..i + 2) {
+                Some(cps) => (cps[0], cps[1]),
+                None => (0, 0),
+            };
+            let range = range_of(atrd.lTagBkmk);
+            // an ATRDPost10 of 18 bytes for every comment, the parent is relative to it
+            let atrd_post_10 = extra.get(i * 18..i * 18 + 18);
+            let u32_at = |offset: usize| {
+                atrd_post_10.map(|atrd| {
+                    u32::from_le_bytes([
+                        atrd[offset],
+                        atrd[offset + 1],
+                        atrd[offset + 2],
+                        atrd[offset + 3],
+                    ])
+                })
+            };
+            let parent = u32_at(10)
+                .map(|diatrd_parent| diatrd_parent as i32)
+                .filter(|&diatrd_parent| diatrd_parent != 0)
+                .and_then(|diatrd_parent| usize::try_from(i as i32 + diatrd_parent).ok())
+                .filter(|&parent| parent < references.rgstruct.len());
+
+            Annotation {
+                author: usize::try_from(atrd.ibst)
+                    .ok()
+                    .and_then(|ibst| owners.get(ibst).cloned()),
+                initials: atrd.xstUsrInitl.clone(),
+                date: u32_at(0).and_then(dttm_to_string),
+                anchor_cp,
+                range_start: range.map(|(start, _)| start),
+                range_end: range.map(|(_, end)| end),
+                commented_text: range
+                    .map(|(start, end)| story_text(main_text, start, end))
+                    .unwrap_or_default(),
+                parent,
+                depth: u32_at(6).unwrap_or_default(),
+                cp_start,
+                cp_end,
+                text: story_text(annotation_text, cp_start, cp_end),
+            }
+        })
+        .collect();
+
+    Ok(annotations)
+}
+
+fn read_bytes<R: Read + Seek>(table_stream: &mut R, (fc, lcb): (i32, u32)) -> io::Result<Vec<u8>> {
+    let mut bytes = vec![0; lcb as usize];
+    if lcb > 0 {
+        table_stream.seek(SeekFrom::Start(fc as u64))?;
+        table_stream.read_exact(&mut bytes)?;
+    }
+    Ok(bytes)
+}
+
+/// The Xsts that fill `lcb` bytes, strings of UTF-16 characters preceded by their count
+fn read_xsts<R: Read + Seek>(table_stream: &mut R, fc_lcb: (i32, u32)) -> io::Result<Vec<String>> {
+    let bytes = read_bytes(table_stream, fc_lcb)?;
+
+    let mut xsts = vec![];
+    let mut position = 0;
+    while position + 2 <= bytes.len() {
+        let cch = u16::from_le_bytes([bytes[position], bytes[position + 1]]) as usize;
+        let units = bytes[position + 2..]
+            .chunks_exact(2)
+            .take(cch)
+            .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+            .collect::<Vec<_>>();
+        xsts.push(String::from_utf16_lossy(&units));
+        position += 2 + cch * 2;
+    }
+    Ok(xsts)
+}
+
+/// The CPs of a PLC that has no data, e.g. the Plcfhdd
+pub fn read_cps<R: Read + Seek>(
+    table_stream: &mut R,
+    (fc, lcb): (i32, u32),
+) -> io::Result<Vec<i32>> {
+    if lcb < 8 {
+        return Ok(vec![]);
+    }
+
+    let bytes = read_bytes(table_stream, (fc, lcb))?;
+    Ok(bytes
+        .chunks_exact(4)
+        .map(|cp| i32::from_le_bytes([cp[0], cp[1], cp[2], cp[3]]))
+        .collect())
+}
+
+/// The text of the CPs `start` to `end` of a subdocument, clamped to the subdocument
+pub fn story_text(text: &TextDoc, start: i32, end: i32) -> String {
+    let len = text.bytes().len();
+    let start = (start.max(0) as usize).min(len);
+    let end = (end.max(0) as usize).min(len);
+    text.bytes()
+        .get(start..end)
+        .map(|bytes| TextDoc::from(bytes).to_fmt_string())
+        .unwrap_or_default()
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_header_stories() {
+        // the separators, one section and the story Word ends the header document with
+        let header_text = TextDoc::from(b"\x03\r\x04\r\rPage\r\r\r\r");
+        let cps = [0, 2, 4, 4, 5, 5, 5, 5, 5, 10, 10, 10, 10, 11];
+
+        let stories = header_stories(&cps, &header_text)
+            .into_iter()
+            .map(|story| (story.name, story.section, story.text))
+            .collect::<Vec<_>>();
+        assert_eq!(stories.len(), 13);
+        assert_eq!(
+            stories[0],
+            (
+                "Footnote separator".to_string(),
+                None,
+                "{0x03}{0x0D}".to_string()
+            )
+        );
+        assert_eq!(
+            stories[3],
+            ("Endnote separator".to_string(), None, "{0x0D}".to_string())
+        );
+        assert_eq!(
+            stories[8],
+            (
+                "Section 1 – even footer".to_string(),
+                Some(1),
+                "Page{0x0D}".to_string()
+            )
+        );
+        assert_eq!(
+            stories[12],
+            (
+                "End of the header document".to_string(),
+                None,
+                "{0x0D}".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_notes() {
+        let main_text = TextDoc::from(b"First\x02 and second* note\r");
+        let note_text = TextDoc::from(b"\x02 One\r* Two\r\r");
+        // an auto-numbered note at CP 5 and one with the custom mark `*` at CP 17
+        let references = PLCF {
+            rgfc: vec![5, 17, 25],
+            rgstruct: vec![FRD { nAuto: 1 }, FRD { nAuto: 0 }],
+        };
+
+        let footnotes = notes(
+            "footnote",
+            &references,
+            &[0, 6, 12, 13],
+            &main_text,
+            &note_text,
+        );
+        let summary = footnotes
+            .iter()
+            .map(|note| {
+                (
+                    note.number,
+                    note.mark.as_str(),
+                    note.context_before.as_str(),
+                    note.text.as_str(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            [
+                (Some(1), "{0x02}", "First", "{0x02}{0x20}One{0x0D}"),
+                (
+                    None,
+                    "*",
+                    "First{0x02}{0x20}and{0x20}second",
+                    "*{0x20}Two{0x0D}"
+                ),
+            ]
+        );
+        assert!(note_diagnostics(&footnotes).is_empty());
+
+        // a custom mark that reads as an auto-numbered reference
+        let references = PLCF {
+            rgfc: vec![5, 25],
+            rgstruct: vec![FRD { nAuto: 0 }],
+        };
+        let endnotes = notes("endnote", &references, &[0, 6, 7], &main_text, &note_text);
+        let diagnostics = note_diagnostics(&endnotes);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, "note_mark_mismatch");
+    }
+
+    #[test]
+    fn test_annotations() {
+        let xst = |text: &str| {
+            let mut xst = (text.len() as u16).to_le_bytes().to_vec();
+            xst.extend(text.encode_utf16().flat_map(u16::to_le_bytes));
+            xst
+        };
+        let atrd = |initials: &str, ibst: i16, l_tag_bkmk: i32| {
+            let mut atrd = xst(initials);
+            atrd.resize(20, 0);
+            atrd.extend(ibst.to_le_bytes());
+            atrd.extend([0; 4]);
+            atrd.extend(l_tag_bkmk.to_le_bytes());
+            atrd
+        };
+        let atrd_post_10 = |dttm: u32, depth: u32, diatrd_parent: i32| {
+            let mut atrd = dttm.to_le_bytes().to_vec();
+            atrd.extend([0; 2]);
+            atrd.extend(depth.to_le_bytes());
+            atrd.extend(diatrd_parent.to_le_bytes());
+            atrd.extend([0; 4]);
+            atrd
+        };
+        let cps = |cps: &[i32]| {
+            cps.iter()
+                .flat_map(|cp| cp.to_le_bytes())
+                .collect::<Vec<_>>()
+        };
+        let mut table_stream = vec![];
+        let mut push = |bytes: Vec<u8>| {
+            let fc_lcb = (table_stream.len() as i32, bytes.len() as u32);
+            table_stream.extend(bytes);
+            fc_lcb
+        };
+
+        // a comment on "Big" and a reply to it without a range
+        let mut plcfand_ref = cps(&[0, 8, 13]);
+        plcfand_ref.extend(atrd("AB", 0, 7));
+        plcfand_ref.extend(atrd("CD", 1, -1));
+        let plcfand_ref = push(plcfand_ref);
+        let plcfand_txt = push(cps(&[0, 5, 10, 11]));
+        let owners = push([xst("Ann Berg"), xst("Carl Dahl")].concat());
+        let mut sttbf_atn_bkmk = vec![0xFF, 0xFF, 1, 0, 10, 0, 0, 0, 0, 0];
+        sttbf_atn_bkmk.extend(7i32.to_le_bytes());
+        sttbf_atn_bkmk.extend([0; 4]);
+        let sttbf_atn_bkmk = push(sttbf_atn_bkmk);
+        let mut plcf_atn_bkf = cps(&[4, 14]);
+        plcf_atn_bkf.extend([0; 4]);
+        let plcf_atn_bkf = push(plcf_atn_bkf);
+        let plcf_atn_bkl = push(cps(&[7, 14]));
+        // 2024-03-05 14:30
+        let dttm = 30 | 14 << 6 | 5 << 11 | 3 << 16 | 124 << 20;
+        let atrd_extra = push([atrd_post_10(dttm, 0, 0), atrd_post_10(0, 1, -1)].concat());
+
+        let fib = Fib {
+            fcPlcfandRef: plcfand_ref.0,
+            lcbPlcfandRef: plcfand_ref.1,
+            fcPlcfandTxt: plcfand_txt.0,
+            lcbPlcfandTxt: plcfand_txt.1,
+            fcGrpXstAtnOwners: owners.0,
+            lcbGrpXstAtnOwners: owners.1,
+            fcSttbfAtnBkmk: sttbf_atn_bkmk.0,
+            lcbSttbfAtnBkmk: sttbf_atn_bkmk.1,
+            fcPlcfAtnbkf: plcf_atn_bkf.0,
+            lcbPlcfAtnbkf: plcf_atn_bkf.1,
+            fcPlcfAtnbkl: plcf_atn_bkl.0,
+            lcbPlcfAtnbkl: plcf_atn_bkl.1,
+            fibRgFcLcb2002: Some(FibRgFcLcb2002 {
+                fcAtrdExtra: atrd_extra.0,
+                lcbAtrdExtra: atrd_extra.1,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let main_text = TextDoc::from(b"The Big\x05 cat\x05\r");
+        let annotation_text = TextDoc::from(b"\x05Why\r\x05Why\r\r");
+
+        let annotations = read_annotations(
+            &mut Cursor::new(table_stream),
+            &fib,
+            &main_text,
+            &annotation_text,
+        )
+        .unwrap();
+        assert_eq!(annotations.len(), 2);
+        assert_eq!(annotations[0].author.as_deref(), Some("Ann Berg"));
+        assert_eq!(annotations[0].initials, "AB");
+        assert_eq!(annotations[0].date.as_deref(), Some("2024-03-05 14:30"));
+        assert_eq!(
+            (annotations[0].range_start, annotations[0].range_end),
+            (Some(4), Some(7))
+        );
+        assert_eq!(annotations[0].commented_text, "Big");
+        assert_eq!(annotations[0].text, "{0x05}Why{0x0D}");
+        assert_eq!(annotations[1].author.as_deref(), Some("Carl Dahl"));
+        assert_eq!(annotations[1].date, None);
+        assert_eq!(annotations[1].range_start, None);
+        assert_eq!((annotations[1].parent, annotations[1].depth), (Some(0), 1));
+    }
+}
@@ -0,0 +1,242 @@
+//! String tables of the table stream, the STTBs that hold bookmark names, the authors of
+//! revisions or the save history along with extra data for every string
+use std::io::{self, Read, Seek, SeekFrom};
+
+use super::{model::*, residual::CP1252_80_9F};
+
+/// What the strings of the SttbfAssoc hold, by index. The first and the last are unused
+const ASSOCIATED_STRINGS: [&str; 18] = [
+    "",
+    "Template",
+    "Title",
+    "Subject",
+    "Keywords",
+    "Comments",
+    "Author",
+    "Last revised by",
+    "Mail merge data source",
+    "Mail merge header source",
+    "Mail merge criteria 1",
+    "Mail merge criteria 2",
+    "Mail merge criteria 3",
+    "Mail merge criteria 4",
+    "Mail merge criteria 5",
+    "Mail merge criteria 6",
+    "Mail merge criteria 7",
+    "",
+];
+
+/// A string of an STTB and the extra data that follows it
+pub type SttbEntry = (String, Vec<u8>);
+
+/// Reads the STTB at `fc` of the table stream, empty if `lcb` is 0. Extended STTBs, which start
+/// with 0xFFFF, hold UTF-16 strings, the others 8-bit strings
+pub fn read_sttb<R: Read + Seek>(
+    table_stream: &mut R,
+    (fc, lcb): (i32, u32),
+) -> io::Result<Vec<SttbEntry>> {
+    if lcb < 4 {
+        return Ok(vec![]);
+    }
+
+    sttb_from_bytes(&read_range(table_stream, (fc, lcb))?)
+}
+
+/// Reads the STTBF of a Word 6.0/95 file at `fc` of the table stream, empty if `lcb` is 0
+pub fn read_sttb6<R: Read + Seek>(
+    table_stream: &mut R,
+    (fc, lcb): (i32, u32),
+) -> io::Result<Vec<String>> {
+    if lcb < 2 {
+        return Ok(vec![]);
+    }
+
+    Ok(sttb6_from_bytes(&read_range(table_stream, (fc, lcb))?))
+}
+
+fn read_range<R: Read + Seek>(table_stream: &mut R, (fc, lcb): (i32, u32)) -> io::Result<Vec<u8>> {
+    let mut bytes = vec![0; lcb as usize];
+    table_stream.seek(SeekFrom::Start(fc as u64))?;
+    table_stream.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Parses the STTB of `bytes`, the strings are cut off at the end of `bytes`
+pub fn sttb_from_bytes(bytes: &[u8]) -> io::Result<Vec<SttbEntry>> {
+    let u16_at = |offset: usize| {
+        bytes
+            .get(offset..offset + 2)
+            .map(|value| u16::from_le_bytes([value[0], value[1]]))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "truncated STTB"))
+    };
+
+    let extended = u16_at(0)? == 0xFFFF;
+    let mut position = if extended { 2 } else { 0 };
+    let count = u16_at(position)? as usize;
+    let cb_extra = u16_at(position + 2)? as usize;
+    position += 4;
+
+    let mut entries = Vec::with_capacity(count);
+    for _ in 0..count {
+        let string = if extended {
+            let cch = u16_at(position)? as usize;
+            let units = bytes
+                .get(position + 2..)
+                .unwrap_or_default()
+                .chunks_exact(2)
+                .take(cch)
+                .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+                .collect::<Vec<_>>();
+            position += 2 + cch * 2;
+            String::from_utf16_lossy(&units)
+        } else {
+            let cch = *bytes.get(position).unwrap_or(&0) as usize;
+            let chars = bytes.get(position + 1..).unwrap_or_default();
+            position += 1 + cch;
+            cp1252_string(chars.iter().take(cch))
+        };
+        let extra_data = bytes
+            .get(position..(position + cb_extra).min(bytes.len()))
+            .unwrap_or_default()
+            .to_vec();
+        position += cb_extra;
+
+        entries.push((string, extra_data));
+    }
+
+    Ok(entries)
+}
+
+/// Parses the STTBF of a Word 6.0/95 file, which starts with its size in bytes instead of the
+/// number of strings and holds no extra data
+pub fn sttb6_from_bytes(bytes: &[u8]) -> Vec<String> {
+    let Some(cb) = bytes.get(0..2) else {
+        return vec![];
+    };
+    let end = (u16::from_le_bytes([cb[0], cb[1]]) as usize).min(bytes.len());
+
+    let mut strings = vec![];
+    let mut position = 2;
+    while position < end {
+        let cch = bytes[position] as usize;
+        let chars = &bytes[(position + 1).min(end)..(position + 1 + cch).min(end)];
+        strings.push(cp1252_string(chars));
+        position += 1 + cch;
+    }
+    strings
+}
+
+/// The 8-bit characters of a non-extended STTB, which Word writes in the ANSI code page
+fn cp1252_string<'a>(bytes: impl IntoIterator<Item = &'a u8>) -> String {
+    bytes
+        .into_iter()
+        .map(|&byte| match byte {
+            0x80..=0x9F => {
+                CP1252_80_9F[byte as usize - 0x80].unwrap_or(char::REPLACEMENT_CHARACTER)
+            }
+            _ => byte as char,
+        })
+        .collect()
+}
+
+/// Reads the authors and paths of the last saves of the SttbSavedBy and the strings of the
+/// SttbfAssoc, the template path among them
+pub fn read_save_history<R: Read + Seek>(
+    table_stream: &mut R,
+    fib: &Fib,
+    word6: bool,
+) -> io::Result<SaveHistory> {
+    let read_strings = |table_stream: &mut R, range: (i32, u32)| -> io::Result<Vec<String>> {
+        if word6 {
+            read_sttb6(table_stream, range)
+        } else {
+            Ok(read_sttb(table_stream, range)?
+                .into_iter()
+                .map(|(string, _)| string)
+                .collect())
+        }
+    };
+    let saved_by = read_strings(table_stream, (fib.fcSttbSavedBy, fib.lcbSttbSavedBy))?;
+    let associated = read_strings(table_stream, (fib.fcSttbfAssoc, fib.lcbSttbfAssoc))?;
+
+    Ok(save_history(&saved_by, &associated))
+}
+
+/// The save history of the strings of the SttbSavedBy, pairs of author and path with the last
+/// save last, and of the SttbfAssoc
+pub fn save_history(saved_by: &[String], associated: &[String]) -> SaveHistory {
+    let template = associated
+        .get(1)
+        .filter(|template| !template.is_empty())
+        .cloned();
+
+    SaveHistory {
+        template,
+        saves: saved_by
+            .chunks(2)
+            .map(|pair| Save {
+                author: pair[0].clone(),
+                path: pair.get(1).cloned().unwrap_or_default(),
+            })
+            .collect(),
+        associated: associated
+            .iter()
+            .zip(ASSOCIATED_STRINGS)
+            .skip(2)
+            .filter(|(value, name)| !value.is_empty() && !name.is_empty())
+            .map(|(value, name)| AssociatedString {
+                name: name.to_string(),
+                value: value.clone(),
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_history() {
+        // a non-extended STTB of two 8-bit strings, 0x80 is the euro sign in CP1252
+        let mut saved_by = vec![2, 0, 0, 0];
+        for string in [&b"Ren\xE9e"[..], &b"C:\\\x80.doc"[..]] {
+            saved_by.push(string.len() as u8);
+            saved_by.extend(string);
+        }
+        let saved_by = sttb_from_bytes(&saved_by)
+            .unwrap()
+            .into_iter()
+            .map(|(string, _)| string)
+            .collect::<Vec<_>>();
+        assert_eq!(saved_by, ["Ren\u{e9}e", "C:\\\u{20ac}.doc"]);
+
+        // a Word 6.0/95 STTBF starts with its size in bytes
+        let mut associated = vec![0, 0];
+        for string in [&b""[..], b"Normal.dot", b"Report"] {
+            associated.push(string.len() as u8);
+            associated.extend(string);
+        }
+        let cb = associated.len() as u16;
+        associated[..2].copy_from_slice(&cb.to_le_bytes());
+        let associated = sttb6_from_bytes(&associated);
+        assert_eq!(associated, ["", "Normal.dot", "Report"]);
+
+        let history = save_history(&saved_by, &associated);
+        assert_eq!(history.template.as_deref(), Some("Normal.dot"));
+        assert_eq!(
+            (
+                history.saves[0].author.as_str(),
+                history.saves[0].path.as_str()
+            ),
+            ("Ren\u{e9}e", "C:\\\u{20ac}.doc")
+        );
+        assert_eq!(
+            (
+                history.associated[0].name.as_str(),
+                history.associated[0].value.as_str()
+            ),
+            ("Title", "Report")
+        );
+    }
+}
@@ -1,20 +1,55 @@
-use json::{object, JsonValue};
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::Value;
 use struct_iterable::Iterable;
 
 use super::model::*;
 
+/// Field names mapped to their descriptions
+pub type Descriptions = HashMap<&'static str, &'static str>;
+
 /// A Trait that states that 'Self' can be made into a `Structure`
 pub trait ToStructure {
     /// A function for annotation name of fields in the structure with their description
-    fn descriptions() -> JsonValue;
-    /// A function which processes the struct and returns a JsonValue of the name and value of the fields
+    fn descriptions() -> Descriptions;
+    /// A function which processes the struct and returns the name and value of the fields
     fn structure_items(&self) -> Vec<StructureItem>;
     /// A function used to make the substructures, if any, of the struct
     fn substructures(&self) -> Option<Vec<Structure>>;
 }
 
+/// Makes the `StructureItem`s of the fields in `field_names`. `item` is serialized once and the
+/// value of each field is looked up by its name
+fn structure_items_of<'a, T: Serialize>(
+    item: &T,
+    field_names: impl IntoIterator<Item = &'a str>,
+    descriptions: &Descriptions,
+) -> Vec<StructureItem> {
+    let item_json = serde_json::to_value(item).unwrap();
+
+    field_names
+        .into_iter()
+        .map(|field_name| StructureItem {
+            name: field_name.to_string(),
+            value: value_to_string(&item_json[field_name]),
+            description: descriptions
+                .get(field_name)
+                .map(|description| description.to_string()),
+        })
+        .collect()
+}
+
+/// Strings are used as they are, any other value as its compact JSON
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::String(string) => string.clone(),
+        value => value.to_string(),
+    }
+}
+
 impl ToStructure for Fib {
-    fn descriptions() -> JsonValue {
+    fn descriptions() -> Descriptions {
         let fields = vec![
             ("wIdent", "Magic Number"),
             (
@@ -288,15 +323,67 @@ impl ToStructure for Fib {
             ("lcbSttbListNames", ""),
             ("fcSttbfUssr", ""),
             ("lcbSttbfUssr", ""),
+            ("cswNew", "The number of entries in rgswNew[]"),
+            ("nFibNew", "The version of the file, replaces nFib when cswNew is set"),
+            ("cQuickSavesNew", "Number of times the document has been quick-saved"),
+            ("lidThemeOther", "Language of the theme fonts for western text"),
+            ("lidThemeFE", "Language of the theme fonts for East Asian text"),
+            ("lidThemeCS", "Language of the theme fonts for complex script text"),
+        ];
+        fields
+            .into_iter()
+            .filter(|(_, description)| !description.is_empty())
+            .collect()
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self
+            .iter()
+            .map(|(field_name, _)| field_name)
+            // Ignoring the FC/LCB blocks of later versions as they are substructures
+            .filter(|field_name| !field_name.starts_with("fibRgFcLcb"));
+
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        let mut substructures = vec![];
+
+        if let Some(block) = &self.fibRgFcLcb2000 {
+            substructures.push(Structure::from("FibRgFcLcb2000", block));
+        }
+        if let Some(block) = &self.fibRgFcLcb2002 {
+            substructures.push(Structure::from("FibRgFcLcb2002", block));
+        }
+        if let Some(block) = &self.fibRgFcLcb2003 {
+            substructures.push(Structure::from("FibRgFcLcb2003", block));
+        }
+        if let Some(block) = &self.fibRgFcLcb2007 {
+            substructures.push(Structure::from("FibRgFcLcb2007", block));
+        }
+
+        Some(substructures)
+    }
+}
+
+impl ToStructure for FibRgFcLcb2000 {
+    fn descriptions() -> Descriptions {
+        let fields = vec![
             ("fcPlcfTch", ""),
             ("lcbPlcfTch", ""),
-            ("fcRmdfThreading", "Table stream offset of revision mark data(unused)"),
+            (
+                "fcRmdfThreading",
+                "Table stream offset of revision mark data(unused)",
+            ),
             ("lcbRmdfThreading", ""),
             ("fcMid", "Table stream offset of Message ID (unused)"),
             ("lcbMid", ""),
             ("fcSttbRgtplc", "Table stream offset of list gallery data"),
             ("lcbSttbRgtplc", ""),
-            ("fcMsoEnvelope", "Table stream offset of persist the mail envelope"),
+            (
+                "fcMsoEnvelope",
+                "Table stream offset of persist the mail envelope",
+            ),
             ("lcbMsoEnvelope", ""),
             ("fcPlcfLad", ""),
             ("lcbPlcfLad", ""),
@@ -309,6 +396,26 @@ impl ToStructure for Fib {
             ("fcPgdMotherOld", ""),
             ("fcpgdFtnOld", ""),
             ("fcpgdEdnOld", ""),
+        ];
+        fields
+            .into_iter()
+            .filter(|(_, description)| !description.is_empty())
+            .collect()
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self.iter().map(|(field_name, _)| field_name);
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        None
+    }
+}
+
+impl ToStructure for FibRgFcLcb2002 {
+    fn descriptions() -> Descriptions {
+        let fields = vec![
             ("fcUnused1", ""),
             ("lcbUnused1", ""),
             ("fcPlcfPgp", ""),
@@ -343,6 +450,8 @@ impl ToStructure for Fib {
             ("lcbSttbfbkmkBPRepairs", ""),
             ("fcPlcfbkfBPRepairs", ""),
             ("lcbPlcfbkfBPRepairs", ""),
+            ("fcPlcfbklBPRepairs", ""),
+            ("lcbPlcfbklBPRepairs", ""),
             ("fcPmsNew", ""),
             ("lcbPmsNew", ""),
             ("fcODSO", ""),
@@ -363,6 +472,26 @@ impl ToStructure for Fib {
             ("lcbPlcflvcNewXP", ""),
             ("fcPlcflvcMixedXP", ""),
             ("lcbPlcflvcMixedXP", ""),
+        ];
+        fields
+            .into_iter()
+            .filter(|(_, description)| !description.is_empty())
+            .collect()
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self.iter().map(|(field_name, _)| field_name);
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        None
+    }
+}
+
+impl ToStructure for FibRgFcLcb2003 {
+    fn descriptions() -> Descriptions {
+        let fields = vec![
             ("fcHplxsdr", "XML Shhema Definition references"),
             ("lcbHplxsdr", ""),
             ("fcSttbfBkmkSdt", ""),
@@ -399,42 +528,127 @@ impl ToStructure for Fib {
             ("lcbPlcflvcNew", ""),
             ("fcPlcflvcNewInline", ""),
             ("lcbPlcflvcNewInline", ""),
-            ("fcpgdMother", ""),
-            ("fcpgdFtn", ""),
-            ("fcpgdEdn", ""),
+            ("fcPgdMother", ""),
+            ("lcbPgdMother", ""),
+            ("fcBkdMother", ""),
+            ("lcbBkdMother", ""),
+            ("fcAfdMother", ""),
+            ("lcbAfdMother", ""),
+            ("fcPgdFtn", ""),
+            ("lcbPgdFtn", ""),
+            ("fcBkdFtn", ""),
+            ("lcbBkdFtn", ""),
+            ("fcAfdFtn", ""),
+            ("lcbAfdFtn", ""),
+            ("fcPgdEdn", ""),
+            ("lcbPgdEdn", ""),
+            ("fcBkdEdn", ""),
+            ("lcbBkdEdn", ""),
+            ("fcAfdEdn", ""),
+            ("lcbAfdEdn", ""),
             ("fcAfd", ""),
             ("lcbAfd", ""),
-            ("cswNew", "The number of entries in rgswNew[]"),
-            ("actualNFib", "The actual nFib"),
-            ("cQuickSavesNew", ""),
         ];
-        let mut return_value = object::Object::with_capacity(fields.len());
-        for (name, description) in fields.into_iter().filter(|(_, descr)| *descr != "") {
-            return_value.insert(name, description.into());
-        }
-        return_value.into()
+        fields
+            .into_iter()
+            .filter(|(_, description)| !description.is_empty())
+            .collect()
     }
 
     fn structure_items(&self) -> Vec<StructureItem> {
-        let descriptions = Self::descriptions();
-        let self_json = json::parse(&serde_json::to_string(&self).unwrap()).unwrap();
-        let mut structure_items = vec![];
-        for (field_name, _) in self.iter() {
-            let field_val = self_json[field_name].to_string();
-            let description = if descriptions.has_key(&field_name) {
-                Some(descriptions[field_name].clone().to_string())
-            } else {
-                None
-            };
+        let field_names = self.iter().map(|(field_name, _)| field_name);
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
 
-            structure_items.push(StructureItem {
-                name: field_name.to_string(),
-                value: field_val,
-                description,
-            });
-        }
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        None
+    }
+}
+
+impl ToStructure for FibRgFcLcb2007 {
+    fn descriptions() -> Descriptions {
+        let fields = vec![
+            ("fcPlcfmthd", "Not used"),
+            ("lcbPlcfmthd", "Not used"),
+            (
+                "fcSttbfBkmkMoveFrom",
+                "Offset in table stream of the STTBF of bookmark names for text moved from",
+            ),
+            ("lcbSttbfBkmkMoveFrom", ""),
+            (
+                "fcPlcfBkfMoveFrom",
+                "Offset in table stream of the PLCF of bookmark starts for text moved from",
+            ),
+            ("lcbPlcfBkfMoveFrom", ""),
+            (
+                "fcPlcfBklMoveFrom",
+                "Offset in table stream of the PLCF of bookmark ends for text moved from",
+            ),
+            ("lcbPlcfBklMoveFrom", ""),
+            (
+                "fcSttbfBkmkMoveTo",
+                "Offset in table stream of the STTBF of bookmark names for text moved to",
+            ),
+            ("lcbSttbfBkmkMoveTo", ""),
+            (
+                "fcPlcfBkfMoveTo",
+                "Offset in table stream of the PLCF of bookmark starts for text moved to",
+            ),
+            ("lcbPlcfBkfMoveTo", ""),
+            (
+                "fcPlcfBklMoveTo",
+                "Offset in table stream of the PLCF of bookmark ends for text moved to",
+            ),
+            ("lcbPlcfBklMoveTo", ""),
+            ("fcUnused1", "Not used"),
+            ("lcbUnused1", "Not used"),
+            ("fcUnused2", "Not used"),
+            ("lcbUnused2", "Not used"),
+            ("fcUnused3", "Not used"),
+            ("lcbUnused3", "Not used"),
+            (
+                "fcSttbfBkmkArto",
+                "Offset in table stream of the STTBF of AutoText bookmark names",
+            ),
+            ("lcbSttbfBkmkArto", ""),
+            (
+                "fcPlcfBkfArto",
+                "Offset in table stream of the PLCF of AutoText bookmark starts",
+            ),
+            ("lcbPlcfBkfArto", ""),
+            (
+                "fcPlcfBklArto",
+                "Offset in table stream of the PLCF of AutoText bookmark ends",
+            ),
+            ("lcbPlcfBklArto", ""),
+            ("fcArtoData", "Not used"),
+            ("lcbArtoData", "Not used"),
+            ("fcUnused4", "Not used"),
+            ("lcbUnused4", "Not used"),
+            ("fcUnused5", "Not used"),
+            ("lcbUnused5", "Not used"),
+            ("fcUnused6", "Not used"),
+            ("lcbUnused6", "Not used"),
+            (
+                "fcOssTheme",
+                "Offset in table stream of the Office theme of the document",
+            ),
+            ("lcbOssTheme", ""),
+            (
+                "fcColorSchemeMapping",
+                "Offset in table stream of the color scheme mapping of the document",
+            ),
+            ("lcbColorSchemeMapping", ""),
+        ];
+        fields
+            .into_iter()
+            .filter(|(_, description)| !description.is_empty())
+            .collect()
+    }
 
-        structure_items
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self.iter().map(|(field_name, _)| field_name);
+        structure_items_of(self, field_names, &Self::descriptions())
     }
 
     fn substructures(&self) -> Option<Vec<Structure>> {
@@ -442,40 +656,74 @@ impl ToStructure for Fib {
     }
 }
 
-impl ToStructure for Text {
-    fn descriptions() -> JsonValue {
-        object! {
-            main_text: "main document",
-            footnote_text: "footnote subdocument",
-            header_text: "header subdocument",
-            annotation_text: "annotion subdocument",
-            endnote_text: "endnote subdocument",
-            textbox_text: "textbox document",
-            header_textbox_text: "header textbox subdocument",
-        }
+impl ToStructure for Fib6 {
+    fn descriptions() -> Descriptions {
+        // most fields mean the same as in the Word 97 FIB, except that fc's point into the
+        // WordDocument stream
+        let mut descriptions = Fib::descriptions();
+        let fields = vec![
+            ("fcSpare0", "Spare file offset"),
+            ("ccpSpare2", "Spare character count"),
+            (
+                "pnChpFirst",
+                "Page number of the lowest PLC leaf of the CHPX FKPs",
+            ),
+            (
+                "pnPapFirst",
+                "Page number of the lowest PLC leaf of the PAPX FKPs",
+            ),
+            ("cpnBteChp", "Count of CHPX FKPs recorded in the file"),
+            ("cpnBtePap", "Count of PAPX FKPs recorded in the file"),
+        ];
+        descriptions.extend(fields);
+        descriptions
     }
 
     fn structure_items(&self) -> Vec<StructureItem> {
-        let descriptions = Self::descriptions();
-        let self_json = json::parse(&serde_json::to_string(&self).unwrap()).unwrap();
-
-        let mut structure_items = vec![];
-        for (field_name, _) in self.iter() {
-            let field_val = self_json[field_name].to_string();
-            let description = if descriptions.has_key(&field_name) {
-                Some(descriptions[field_name].clone().to_string())
-            } else {
-                None
-            };
+        let field_names = self.iter().map(|(field_name, _)| field_name);
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
 
-            structure_items.push(StructureItem {
-                name: field_name.to_string(),
-                value: field_val,
-                description,
-            });
-        }
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        None
+    }
+}
+
+impl ToStructure for Encryption {
+    fn descriptions() -> Descriptions {
+        let fields = vec![
+            ("encryptionType", "How the document is protected"),
+            (
+                "cbHeader",
+                "Size of the EncryptionHeader at the start of the table stream",
+            ),
+            ("vMajor", "Major version of the EncryptionHeader"),
+            ("vMinor", "Minor version of the EncryptionHeader"),
+            ("AlgID", "Encryption algorithm, 0x6801 for RC4"),
+            ("AlgIDHash", "Hashing algorithm, 0x8004 for SHA-1"),
+            ("KeySize", "Key size in bits, 0 means 40 bits"),
+            ("ProviderType", "Type of the cryptographic provider"),
+            ("CSPName", "Name of the cryptographic provider"),
+            ("Salt", "Salt hashed with the password"),
+            (
+                "EncryptedVerifier",
+                "Random bytes encrypted with the key, used to check the password",
+            ),
+            ("EncryptedVerifierHash", "Encrypted hash of the verifier"),
+            (
+                "decrypted",
+                "Whether the document was decrypted with a password before it was decoded",
+            ),
+        ];
+        fields
+            .into_iter()
+            .filter(|(_, description)| !description.is_empty())
+            .collect()
+    }
 
-        structure_items
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self.iter().map(|(field_name, _)| field_name);
+        structure_items_of(self, field_names, &Self::descriptions())
     }
 
     fn substructures(&self) -> Option<Vec<Structure>> {
@@ -483,48 +731,71 @@ impl ToStructure for Text {
     }
 }
 
-impl ToStructure for SHSHI {
-    fn descriptions() -> JsonValue {
-        object! {
-            cbStshi: "Count of bytes of this SHSHI allocation",
-            cstd: "The Number of styles in the stylesheet",
-            cbSTDBaseInFile: "The length of STD Base as stored in file",
-            fStdStylenamesWritten: "Flag indicating whether built-in styles are stored",
-            stiMaxWhenSaved: "Max sti known when this file was written",
-            istdMaxFixedWhenSaved: "The number of fixed-index issd's there are",
-            nVerBuiltInNamesWhenSaved: "Current version of build-in stylenames",
-            rgftcStandardChpStsh: "rgftc used by StandardChpStsh for this document",
-            cbLSD: "count of bytes of each LSD in mpstilsd",
-            mpstilsd: "latent style data(array of LSDs)",
-        }
+impl ToStructure for Text {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            ("main_text", "main document"),
+            ("footnote_text", "footnote subdocument"),
+            ("header_text", "header subdocument"),
+            ("annotation_text", "annotion subdocument"),
+            ("endnote_text", "endnote subdocument"),
+            ("textbox_text", "textbox document"),
+            ("header_textbox_text", "header textbox subdocument"),
+        ])
     }
 
     fn structure_items(&self) -> Vec<StructureItem> {
-        let descriptions = Self::descriptions();
-        let self_json = json::parse(&serde_json::to_string(&self).unwrap()).unwrap();
+        let field_names = self.iter().map(|(field_name, _)| field_name);
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
 
-        let mut structure_items = vec![];
-        for (field_name, _) in self.iter() {
-            // Ignoreing Styles as it is a substructure
-            if field_name == "styles" {
-                continue;
-            }
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        None
+    }
+}
 
-            let field_val = self_json[field_name].to_string();
-            let description = if descriptions.has_key(&field_name) {
-                Some(descriptions[field_name].clone().to_string())
-            } else {
-                None
-            };
+impl ToStructure for SHSHI {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            ("cbStshi", "Count of bytes of this SHSHI allocation"),
+            ("cstd", "The Number of styles in the stylesheet"),
+            (
+                "cbSTDBaseInFile",
+                "The length of STD Base as stored in file",
+            ),
+            (
+                "fStdStylenamesWritten",
+                "Flag indicating whether built-in styles are stored",
+            ),
+            (
+                "stiMaxWhenSaved",
+                "Max sti known when this file was written",
+            ),
+            (
+                "istdMaxFixedWhenSaved",
+                "The number of fixed-index issd's there are",
+            ),
+            (
+                "nVerBuiltInNamesWhenSaved",
+                "Current version of build-in stylenames",
+            ),
+            (
+                "rgftcStandardChpStsh",
+                "rgftc used by StandardChpStsh for this document",
+            ),
+            ("cbLSD", "count of bytes of each LSD in mpstilsd"),
+            ("mpstilsd", "latent style data(array of LSDs)"),
+        ])
+    }
 
-            structure_items.push(StructureItem {
-                name: field_name.to_string(),
-                value: field_val,
-                description,
-            });
-        }
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self
+            .iter()
+            .map(|(field_name, _)| field_name)
+            // Ignoreing Styles as it is a substructure
+            .filter(|field_name| *field_name != "styles");
 
-        structure_items
+        structure_items_of(self, field_names, &Self::descriptions())
     }
 
     fn substructures(&self) -> Option<Vec<Structure>> {
@@ -540,41 +811,30 @@ impl ToStructure for SHSHI {
 }
 
 impl ToStructure for STD {
-    fn descriptions() -> JsonValue {
-        object! {
-            sti: "The style identifier",
-            fScratch: "Spare fields for temporary use, should alwase be zero",
-            fInvalHeight: "Flag indicating PHEs of all text with this styel are wrong",
-            fHasUpe: "Flag indicating that UPEs have been generated",
-            fMassCopy: "Flag indicating that std has been mas-copied",
-            stk: "Style Kind",
-            istdBase: "Base Style identifier",
-            cupx: "Number of UPSx (and UPEs)",
-            istdNext: "Next Style identifier",
-            bchUpe: "Offset to end of upx's, start of upe's"
-        }
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            ("sti", "The style identifier"),
+            (
+                "fScratch",
+                "Spare fields for temporary use, should alwase be zero",
+            ),
+            (
+                "fInvalHeight",
+                "Flag indicating PHEs of all text with this styel are wrong",
+            ),
+            ("fHasUpe", "Flag indicating that UPEs have been generated"),
+            ("fMassCopy", "Flag indicating that std has been mas-copied"),
+            ("stk", "Style Kind"),
+            ("istdBase", "Base Style identifier"),
+            ("cupx", "Number of UPSx (and UPEs)"),
+            ("istdNext", "Next Style identifier"),
+            ("bchUpe", "Offset to end of upx's, start of upe's"),
+        ])
     }
 
     fn structure_items(&self) -> Vec<StructureItem> {
-        let descriptions = Self::descriptions();
-        let self_json = json::parse(&serde_json::to_string(&self).unwrap()).unwrap();
-        let mut structure_items = vec![];
-        for (field_name, _) in self.iter() {
-            let field_val = self_json[field_name].to_string();
-            let description = if descriptions.has_key(&field_name) {
-                Some(descriptions[field_name].clone().to_string())
-            } else {
-                None
-            };
-
-            structure_items.push(StructureItem {
-                name: field_name.to_string(),
-                value: field_val,
-                description,
-            });
-        }
-
-        structure_items
+        let field_names = self.iter().map(|(field_name, _)| field_name);
+        structure_items_of(self, field_names, &Self::descriptions())
     }
 
     fn substructures(&self) -> Option<Vec<Structure>> {
@@ -583,30 +843,13 @@ impl ToStructure for STD {
 }
 
 impl ToStructure for DocumentSummaryInfoStream {
-    fn descriptions() -> JsonValue {
-        object! {}
+    fn descriptions() -> Descriptions {
+        Descriptions::new()
     }
 
     fn structure_items(&self) -> Vec<StructureItem> {
-        let descriptions = Self::descriptions();
-        let self_json = json::parse(&serde_json::to_string(&self).unwrap()).unwrap();
-        let mut structure_items = vec![];
-        for (field_name, _) in self.iter() {
-            let field_val = self_json[field_name].to_string();
-            let description = if descriptions.has_key(&field_name) {
-                Some(descriptions[field_name].clone().to_string())
-            } else {
-                None
-            };
-
-            structure_items.push(StructureItem {
-                name: field_name.to_string(),
-                value: field_val,
-                description,
-            });
-        }
-
-        structure_items
+        let field_names = self.iter().map(|(field_name, _)| field_name);
+        structure_items_of(self, field_names, &Self::descriptions())
     }
 
     fn substructures(&self) -> Option<Vec<Structure>> {
@@ -615,35 +858,19 @@ impl ToStructure for DocumentSummaryInfoStream {
 }
 
 impl ToStructure for SummaryInformation {
-    fn descriptions() -> JsonValue {
-        object! {}
+    fn descriptions() -> Descriptions {
+        Descriptions::new()
     }
 
     fn structure_items(&self) -> Vec<StructureItem> {
-        let descriptions = Self::descriptions();
-        let self_json = json::parse(&serde_json::to_string(&self).unwrap()).unwrap();
-        let mut structure_items = vec![];
-
-        for (field_name, _) in self.propertysetStreamHeader.iter().chain(self.iter()) {
-            if field_name == "propertysetStreamHeader" {
-                continue;
-            }
+        let field_names = self
+            .propertysetStreamHeader
+            .iter()
+            .chain(self.iter())
+            .map(|(field_name, _)| field_name)
+            .filter(|field_name| *field_name != "propertysetStreamHeader");
 
-            let field_val = self_json[field_name].to_string();
-            let description = if descriptions.has_key(&field_name) {
-                Some(descriptions[field_name].clone().to_string())
-            } else {
-                None
-            };
-
-            structure_items.push(StructureItem {
-                name: field_name.to_string(),
-                value: field_val,
-                description,
-            });
-        }
-
-        structure_items
+        structure_items_of(self, field_names, &Self::descriptions())
     }
 
     fn substructures(&self) -> Option<Vec<Structure>> {
@@ -652,8 +879,8 @@ impl ToStructure for SummaryInformation {
 }
 
 impl ToStructure for PLCF<PCD> {
-    fn descriptions() -> JsonValue {
-        object! {}
+    fn descriptions() -> Descriptions {
+        Descriptions::new()
     }
 
     fn structure_items(&self) -> Vec<StructureItem> {
@@ -673,31 +900,1095 @@ impl ToStructure for PLCF<PCD> {
 }
 
 impl ToStructure for PCD {
-    fn descriptions() -> JsonValue {
-        object! {}
+    fn descriptions() -> Descriptions {
+        Descriptions::new()
     }
 
     fn structure_items(&self) -> Vec<StructureItem> {
-        let descriptions = Self::descriptions();
-        let self_json = json::parse(&serde_json::to_string(&self).unwrap()).unwrap();
-        let mut structure_items = vec![];
-
-        for (field_name, _) in self.iter() {
-            let field_val = self_json[field_name].to_string();
-            let description = if descriptions.has_key(&field_name) {
-                Some(descriptions[field_name].clone().to_string())
-            } else {
-                None
-            };
+        let field_names = self.iter().map(|(field_name, _)| field_name);
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        None
+    }
+}
+
+impl ToStructure for Vec<Picture> {
+    fn descriptions() -> Descriptions {
+        Descriptions::new()
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        vec![]
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        let mut substructures = vec![];
+
+        for (i, picture) in self.iter().enumerate() {
+            let picture_structure = Structure::from(&format!("Picture {}", i), picture);
+            substructures.push(picture_structure);
+        }
+
+        Some(substructures)
+    }
+}
+
+impl ToStructure for Picture {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            (
+                "source",
+                "Data for the Data stream, BStore for the BLIP store",
+            ),
+            ("stream_name", "Stream the picture is stored in"),
+            (
+                "offset",
+                "Offset of the PICF or the BLIP record in the stream",
+            ),
+            ("len", "Length of the PICF or the BLIP record in the stream"),
+            ("blip_type", "Format of the picture"),
+            ("size", "Size of the picture in bytes, after decompression"),
+            ("sha256", "SHA-256 of the picture"),
+        ])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self
+            .iter()
+            .map(|(field_name, _)| field_name)
+            // Ignoring the PICF and the FBSE as they are substructures
+            .filter(|field_name| !["data", "picf", "fbse"].contains(field_name));
+
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        let mut substructures = vec![];
+
+        if let Some(picf) = &self.picf {
+            substructures.push(Structure::from("PICF", picf));
+        }
+        if let Some(fbse) = &self.fbse {
+            substructures.push(Structure::from("OfficeArtFBSE", fbse));
+        }
+
+        Some(substructures)
+    }
+}
+
+impl ToStructure for PICF {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            (
+                "lcb",
+                "Size of the PICF and the picture data that follows it",
+            ),
+            ("cbHeader", "Size of the PICF"),
+            (
+                "mm",
+                "Storage format, 0x64 for OfficeArt and 0x66 for a linked OfficeArt picture",
+            ),
+            ("xExt", "Width of the metafile"),
+            ("yExt", "Height of the metafile"),
+            ("swHMF", "Handle to the metafile, not used"),
+            ("dxaGoal", "Width of the picture in twips, before cropping"),
+            ("dyaGoal", "Height of the picture in twips, before cropping"),
+            ("mx", "Horizontal scaling in tenths of a percent"),
+            ("my", "Vertical scaling in tenths of a percent"),
+            ("bpp", "Bits per pixel of a bitmap"),
+            ("cProps", "Must be zero"),
+            ("stPicName", "File name of a linked picture"),
+        ])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self.iter().map(|(field_name, _)| field_name);
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
 
-            structure_items.push(StructureItem {
-                name: field_name.to_string(),
-                value: field_val,
-                description,
-            });
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        None
+    }
+}
+
+impl ToStructure for OfficeArtFBSE {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            ("btWin32", "BLIP type on Windows"),
+            ("btMacOS", "BLIP type on the Macintosh"),
+            ("rgbUid", "MD4 digest of the picture data"),
+            ("tag", "Unused"),
+            ("size", "Size of the BLIP in the delay stream"),
+            ("cRef", "Number of shapes that use the BLIP"),
+            ("foDelay", "Offset of the BLIP in the WordDocument stream"),
+            ("cbName", "Length of nameData in bytes"),
+            ("nameData", "Name of the BLIP"),
+        ])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self.iter().map(|(field_name, _)| field_name);
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        None
+    }
+}
+
+impl ToStructure for OfficeArtContent {
+    fn descriptions() -> Descriptions {
+        Descriptions::new()
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        vec![]
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        let mut substructures = vec![Structure::from(
+            self.dgg_container.header.record_name(),
+            &self.dgg_container,
+        )];
+
+        for drawing in &self.drawings {
+            let name = match drawing.dgglbl {
+                0 => "OfficeArtDgContainer (main document)".to_string(),
+                1 => "OfficeArtDgContainer (headers)".to_string(),
+                dgglbl => format!("OfficeArtDgContainer ({dgglbl})"),
+            };
+            substructures.push(Structure::from(&name, &drawing.container));
         }
 
-        structure_items
+        Some(substructures)
+    }
+}
+
+impl ToStructure for OfficeArtRecord {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            ("offset", "Offset of the record in the table stream"),
+            ("recVer", "Version of the record, 0xF for containers"),
+            (
+                "recInstance",
+                "Depends on the record type, e.g. the number of properties",
+            ),
+            ("recType", "Type of the record"),
+            ("recLen", "Length of the record after its header"),
+            ("spid", "Shape identifier"),
+            (
+                "grfPersistent",
+                "Flags of the shape, e.g. fGroup, fDeleted and fHaveAnchor",
+            ),
+            (
+                "clientTextbox",
+                "The high 16 bits are the 1-based index of the text box",
+            ),
+        ])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let descriptions = Self::descriptions();
+        let header = &self.header;
+        let values = [
+            ("offset", Some(format!("{:#X}", self.offset))),
+            ("recVer", Some(format!("{:#X}", header.recVer))),
+            ("recInstance", Some(format!("{:#X}", header.recInstance))),
+            ("recType", Some(format!("{:#06X}", header.recType))),
+            ("recLen", Some(header.recLen.to_string())),
+            ("spid", self.spid.map(|spid| spid.to_string())),
+            (
+                "grfPersistent",
+                self.grfPersistent.map(|flags| format!("{flags:#010X}")),
+            ),
+            (
+                "clientTextbox",
+                self.clientTextbox.map(|value| format!("{value:#010X}")),
+            ),
+        ];
+
+        values
+            .into_iter()
+            .filter_map(|(name, value)| {
+                Some(StructureItem {
+                    name: name.to_string(),
+                    value: value?,
+                    description: descriptions
+                        .get(name)
+                        .map(|description| description.to_string()),
+                })
+            })
+            .collect()
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        let mut substructures = vec![];
+
+        for child in &self.children {
+            substructures.push(Structure::from(child.header.record_name(), child));
+        }
+        for property in &self.properties {
+            let name = match office_art_property_name(property.pid) {
+                Some(name) => format!("{name} ({:#06X})", property.pid),
+                None => format!("Property {:#06X}", property.pid),
+            };
+            substructures.push(Structure::from(&name, property));
+        }
+
+        Some(substructures)
+    }
+}
+
+impl ToStructure for OfficeArtFOPTE {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            ("pid", "Property identifier"),
+            ("fBid", "The value is a BLIP identifier"),
+            ("fComplex", "The value is the length of the complex data"),
+            ("op", "Value of the property"),
+            (
+                "complexData",
+                "Complex data of the property, e.g. a string or an array",
+            ),
+        ])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self.iter().map(|(field_name, _)| field_name);
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        None
+    }
+}
+
+impl ToStructure for Vec<Shape> {
+    fn descriptions() -> Descriptions {
+        Descriptions::new()
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        vec![]
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        let mut substructures = vec![];
+
+        for (i, shape) in self.iter().enumerate() {
+            let shape_structure = Structure::from(&format!("Shape {}", i), shape);
+            substructures.push(shape_structure);
+        }
+
+        Some(substructures)
+    }
+}
+
+impl ToStructure for Shape {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            (
+                "story",
+                "main for the main document, header for the headers",
+            ),
+            ("cp", "Character position the shape is anchored to"),
+            ("textbox_text", "Text of the text box of the shape"),
+        ])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = ["story", "cp", "textbox_text"];
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        Some(vec![Structure::from("FSPA", &self.fspa)])
+    }
+}
+
+impl ToStructure for FSPA {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            ("spid", "Shape identifier"),
+            ("xaLeft", "Left of the shape in twips"),
+            ("yaTop", "Top of the shape in twips"),
+            ("xaRight", "Right of the shape in twips"),
+            ("yaBottom", "Bottom of the shape in twips"),
+            ("fHdr", "The shape is in a header"),
+            (
+                "bx",
+                "Horizontal position relative to the margin, page or column",
+            ),
+            (
+                "by",
+                "Vertical position relative to the margin, page or paragraph",
+            ),
+            ("wr", "Text wrapping"),
+            ("wrk", "Sides the text wraps around"),
+            ("fRcaSimple", "Must be zero"),
+            ("fBelowText", "The shape is behind the text"),
+            ("fAnchorLock", "The anchor is locked"),
+            ("cTxbx", "Must be zero"),
+        ])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self.iter().map(|(field_name, _)| field_name);
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        None
+    }
+}
+
+impl ToStructure for Vec<EmbeddedObject> {
+    fn descriptions() -> Descriptions {
+        Descriptions::new()
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        vec![]
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        let mut substructures = vec![];
+
+        for object in self {
+            let object_structure = Structure::from(&object.storage_name, object);
+            substructures.push(object_structure);
+        }
+
+        Some(substructures)
+    }
+}
+
+impl ToStructure for EmbeddedObject {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            ("storage_path", "Storage of the object in the compound file"),
+            ("clsid", "CLSID of the storage"),
+            ("user_type", "Display name of the object type"),
+            ("clipboard_format", "Clipboard format of the object"),
+            ("prog_id", "Programmatic identifier of the object type"),
+            (
+                "linked",
+                "The object is linked to a file instead of embedded",
+            ),
+            (
+                "odt",
+                "ObjInfo flags, 0x8 fLink, 0x20 fIcon, 0x40 fIsOle1 and 0x800 fOCX",
+            ),
+            ("package_label", "Label of an OLE 1.0 Package"),
+            (
+                "package_path",
+                "Original path of the file of an OLE 1.0 Package",
+            ),
+            ("payload_source", "Stream the payload was extracted from"),
+            ("payload_size", "Size of the payload in bytes"),
+            ("payload_sha256", "SHA-256 of the payload"),
+            (
+                "cp",
+                "Character position of the object in the main document",
+            ),
+            ("field_code", "Instructions of the field of the object"),
+        ])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self
+            .iter()
+            .map(|(field_name, _)| field_name)
+            // Ignoring the streams as they are a substructure
+            .filter(|field_name| !["storage_name", "streams", "payload"].contains(field_name));
+
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        let streams = self
+            .streams
+            .iter()
+            .map(|(name, len)| StructureItem {
+                name: name.escape_default().to_string(),
+                value: len.to_string(),
+                description: None,
+            })
+            .collect();
+
+        Some(vec![Structure {
+            name: "Streams".to_string(),
+            structure: streams,
+            substructs: None,
+        }])
+    }
+}
+
+impl ToStructure for VbaProject {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            (
+                "storage_path",
+                "Storage of the VBA project in the compound file",
+            ),
+            ("name", "Name of the project"),
+            ("doc_string", "Description of the project"),
+            (
+                "sys_kind",
+                "Platform the project was created on, 1 for 32-bit Windows",
+            ),
+            ("code_page", "Code page of the strings and the source code"),
+            ("version", "Version of the project"),
+            ("constants", "Conditional compilation constants"),
+            ("references", "Referenced libraries and projects"),
+        ])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self
+            .iter()
+            .map(|(field_name, _)| field_name)
+            // Ignoring the modules as they are substructures
+            .filter(|field_name| *field_name != "modules");
+
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        let mut substructures = vec![];
+
+        for module in &self.modules {
+            let module_structure = Structure::from(&module.name, module);
+            substructures.push(module_structure);
+        }
+
+        Some(substructures)
+    }
+}
+
+impl ToStructure for VbaModule {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            ("stream_name", "Stream of the module in the VBA storage"),
+            (
+                "module_type",
+                "procedural or document, class and form modules",
+            ),
+            (
+                "text_offset",
+                "Offset of the compressed source code in the stream",
+            ),
+            ("read_only", "The module is read-only"),
+            ("private", "The module is only visible to its project"),
+            (
+                "auto_exec",
+                "Procedures that run when the document is opened or closed",
+            ),
+            (
+                "source_size",
+                "Size of the decompressed source code in bytes",
+            ),
+            ("source_sha256", "SHA-256 of the decompressed source code"),
+        ])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self
+            .iter()
+            .map(|(field_name, _)| field_name)
+            .filter(|field_name| !["name", "source"].contains(field_name));
+
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        None
+    }
+}
+
+impl ToStructure for Vec<DirectoryEntry> {
+    fn descriptions() -> Descriptions {
+        Descriptions::new()
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        vec![]
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        let mut substructures = vec![];
+
+        for entry in self {
+            // entries that are not linked into the tree have no path
+            let name = match &entry.path {
+                Some(path) => path.clone(),
+                None => format!("Entry {}", entry.sid),
+            };
+            let entry_structure = Structure::from(&name, entry);
+            substructures.push(entry_structure);
+        }
+
+        Some(substructures)
+    }
+}
+
+impl ToStructure for DirectoryEntry {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            (
+                "sid",
+                "Stream identifier, the index of the entry in the directory",
+            ),
+            ("name", "Name of the entry"),
+            (
+                "path",
+                "Path from the root storage, null if no storage links to the entry",
+            ),
+            ("object_type", "root, storage, stream or unallocated"),
+            (
+                "color",
+                "Colour of the entry in the red-black tree of its storage",
+            ),
+            ("left_sibling", "Stream identifier of the left sibling"),
+            ("right_sibling", "Stream identifier of the right sibling"),
+            (
+                "child",
+                "Stream identifier of the root of the tree of the entries of a storage",
+            ),
+            ("clsid", "CLSID of a storage"),
+            ("state_bits", "User-defined flags of a storage"),
+            ("creation_time", "FILETIME of the creation of a storage"),
+            (
+                "modified_time",
+                "FILETIME of the last modification of a storage",
+            ),
+            (
+                "starting_sector",
+                "First sector of the stream, in the mini stream below the cutoff",
+            ),
+            ("size", "Size of the stream in bytes"),
+        ])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self.iter().map(|(field_name, _)| field_name);
+
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        None
+    }
+}
+
+impl ToStructure for Vec<ResidualText> {
+    fn descriptions() -> Descriptions {
+        Descriptions::new()
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        vec![]
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        let mut substructures = vec![];
+
+        for (i, run) in self.iter().enumerate() {
+            let run_structure = Structure::from(&format!("Run {i}"), run);
+            substructures.push(run_structure);
+        }
+
+        Some(substructures)
+    }
+}
+
+impl ToStructure for ResidualText {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            ("stream_name", "Stream the run was found in"),
+            (
+                "source",
+                "unreferenced bytes of the WordDocument stream or the container structure",
+            ),
+            ("offset", "Offset of the run in the stream"),
+            ("len", "Length of the run in bytes"),
+            ("encoding", "cp1252 or utf-16le"),
+            ("text", "The recovered text"),
+        ])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self.iter().map(|(field_name, _)| field_name);
+
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        None
+    }
+}
+
+impl ToStructure for Vec<HeaderStory> {
+    fn descriptions() -> Descriptions {
+        Descriptions::new()
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        vec![]
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        let mut substructures = vec![];
+
+        for story in self {
+            let story_structure = Structure::from(&story.name, story);
+            substructures.push(story_structure);
+        }
+
+        Some(substructures)
+    }
+}
+
+impl ToStructure for HeaderStory {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            ("section", "Section of the header or footer, from 1"),
+            (
+                "kind",
+                "Separator of the notes or header or footer of the section",
+            ),
+            ("cp_start", "First CP of the story in the header document"),
+            (
+                "cp_end",
+                "CP past the end of the story, an empty story is inherited from the section before",
+            ),
+            ("text", "Text of the story"),
+        ])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self
+            .iter()
+            .map(|(field_name, _)| field_name)
+            .filter(|field_name| *field_name != "name");
+
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        None
+    }
+}
+
+impl ToStructure for Vec<Note> {
+    fn descriptions() -> Descriptions {
+        Descriptions::new()
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        vec![]
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        let mut substructures = vec![];
+
+        for (kind, label) in [("footnote", "Footnote"), ("endnote", "Endnote")] {
+            let notes = self.iter().filter(|note| note.kind == kind);
+            for (i, note) in notes.enumerate() {
+                let note_structure = Structure::from(&format!("{label} {}", i + 1), note);
+                substructures.push(note_structure);
+            }
+        }
+
+        Some(substructures)
+    }
+}
+
+impl ToStructure for Note {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            (
+                "auto_numbered",
+                "Numbered by Word rather than by a custom mark",
+            ),
+            ("number", "Ordinal among the auto-numbered notes"),
+            ("anchor_cp", "CP of the reference in the main document"),
+            ("mark", "Character at the reference, 0x02 if auto-numbered"),
+            ("context_before", "Main text before the reference"),
+            ("context_after", "Main text after the reference"),
+            ("cp_start", "First CP of the note in its note document"),
+            ("cp_end", "CP past the end of the note"),
+            ("text", "Text of the note"),
+        ])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self
+            .iter()
+            .map(|(field_name, _)| field_name)
+            .filter(|field_name| *field_name != "kind");
+
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        None
+    }
+}
+
+impl ToStructure for Vec<Annotation> {
+    fn descriptions() -> Descriptions {
+        Descriptions::new()
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        vec![]
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        let mut substructures = vec![];
+
+        for (i, annotation) in self.iter().enumerate() {
+            let annotation_structure = Structure::from(&format!("Comment {i}"), annotation);
+            substructures.push(annotation_structure);
+        }
+
+        Some(substructures)
+    }
+}
+
+impl ToStructure for Annotation {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            ("author", "Author from the GrpXstAtnOwners"),
+            ("initials", "Initials of the author"),
+            (
+                "date",
+                "Date of the comment, written by Word 2002 and later",
+            ),
+            ("anchor_cp", "CP of the reference in the main document"),
+            ("range_start", "First CP of the commented range"),
+            ("range_end", "CP past the end of the commented range"),
+            ("commented_text", "Main text of the commented range"),
+            ("parent", "Index of the comment this one replies to"),
+            ("depth", "Depth of the comment in its thread"),
+            (
+                "cp_start",
+                "First CP of the comment in the annotation document",
+            ),
+            ("cp_end", "CP past the end of the comment"),
+            ("text", "Text of the comment"),
+        ])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self.iter().map(|(field_name, _)| field_name);
+
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        None
+    }
+}
+
+impl ToStructure for Vec<Bookmark> {
+    fn descriptions() -> Descriptions {
+        Descriptions::new()
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        vec![]
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        let mut substructures = vec![];
+
+        for bookmark in self {
+            let bookmark_structure = Structure::from(&bookmark.name, bookmark);
+            substructures.push(bookmark_structure);
+        }
+
+        Some(substructures)
+    }
+}
+
+impl ToStructure for Bookmark {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            ("hidden", "Name starts with _, hidden in Word"),
+            ("hidden_kind", "What Word adds the hidden bookmark for"),
+            ("start_cp", "First CP of the bookmark"),
+            ("end_cp", "CP past the end of the bookmark"),
+            ("column_first", "First column of a table bookmark"),
+            ("column_lim", "Column past the last column of the bookmark"),
+            ("text", "Main text of the bookmark"),
+        ])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self
+            .iter()
+            .map(|(field_name, _)| field_name)
+            .filter(|field_name| *field_name != "name");
+
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        None
+    }
+}
+
+impl ToStructure for Vec<Field> {
+    fn descriptions() -> Descriptions {
+        Descriptions::new()
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        vec![]
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        let mut substructures = vec![];
+
+        for (i, field) in self.iter().enumerate() {
+            let name = format!("Field {i} ({})", field.story);
+            substructures.push(Structure::from(&name, field));
+        }
+
+        Some(substructures)
+    }
+}
+
+impl ToStructure for Field {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            ("flt", "Field type of the field begin"),
+            ("field_type", "Name of the field type"),
+            ("begin_cp", "CP of the field begin in the subdocument"),
+            ("separator_cp", "CP of the field separator"),
+            ("end_cp", "CP of the field end"),
+            ("depth", "Number of fields the field is nested in"),
+            ("locked", "Locked against updates"),
+            ("result_dirty", "Result formatted since the last update"),
+            ("result_edited", "Result edited since the last update"),
+            ("instruction", "Field code"),
+            ("result", "Text of the result"),
+        ])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self
+            .iter()
+            .map(|(field_name, _)| field_name)
+            .filter(|field_name| !["story", "children"].contains(field_name));
+
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        let mut substructures = vec![];
+
+        for (i, field) in self.children.iter().enumerate() {
+            substructures.push(Structure::from(&format!("Field {i}"), field));
+        }
+
+        Some(substructures)
+    }
+}
+
+impl ToStructure for Revisions {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            ("track_changes", "fRevMarking, changes are tracked"),
+            ("show_markup", "fRMView, revisions are shown on screen"),
+            ("print_markup", "fRMPrint, revisions are printed"),
+            ("lock_revisions", "fLockRev, tracking is locked"),
+            ("authors", "Authors of the SttbfRMark"),
+        ])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let mut items = self.settings.structure_items();
+        items.extend(structure_items_of(self, ["authors"], &Self::descriptions()));
+        items
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        let mut substructures = vec![];
+
+        for (i, revision) in self.revisions.iter().enumerate() {
+            let revision_structure = Structure::from(&format!("Revision {i}"), revision);
+            substructures.push(revision_structure);
+        }
+
+        Some(substructures)
+    }
+}
+
+impl ToStructure for RevisionSettings {
+    fn descriptions() -> Descriptions {
+        Revisions::descriptions()
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self.iter().map(|(field_name, _)| field_name);
+
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        None
+    }
+}
+
+impl ToStructure for Revision {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            ("kind", "Insertion, deletion or change of properties"),
+            ("author", "Author from the SttbfRMark"),
+            ("date", "Date of the revision"),
+            ("cp_start", "First CP of the revision"),
+            ("cp_end", "CP past the end of the revision"),
+            ("text", "Text of the revision"),
+        ])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self.iter().map(|(field_name, _)| field_name);
+
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        None
+    }
+}
+
+impl ToStructure for Rsids {
+    fn descriptions() -> Descriptions {
+        Descriptions::new()
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        vec![]
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        let mut substructures = vec![];
+
+        for session in &self.table {
+            let session_structure = Structure::from(&format!("Session {}", session.rsid), session);
+            substructures.push(session_structure);
+        }
+        for (i, run) in self.runs.iter().enumerate() {
+            let run_structure = Structure::from(&format!("Run {i}"), run);
+            substructures.push(run_structure);
+        }
+
+        Some(substructures)
+    }
+}
+
+impl ToStructure for RsidSession {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            ("runs", "Runs changed in the session"),
+            ("chars", "CPs of those runs"),
+            ("styles", "Styles changed in the session"),
+        ])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self
+            .iter()
+            .map(|(field_name, _)| field_name)
+            .filter(|&field_name| field_name != "rsid");
+
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        None
+    }
+}
+
+impl ToStructure for RsidRun {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            ("kind", "Text, properties, deletion or paragraph"),
+            ("rsid", "Session of the change"),
+            ("in_table", "The Plrsid lists the rsid"),
+            ("cp_start", "First CP of the run"),
+            ("cp_end", "CP past the end of the run"),
+        ])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self.iter().map(|(field_name, _)| field_name);
+
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        None
+    }
+}
+
+impl ToStructure for SaveHistory {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([("template", "ibstAssocDot, path of the attached template")])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        structure_items_of(self, ["template"], &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        let mut substructures = vec![];
+
+        for (i, save) in self.saves.iter().enumerate() {
+            let save_structure = Structure::from(&format!("Save {i}"), save);
+            substructures.push(save_structure);
+        }
+        for associated in &self.associated {
+            let associated_structure = Structure::from(&associated.name, associated);
+            substructures.push(associated_structure);
+        }
+
+        Some(substructures)
+    }
+}
+
+impl ToStructure for Save {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([
+            ("author", "Author who saved the document"),
+            ("path", "Path it was saved to"),
+        ])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        let field_names = self.iter().map(|(field_name, _)| field_name);
+
+        structure_items_of(self, field_names, &Self::descriptions())
+    }
+
+    fn substructures(&self) -> Option<Vec<Structure>> {
+        None
+    }
+}
+
+impl ToStructure for AssociatedString {
+    fn descriptions() -> Descriptions {
+        Descriptions::from([("value", "String of the SttbfAssoc")])
+    }
+
+    fn structure_items(&self) -> Vec<StructureItem> {
+        structure_items_of(self, ["value"], &Self::descriptions())
     }
 
     fn substructures(&self) -> Option<Vec<Structure>> {
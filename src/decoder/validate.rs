@@ -0,0 +1,211 @@
+use super::model::{ByteRange, Diagnostic, Fib, Severity};
+
+/// nFib of Word 97, the FIB of every later version starts with the Word 97 FIB
+const NFIB_WORD_97: u16 = 0x00C1;
+
+/// The lcb's of the fc/lcb pairs that are documented as no longer used by Word 97 and later, by
+/// the name of their FIB field, these should be zero
+fn legacy_pairs(fib: &Fib) -> Vec<(&'static str, u32)> {
+    let mut pairs = vec![
+        ("lcbPlcpad", fib.lcbPlcpad),
+        ("lcbPlcfsea", fib.lcbPlcfsea),
+        ("lcbPlcffldMcr", fib.lcbPlcffldMcr),
+        ("lcbPlcmcr", fib.lcbPlcmcr),
+        ("lcbSttbfmcr", fib.lcbSttbfmcr),
+        ("lcbPlcfpgdFtn", fib.lcbPlcfpgdFtn),
+        ("lcbPlcdoaMom", fib.lcbPlcdoaMom),
+        ("lcbPlcdoaHdr", fib.lcbPlcdoaHdr),
+        ("lcbPlcfpgdEdn", fib.lcbPlcfpgdEdn),
+    ];
+    if let Some(fib_2000) = &fib.fibRgFcLcb2000 {
+        pairs.push(("lcbRmdfThreading", fib_2000.lcbRmdfThreading));
+    }
+    if let Some(fib_2002) = &fib.fibRgFcLcb2002 {
+        pairs.push(("lcbUnused1", fib_2002.lcbUnused1));
+    }
+    if let Some(fib_2007) = &fib.fibRgFcLcb2007 {
+        pairs.extend([
+            ("lcbPlcfmthd", fib_2007.lcbPlcfmthd),
+            ("lcbUnused1", fib_2007.lcbUnused1),
+            ("lcbUnused2", fib_2007.lcbUnused2),
+            ("lcbUnused3", fib_2007.lcbUnused3),
+            ("lcbArtoData", fib_2007.lcbArtoData),
+            ("lcbUnused4", fib_2007.lcbUnused4),
+            ("lcbUnused5", fib_2007.lcbUnused5),
+            ("lcbUnused6", fib_2007.lcbUnused6),
+        ]);
+    }
+    pairs
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, code: &str, message: String) -> Self {
+        Diagnostic {
+            severity,
+            code: code.to_string(),
+            message,
+            stream_name: None,
+            range: None,
+        }
+    }
+
+    pub fn range(mut self, stream_name: &str, start: u64, end: u64) -> Self {
+        self.stream_name = Some(stream_name.to_string());
+        self.range = Some(ByteRange { start, end });
+        self
+    }
+}
+
+/// Checks the fc/lcb pairs of the FIB against the length of the table stream and each other
+pub fn validate_fc_lcb_pairs(
+    pairs: &[(i32, u32, String)],
+    table_stream_name: &str,
+    table_stream_len: u64,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    // pairs that point at data within the stream, checked for overlaps below
+    let mut regions = vec![];
+
+    for (index, (fc, lcb, description)) in pairs.iter().enumerate() {
+        let (fc, lcb) = (*fc, *lcb);
+        let pair_name = format!("fc/lcb pair {index} ({description})");
+
+        if lcb == 0 {
+            if fc != 0 {
+                diagnostics.push(Diagnostic::new(
+                    Severity::Info,
+                    "fc_without_lcb",
+                    format!("{pair_name} has fc {fc:#X} but a zero lcb"),
+                ));
+            }
+            continue;
+        }
+
+        if fc < 0 {
+            diagnostics.push(Diagnostic::new(
+                Severity::Error,
+                "fc_lcb_negative_fc",
+                format!("{pair_name} has a negative fc {fc} with lcb {lcb}"),
+            ));
+            continue;
+        }
+
+        let (start, end) = (fc as u64, fc as u64 + lcb as u64);
+        if end > table_stream_len {
+            diagnostics.push(
+                Diagnostic::new(
+                    Severity::Error,
+                    "fc_lcb_out_of_bounds",
+                    format!(
+                        "{pair_name} ends at {end:#X}, past the end of the {table_stream_name} stream ({table_stream_len:#X} bytes)"
+                    ),
+                )
+                .range(table_stream_name, start, end),
+            );
+            continue;
+        }
+
+        regions.push((start, end, pair_name));
+    }
+
+    regions.sort_by_key(|(start, end, _)| (*start, *end));
+    for (i, (_, end, name)) in regions.iter().enumerate() {
+        for (other_start, other_end, other_name) in &regions[i + 1..] {
+            if other_start >= end {
+                break;
+            }
+            diagnostics.push(
+                Diagnostic::new(
+                    Severity::Warning,
+                    "fc_lcb_overlap",
+                    format!("{name} overlaps {other_name}"),
+                )
+                .range(table_stream_name, *other_start, (*end).min(*other_end)),
+            );
+        }
+    }
+
+    diagnostics
+}
+
+/// Warns about the fc/lcb pairs of a Word 97 or later FIB that are no longer used but are set
+pub fn validate_legacy_pairs(fib: &Fib) -> Vec<Diagnostic> {
+    let n_fib = fib.effective_nfib();
+    if n_fib < NFIB_WORD_97 {
+        return vec![];
+    }
+
+    legacy_pairs(fib)
+        .into_iter()
+        .filter(|(_, lcb)| *lcb != 0)
+        .map(|(name, lcb)| {
+            Diagnostic::new(
+                Severity::Warning,
+                "fc_lcb_legacy_set",
+                format!("{name} is no longer used for nFib {n_fib:#06X} but is {lcb}"),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    use crate::decoder::from_reader::{self, tests::fib_bytes};
+
+    #[test]
+    fn test_validate_fc_lcb_pairs() {
+        let pairs = vec![
+            (0x10, 0x20, "STSH structure".to_string()),
+            (0x20, 0x08, "Complex File Information".to_string()),
+            (0x40, 0, "glossary PLC".to_string()),
+            (0x38, 0x10, "bookmark names STTBF".to_string()),
+            (0, 4, "No longer used".to_string()),
+        ];
+
+        let diagnostics = validate_fc_lcb_pairs(&pairs, "1Table", 0x40);
+        let codes = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.code.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            vec!["fc_without_lcb", "fc_lcb_out_of_bounds", "fc_lcb_overlap"]
+        );
+        assert_eq!(
+            diagnostics[1].range,
+            Some(ByteRange {
+                start: 0x38,
+                end: 0x48
+            })
+        );
+        // "No longer used" [0, 4) overlaps nothing, the STSH [0x10, 0x30) overlaps the CLX
+        assert_eq!(
+            diagnostics[2].range,
+            Some(ByteRange {
+                start: 0x20,
+                end: 0x28
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_legacy_pairs() {
+        for (n_fib, expected) in [(0xC1, 1), (0x68, 0)] {
+            let mut bytes = fib_bytes(n_fib, 0x5D, &[]);
+            // lcbPlcpad, the eighth pair of FibRgFcLcb97
+            bytes[0xD6..0xDA].copy_from_slice(&4u32.to_le_bytes());
+            let (fib, _) = from_reader::fib_from_read_impl(&mut Cursor::new(bytes)).unwrap();
+            assert_eq!(fib.lcbPlcpad, 4);
+
+            let diagnostics = validate_legacy_pairs(&fib);
+            assert_eq!(diagnostics.len(), expected);
+            if let Some(diagnostic) = diagnostics.first() {
+                assert_eq!(diagnostic.code, "fc_lcb_legacy_set");
+                assert!(diagnostic.message.starts_with("lcbPlcpad "));
+            }
+        }
+    }
+}
@@ -0,0 +1,378 @@
+//! VBA projects of the `Macros` storage as described in [MS-OVBA]. The `dir` stream lists the
+//! modules of the project and every module stream ends with its compressed source code
+use std::io::{self, Read, Seek};
+
+use cfb::CompoundFile;
+use sha2::{Digest, Sha256};
+
+use super::model::*;
+
+/// The storage holding the VBA project of a Word document
+pub const VBA_STORAGE: &str = "/Macros/VBA";
+
+/// Procedures that Word, Excel or VBA run by themselves when a document is opened or closed
+const AUTO_EXEC_PROCEDURES: [&str; 16] = [
+    "AutoExec",
+    "AutoOpen",
+    "AutoNew",
+    "AutoClose",
+    "AutoExit",
+    "Auto_Open",
+    "Auto_Close",
+    "Document_Open",
+    "Document_New",
+    "Document_Close",
+    "Document_BeforeClose",
+    "Document_ContentControlOnEnter",
+    "DocumentOpen",
+    "DocumentBeforeClose",
+    "Workbook_Open",
+    "Workbook_Activate",
+];
+
+/// Reads the VBA project of the document, `None` if it has no macros
+pub fn read_vba_project<F: Read + Seek>(
+    cfb: &mut CompoundFile<F>,
+) -> io::Result<Option<VbaProject>> {
+    let dir_path = format!("{VBA_STORAGE}/dir");
+    if !cfb.is_stream(&dir_path) {
+        return Ok(None);
+    }
+
+    let dir = decompress(&read_stream(cfb, &dir_path)?)?;
+    let mut project = project_from_dir(&dir)?;
+    let code_page = project.code_page;
+
+    for module in &mut project.modules {
+        let stream_path = format!("{VBA_STORAGE}/{}", module.stream_name);
+        if !cfb.is_stream(&stream_path) {
+            continue;
+        }
+        let stream = read_stream(cfb, &stream_path)?;
+        // a module whose source can not be decompressed keeps an empty source
+        let Some(source) = stream
+            .get(module.text_offset as usize..)
+            .and_then(|compressed| decompress(compressed).ok())
+        else {
+            continue;
+        };
+
+        module.source_size = source.len();
+        module.source_sha256 = hex::encode(Sha256::digest(&source));
+        module.source = decode(&source, code_page);
+        module.auto_exec = auto_exec_procedures(&module.source);
+    }
+
+    Ok(Some(project))
+}
+
+fn read_stream<F: Read + Seek>(cfb: &mut CompoundFile<F>, path: &str) -> io::Result<Vec<u8>> {
+    let mut bytes = vec![];
+    cfb.open_stream(path)?.read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Decompresses a CompressedContainer, a signature byte followed by chunks of at most 4096
+/// decompressed bytes
+pub fn decompress(container: &[u8]) -> io::Result<Vec<u8>> {
+    let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message.to_string());
+    if container.first() != Some(&0x01) {
+        return Err(invalid("not a compressed container"));
+    }
+
+    let mut decompressed = vec![];
+    let mut position = 1;
+    while position + 2 <= container.len() {
+        let header = u16::from_le_bytes([container[position], container[position + 1]]);
+        let chunk_end = (position + (header & 0x0FFF) as usize + 3).min(container.len());
+        position += 2;
+
+        // uncompressed chunks hold their bytes as they are
+        if header & 0x8000 == 0 {
+            decompressed.extend(&container[position..chunk_end]);
+            position = chunk_end;
+            continue;
+        }
+
+        let chunk_start = decompressed.len();
+        while position < chunk_end {
+            let flags = container[position];
+            position += 1;
+
+            for bit in 0..8 {
+                if position >= chunk_end {
+                    break;
+                }
+
+                if flags & (1 << bit) == 0 {
+                    decompressed.push(container[position]);
+                    position += 1;
+                    continue;
+                }
+
+                // a CopyToken, its split between offset and length depends on how much of the
+                // chunk is decompressed
+                let token = container
+                    .get(position..position + 2)
+                    .map(|token| u16::from_le_bytes([token[0], token[1]]))
+                    .ok_or_else(|| invalid("truncated copy token"))?;
+                position += 2;
+                let difference = decompressed.len() - chunk_start;
+                let mut bit_count = 4;
+                while bit_count < 12 && (1 << bit_count) < difference {
+                    bit_count += 1;
+                }
+                let length_mask = 0xFFFF >> bit_count;
+                let length = (token & length_mask) as usize + 3;
+                let offset = (token >> (16 - bit_count)) as usize + 1;
+                if offset > difference {
+                    return Err(invalid("copy token points before the chunk"));
+                }
+                for _ in 0..length {
+                    decompressed.push(decompressed[decompressed.len() - offset]);
+                }
+            }
+        }
+    }
+
+    Ok(decompressed)
+}
+
+/// Reads the records of the decompressed `dir` stream, the modules are left without source
+pub fn project_from_dir(dir: &[u8]) -> io::Result<VbaProject> {
+    let mut project = VbaProject {
+        storage_path: VBA_STORAGE.trim_start_matches('/').to_string(),
+        name: None,
+        doc_string: None,
+        sys_kind: None,
+        code_page: None,
+        version: None,
+        constants: None,
+        references: vec![],
+        modules: vec![],
+    };
+    let mut module: Option<VbaModule> = None;
+
+    let mut position = 0;
+    while position + 6 <= dir.len() {
+        let id = u16::from_le_bytes([dir[position], dir[position + 1]]);
+        let size = u32::from_le_bytes([
+            dir[position + 2],
+            dir[position + 3],
+            dir[position + 4],
+            dir[position + 5],
+        ]);
+        position += 6;
+        // PROJECTVERSION has a reserved field where the size would be
+        let size = if id == 0x0009 { 6 } else { size as usize };
+        let data = dir.get(position..position + size).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::UnexpectedEof, "truncated dir stream record")
+        })?;
+        position += size;
+
+        let code_page = project.code_page;
+        let u32_of = |data: &[u8]| {
+            data.get(0..4)
+                .map(|value| u32::from_le_bytes([value[0], value[1], value[2], value[3]]))
+        };
+        match id {
+            // PROJECTSYSKIND
+            0x0001 => project.sys_kind = u32_of(data),
+            // PROJECTCODEPAGE
+            0x0003 => {
+                project.code_page = data.get(0..2).map(|cp| u16::from_le_bytes([cp[0], cp[1]]))
+            }
+            // PROJECTNAME
+            0x0004 => project.name = Some(decode(data, code_page)),
+            // PROJECTDOCSTRING
+            0x0005 => project.doc_string = Some(decode(data, code_page)),
+            // PROJECTVERSION
+            0x0009 => {
+                project.version = u32_of(data)
+                    .map(|major| format!("{major}.{}", u16::from_le_bytes([data[4], data[5]])));
+            }
+            // PROJECTCONSTANTS
+            0x000C => project.constants = Some(decode(data, code_page)),
+            // REFERENCENAME
+            0x0016 => project.references.push(decode(data, code_page)),
+            // MODULENAME starts a module
+            0x0019 => {
+                project.modules.extend(module.take());
+                let name = decode(data, code_page);
+                module = Some(VbaModule {
+                    stream_name: name.clone(),
+                    name,
+                    module_type: "procedural".to_string(),
+                    text_offset: 0,
+                    read_only: false,
+                    private: false,
+                    auto_exec: vec![],
+                    source_size: 0,
+                    source_sha256: String::new(),
+                    source: String::new(),
+                });
+            }
+            _ => {
+                if let Some(module) = module.as_mut() {
+                    match id {
+                        // MODULENAMEUNICODE
+                        0x0047 => module.name = utf_16_le(data),
+                        // MODULESTREAMNAME and its Unicode version
+                        0x001A => module.stream_name = decode(data, code_page),
+                        0x0032 => module.stream_name = utf_16_le(data),
+                        // MODULEOFFSET
+                        0x0031 => module.text_offset = u32_of(data).unwrap_or_default(),
+                        // MODULETYPE
+                        0x0021 => module.module_type = "procedural".to_string(),
+                        0x0022 => module.module_type = "document".to_string(),
+                        // MODULEREADONLY and MODULEPRIVATE
+                        0x0025 => module.read_only = true,
+                        0x0028 => module.private = true,
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        match id {
+            // the Terminator of a module
+            0x002B => project.modules.extend(module.take()),
+            // the Terminator of the dir stream
+            0x0010 => break,
+            _ => {}
+        }
+    }
+    project.modules.extend(module);
+
+    Ok(project)
+}
+
+/// Decodes MBCS bytes of the project, as UTF-8 for code page 65001 and as Latin-1 otherwise
+fn decode(bytes: &[u8], code_page: Option<u16>) -> String {
+    match code_page {
+        Some(65001) => String::from_utf8_lossy(bytes).into_owned(),
+        _ => bytes.iter().map(|&byte| byte as char).collect(),
+    }
+}
+
+fn utf_16_le(bytes: &[u8]) -> String {
+    let units = bytes
+        .chunks_exact(2)
+        .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+        .collect::<Vec<_>>();
+    String::from_utf16_lossy(&units)
+}
+
+/// The procedures of `source` that run by themselves, e.g. `Sub AutoOpen()`
+pub fn auto_exec_procedures(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .filter_map(|line| {
+            let mut words = line.split_whitespace().skip_while(|word| {
+                ["Public", "Private", "Friend", "Static"]
+                    .iter()
+                    .any(|modifier| modifier.eq_ignore_ascii_case(word))
+            });
+            let keyword = words.next()?;
+            if !keyword.eq_ignore_ascii_case("Sub") && !keyword.eq_ignore_ascii_case("Function") {
+                return None;
+            }
+            let name = words.next()?.split('(').next()?;
+
+            AUTO_EXEC_PROCEDURES
+                .iter()
+                .any(|procedure| procedure.eq_ignore_ascii_case(name))
+                .then(|| name.to_string())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Write};
+
+    #[test]
+    fn test_vba() {
+        // the example of MS-OVBA 3.2.3, a chunk of literals and copy tokens
+        let container = hex::decode(
+            "012fb000236161616263646582660070616768696a013808616b6c00306d6e6f70\
+             0671027004107273747576107778797a003c",
+        )
+        .unwrap();
+        assert_eq!(
+            decompress(&container).unwrap(),
+            b"#aaabcdefaaaaghijaaaaaklaaamnopqaaaaaaaaaaaarstuvwxyzaaa"
+        );
+
+        // a compressed container of literals only, one flag byte for every 8 of them
+        let compress = |bytes: &[u8]| {
+            let mut chunk = vec![];
+            for literals in bytes.chunks(8) {
+                chunk.push(0);
+                chunk.extend(literals);
+            }
+            let mut container = vec![1];
+            container.extend((0xB000 | (chunk.len() as u16 - 1)).to_le_bytes());
+            container.extend(chunk);
+            container
+        };
+        let mut dir = vec![];
+        for (id, data) in [
+            (0x0001, 1u32.to_le_bytes().to_vec()),
+            (0x0003, 1252u16.to_le_bytes().to_vec()),
+            (0x0004, b"Project".to_vec()),
+            (0x0019, b"ThisDocument".to_vec()),
+            (0x001A, b"ThisDocument".to_vec()),
+            (0x0031, 3u32.to_le_bytes().to_vec()),
+            (0x0022, vec![]),
+            (0x002B, vec![]),
+            (0x0019, b"Module1".to_vec()),
+            (0x001A, b"Module1".to_vec()),
+            (0x0031, 0u32.to_le_bytes().to_vec()),
+            (0x0021, vec![]),
+            (0x002B, vec![]),
+            (0x0010, vec![]),
+        ] {
+            dir.extend((id as u16).to_le_bytes());
+            dir.extend((data.len() as u32).to_le_bytes());
+            dir.extend(data);
+        }
+        let source = "Private Sub Document_Open()\r\n    Shell \"calc\"\r\nEnd Sub\r\n";
+        let mut this_document = vec![0xCA, 0xFE, 0x00];
+        this_document.extend(compress(source.as_bytes()));
+
+        let mut cfb = CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+        cfb.create_storage_all("/Macros/VBA").unwrap();
+        for (path, data) in [
+            ("/Macros/VBA/dir", compress(&dir)),
+            ("/Macros/VBA/ThisDocument", this_document),
+            (
+                "/Macros/VBA/Module1",
+                compress(b"Sub AutoOpen()\r\nEnd Sub"),
+            ),
+        ] {
+            cfb.create_stream(path).unwrap().write_all(&data).unwrap();
+        }
+
+        let project = read_vba_project(&mut cfb).unwrap().unwrap();
+        assert_eq!(project.name.as_deref(), Some("Project"));
+        assert_eq!((project.sys_kind, project.code_page), (Some(1), Some(1252)));
+        assert_eq!(project.modules.len(), 2);
+        let this_document = &project.modules[0];
+        assert_eq!(this_document.module_type, "document");
+        assert_eq!(this_document.text_offset, 3);
+        assert_eq!(this_document.source, source);
+        assert_eq!(this_document.source_size, source.len());
+        assert_eq!(this_document.auto_exec, ["Document_Open"]);
+        assert_eq!(project.modules[1].module_type, "procedural");
+        assert_eq!(project.modules[1].auto_exec, ["AutoOpen"]);
+
+        assert!(auto_exec_procedures("Sub AutoOpenLater()\r\n' Sub AutoOpen()").is_empty());
+        assert!(
+            read_vba_project(&mut CompoundFile::create(Cursor::new(Vec::new())).unwrap())
+                .unwrap()
+                .is_none()
+        );
+    }
+}
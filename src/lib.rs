@@ -1,2 +1,4 @@
 pub mod decoder;
-pub mod subreader;
\ No newline at end of file
+pub mod report;
+pub mod server;
+pub mod subreader;
@@ -1,51 +1,1164 @@
-use decoder::WordDocument;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use doc_anal::{
+    decoder::{
+        docx_rsids, is_zip, json_schema, rsid_overlap, Diagnostic, DirectoryEntry, EmbeddedObject,
+        Field, Note, PhysicalStructure, Picture, ResidualText, RsidOverlap, Severity,
+        StreamCoverage, Structure, VbaProject, WordDocument,
+    },
+    report, server,
+};
 use serde_derive::Deserialize;
+use serde_json::{json, Value};
 use std::{
-    fs::{self, File},
-    io,
-    path::Path,
+    fs,
+    io::{self, Write},
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process::ExitCode,
 };
 
-mod decoder;
-mod subreader;
+/// Name of the config file that is picked up from the current directory when `--config` is not given
+const DEFAULT_CONFIG_PATH: &str = "doc_anal_config.toml";
+
+/// Exit codes returned by the program
+mod exit {
+    /// Everything went fine (and compared documents are identical)
+    pub const SUCCESS: u8 = 0;
+    /// The compared documents differ
+    pub const DIFFERENCES: u8 = 1;
+    /// Bad arguments or config (clap also uses 2 for its own usage errors)
+    pub const USAGE: u8 = 2;
+    /// A file could not be read or the output could not be written
+    pub const IO_ERROR: u8 = 3;
+    /// A file was read but could not be decoded as a Word document
+    pub const DECODE_ERROR: u8 = 4;
+}
+
+/// Compares the structure of .doc (and .docx) files to see if they have maybe been converted from each other
+#[derive(Debug, Parser)]
+#[command(name = "doc_anal", version, about)]
+struct Cli {
+    /// Path of the TOML config file holding default values
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
 
-fn main() -> io::Result<()> {
-    println!("This is a rust program that is supposed to compary .doc and .docx files to see if they have maybe been converted from each other");
+    /// Password used to decrypt encrypted documents
+    #[arg(long, global = true)]
+    password: Option<String>,
 
-    let config = Config::new();
-    let file_path = config.input.old_doc_file_name;
+    #[command(flatten)]
+    output: OutputArgs,
 
-    let file = File::open(file_path)?;
-    let word_doc = WordDocument::read_file(file)?;
-    let _ = word_doc.get_logical_structures();
-    let _ = word_doc.get_physical_sructures();
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Args)]
+struct OutputArgs {
+    /// Write the output to this file (or directory for `batch`) instead of stdout
+    #[arg(short, long, global = true)]
+    output: Option<PathBuf>,
+
+    /// Format of the output
+    #[arg(short, long, global = true, value_enum)]
+    format: Option<OutputFormat>,
+}
 
-    // let something = word_doc.to_json();
-    // println!("{}", something.pretty(2));
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Print the logical structures of a document as JSON
+    Inspect {
+        /// The document to inspect, defaults to `input.old_doc_file_name` in the config
+        file: Option<PathBuf>,
+    },
+    /// Print the physical structures (byte ranges) of a document
+    Physical {
+        /// The document to inspect, defaults to `input.old_doc_file_name` in the config
+        file: Option<PathBuf>,
+    },
+    /// List the problems found in a document, e.g. invalid fc/lcb pairs in the FIB
+    Diagnostics {
+        /// The document to inspect, defaults to `input.old_doc_file_name` in the config
+        file: Option<PathBuf>,
+    },
+    /// Show how much of every stream is explained by the physical structures
+    Coverage {
+        /// The document to inspect, defaults to `input.old_doc_file_name` in the config
+        file: Option<PathBuf>,
+    },
+    /// Compare two documents. Exits with 1 if they differ
+    Compare {
+        /// The reference document
+        a: PathBuf,
+        /// The document compared against the reference
+        b: PathBuf,
+        #[command(flatten)]
+        mode: CompareMode,
+    },
+    /// List the streams and storages of the compound file
+    Streams {
+        /// The document to inspect, defaults to `input.old_doc_file_name` in the config
+        file: Option<PathBuf>,
+        /// List every directory entry with its CLSID, timestamps, sectors and tree links
+        #[arg(long)]
+        tree: bool,
+    },
+    /// List the pictures of the Data stream and the BLIP store
+    Pictures {
+        /// The document to inspect, defaults to `input.old_doc_file_name` in the config
+        file: Option<PathBuf>,
+        /// Write every picture to this directory as `picture_<index>.<extension>`
+        #[arg(long)]
+        extract: Option<PathBuf>,
+    },
+    /// List the objects embedded in the ObjectPool
+    Objects {
+        /// The document to inspect, defaults to `input.old_doc_file_name` in the config
+        file: Option<PathBuf>,
+        /// Write the payload of every object to this directory as `object_<index>.<extension>`
+        #[arg(long)]
+        extract: Option<PathBuf>,
+    },
+    /// List the modules of the VBA project and flag the procedures that run by themselves
+    Macros {
+        /// The document to inspect, defaults to `input.old_doc_file_name` in the config
+        file: Option<PathBuf>,
+        /// Write the source of every module to this directory as `<module>.bas` or `<module>.cls`
+        #[arg(long)]
+        extract: Option<PathBuf>,
+        /// Print the source of every module after its line
+        #[arg(long)]
+        source: bool,
+    },
+    /// List the footnotes and endnotes with the main text around their references
+    Notes {
+        /// The document to inspect, defaults to `input.old_doc_file_name` in the config
+        file: Option<PathBuf>,
+    },
+    /// List the fields of every subdocument with their instructions and results
+    Fields {
+        /// The document to inspect, defaults to `input.old_doc_file_name` in the config
+        file: Option<PathBuf>,
+    },
+    /// List the text found in bytes the document no longer refers to, e.g. left by fast saves
+    Residual {
+        /// The document to inspect, defaults to `input.old_doc_file_name` in the config
+        file: Option<PathBuf>,
+    },
+    /// Decode every .doc file in a directory
+    Batch {
+        /// The directory to scan
+        dir: PathBuf,
+    },
+    /// Print the JSON Schema of the JSON output
+    Schema,
+    /// Run a local HTTP server that serves the JSON of uploaded documents to the frontend
+    Serve {
+        /// Address to listen on, defaults to `server.bind` in the config or 127.0.0.1
+        #[arg(long)]
+        bind: Option<String>,
+        /// Port to listen on, defaults to `server.port` in the config or 8080
+        #[arg(short, long)]
+        port: Option<u16>,
+        /// Origin of a web page that may call the API, defaults to `server.allow_origin` in the
+        /// config. Without it browsers only allow pages served by doc_anal itself
+        #[arg(long)]
+        allow_origin: Option<String>,
+    },
+}
 
-    // let fib = &word_doc.fib;
-    // println!("{:?}", fib);
-    // let stylesheet = &word_doc.stylesheet;
-    // println!("{:#?}", stylesheet);
+#[derive(Debug, Args)]
+#[group(multiple = false)]
+struct CompareMode {
+    /// Compare the logical structures (default)
+    #[arg(long)]
+    logical: bool,
+    /// Compare the physical structures byte by byte
+    #[arg(long)]
+    physical: bool,
+    /// Compare the text subdocuments
+    #[arg(long)]
+    text: bool,
+    /// Compare the rsids of the editing sessions, either document can be a .docx
+    #[arg(long)]
+    rsids: bool,
+}
 
-    word_doc.print_cfb_structure();
-    Ok(())
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum OutputFormat {
+    /// Compact JSON
+    Json,
+    /// Indented JSON
+    Pretty,
+    /// Human readable text
+    Text,
+    /// Self-contained HTML report, only for `compare`
+    Html,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize)]
 struct Input {
-    old_doc_file_name: String,
+    old_doc_file_name: Option<PathBuf>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Output {
+    format: Option<OutputFormat>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize)]
+struct Server {
+    bind: Option<String>,
+    port: Option<u16>,
+    allow_origin: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
 struct Config {
+    #[serde(default)]
     input: Input,
+    #[serde(default)]
+    output: Output,
+    #[serde(default)]
+    server: Server,
 }
 
 impl Config {
-    fn new() -> Self {
-        let path = Path::new("doc_anal_config.toml");
-        let content = fs::read_to_string(path).expect("Unable to locate 'doc_anal_config.toml'");
-        toml::from_str(&content).unwrap()
+    /// Reads the config at `path`. Without a path the default config is used if it exists
+    fn load(path: Option<&Path>) -> Result<Self, CliError> {
+        let (path, required) = match path {
+            Some(path) => (path, true),
+            None => (Path::new(DEFAULT_CONFIG_PATH), false),
+        };
+
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if !required && err.kind() == io::ErrorKind::NotFound => {
+                return Ok(Config::default())
+            }
+            Err(err) => return Err(CliError::io(path, err)),
+        };
+
+        toml::from_str(&content)
+            .map_err(|err| CliError::Usage(format!("invalid config '{}': {err}", path.display())))
+    }
+}
+
+#[derive(Debug)]
+enum CliError {
+    Usage(String),
+    Io(String),
+    Decode(String),
+}
+
+impl CliError {
+    fn io(path: &Path, err: io::Error) -> Self {
+        CliError::Io(format!("{}: {err}", path.display()))
+    }
+
+    fn decode(path: &Path, err: io::Error) -> Self {
+        CliError::Decode(format!("{}: {err}", path.display()))
+    }
+
+    fn exit_code(&self) -> u8 {
+        match self {
+            CliError::Usage(_) => exit::USAGE,
+            CliError::Io(_) => exit::IO_ERROR,
+            CliError::Decode(_) => exit::DECODE_ERROR,
+        }
+    }
+}
+
+impl std::fmt::Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliError::Usage(message) | CliError::Io(message) | CliError::Decode(message) => {
+                write!(f, "{message}")
+            }
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(cli) {
+        Ok(code) => ExitCode::from(code),
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::from(err.exit_code())
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<u8, CliError> {
+    let config = Config::load(cli.config.as_deref())?;
+    let format = cli
+        .output
+        .format
+        .or(config.output.format)
+        .unwrap_or(OutputFormat::Pretty);
+    let output = cli.output.output.as_deref();
+    let password = cli.password.as_deref();
+    if format == OutputFormat::Html && !matches!(cli.command, Command::Compare { .. }) {
+        return Err(CliError::Usage(
+            "the html format is only supported by 'compare'".into(),
+        ));
+    }
+
+    let input_file = |file: Option<PathBuf>| {
+        file.or_else(|| config.input.old_doc_file_name.clone())
+            .ok_or_else(|| {
+                CliError::Usage(
+                    "no input file given and no 'input.old_doc_file_name' in the config".into(),
+                )
+            })
+    };
+
+    match cli.command {
+        Command::Inspect { file } => {
+            let word_doc = open_document(&input_file(file)?, password)?;
+            let rendered = match format {
+                OutputFormat::Text => {
+                    structures_to_text(&word_doc.get_logical_structures())
+                        + &diagnostics_to_text(&word_doc.get_diagnostics())
+                }
+                _ => render_json(&word_doc.to_json_logical(), format),
+            };
+            write_output(output, &rendered)?;
+            Ok(exit::SUCCESS)
+        }
+        Command::Physical { file } => {
+            let file = input_file(file)?;
+            let word_doc = open_document(&file, password)?;
+            let rendered = match format {
+                OutputFormat::Text => {
+                    let structures = word_doc
+                        .get_physical_sructures()
+                        .map_err(|err| CliError::decode(&file, err))?;
+                    physical_structures_to_text(&structures)
+                        + &diagnostics_to_text(&word_doc.get_diagnostics())
+                }
+                _ => render_json(
+                    &word_doc
+                        .to_json_physical()
+                        .map_err(|err| CliError::decode(&file, err))?,
+                    format,
+                ),
+            };
+            write_output(output, &rendered)?;
+            Ok(exit::SUCCESS)
+        }
+        Command::Diagnostics { file } => {
+            let word_doc = open_document(&input_file(file)?, password)?;
+            let diagnostics = word_doc.get_diagnostics();
+            let rendered = match format {
+                OutputFormat::Text if diagnostics.is_empty() => "no diagnostics\n".to_string(),
+                OutputFormat::Text => diagnostics_to_text(&diagnostics),
+                _ => render_json(&json!(diagnostics), format),
+            };
+            write_output(output, &rendered)?;
+            Ok(exit::SUCCESS)
+        }
+        Command::Coverage { file } => {
+            let file = input_file(file)?;
+            let word_doc = open_document(&file, password)?;
+            let rendered = match format {
+                OutputFormat::Text => {
+                    let coverage = word_doc
+                        .get_coverage()
+                        .map_err(|err| CliError::decode(&file, err))?;
+                    coverage_to_text(&coverage) + &diagnostics_to_text(&word_doc.get_diagnostics())
+                }
+                _ => render_json(
+                    &word_doc
+                        .to_json_coverage()
+                        .map_err(|err| CliError::decode(&file, err))?,
+                    format,
+                ),
+            };
+            write_output(output, &rendered)?;
+            Ok(exit::SUCCESS)
+        }
+        Command::Compare { a, b, mode } if mode.rsids => {
+            if format == OutputFormat::Html {
+                return Err(CliError::Usage(
+                    "the html format does not support `--rsids`".to_string(),
+                ));
+            }
+            let overlap = rsid_overlap(&read_rsids(&a, password)?, &read_rsids(&b, password)?);
+
+            let rendered = match format {
+                OutputFormat::Text => rsid_overlap_to_text(&overlap),
+                _ => render_json(&json!(overlap), format),
+            };
+            write_output(output, &rendered)?;
+
+            Ok(
+                if overlap.only_in_reference + overlap.only_in_compared > 0 {
+                    exit::DIFFERENCES
+                } else {
+                    exit::SUCCESS
+                },
+            )
+        }
+        Command::Compare { a, b, mode } => {
+            let reference = open_decrypted_document(&a, password)?;
+            let other = open_decrypted_document(&b, password)?;
+
+            // `compare_to_*` compares the document to the reference passed in
+            let compare_to_physical = || {
+                other.compare_to_physical(&reference).map_err(|err| {
+                    CliError::Decode(format!("{} and {}: {err}", a.display(), b.display()))
+                })
+            };
+            let comparisons = if mode.physical {
+                vec![compare_to_physical()?]
+            } else if mode.text {
+                vec![other.compare_to_text(&reference)]
+            } else if mode.logical || format != OutputFormat::Html {
+                vec![other.compare_to_logical(&reference)]
+            } else {
+                // the report shows both unless a comparison was picked
+                vec![other.compare_to_logical(&reference), compare_to_physical()?]
+            };
+            let has_differences = comparisons
+                .iter()
+                .any(|comparison| json_has_differences(&comparison["data"]));
+
+            let rendered = match format {
+                OutputFormat::Text => comparison_to_text(&comparisons[0]),
+                OutputFormat::Html => report::comparison_report(
+                    &a.display().to_string(),
+                    &b.display().to_string(),
+                    &comparisons.iter().collect::<Vec<_>>(),
+                ),
+                _ => render_json(&comparisons[0], format),
+            };
+            write_output(output, &rendered)?;
+
+            Ok(if has_differences {
+                exit::DIFFERENCES
+            } else {
+                exit::SUCCESS
+            })
+        }
+        Command::Streams { file, tree } => {
+            let word_doc = open_document(&input_file(file)?, password)?;
+            let rendered = match format {
+                OutputFormat::Text if tree => directory_to_text(&word_doc.directory),
+                _ if tree => render_json(&json!(word_doc.directory), format),
+                OutputFormat::Text => {
+                    let mut rendered = Vec::new();
+                    word_doc
+                        .write_cfb_structure(&mut rendered)
+                        .map_err(|err| CliError::Io(err.to_string()))?;
+                    String::from_utf8_lossy(&rendered).into_owned()
+                }
+                _ => {
+                    let cfb = word_doc.cfb.borrow();
+                    let entries = cfb
+                        .walk()
+                        .map(|entry| {
+                            json!({
+                                "name": entry.name(),
+                                "path": entry.path().display().to_string(),
+                                "kind": if entry.is_stream() { "stream" } else { "storage" },
+                                "len": entry.len(),
+                            })
+                        })
+                        .collect::<Vec<_>>();
+                    render_json(&Value::from(entries), format)
+                }
+            };
+            write_output(output, &rendered)?;
+            Ok(exit::SUCCESS)
+        }
+        Command::Pictures { file, extract } => {
+            let word_doc = open_decrypted_document(&input_file(file)?, password)?;
+            if let Some(dir) = extract {
+                fs::create_dir_all(&dir).map_err(|err| CliError::io(&dir, err))?;
+                for (i, picture) in word_doc.pictures.iter().enumerate() {
+                    let path = dir.join(format!("picture_{i}.{}", picture.blip_type.extension()));
+                    fs::write(&path, &picture.data).map_err(|err| CliError::io(&path, err))?;
+                }
+            }
+            let rendered = match format {
+                OutputFormat::Text if word_doc.pictures.is_empty() => "no pictures\n".to_string(),
+                OutputFormat::Text => pictures_to_text(&word_doc.pictures),
+                _ => render_json(&json!(word_doc.pictures), format),
+            };
+            write_output(output, &rendered)?;
+            Ok(exit::SUCCESS)
+        }
+        Command::Objects { file, extract } => {
+            let word_doc = open_decrypted_document(&input_file(file)?, password)?;
+            if let Some(dir) = extract {
+                fs::create_dir_all(&dir).map_err(|err| CliError::io(&dir, err))?;
+                for (i, object) in word_doc.objects.iter().enumerate() {
+                    if object.payload_source.is_none() {
+                        continue;
+                    }
+                    let path = dir.join(format!("object_{i}.{}", object.extension()));
+                    fs::write(&path, &object.payload).map_err(|err| CliError::io(&path, err))?;
+                }
+            }
+            let rendered = match format {
+                OutputFormat::Text if word_doc.objects.is_empty() => "no objects\n".to_string(),
+                OutputFormat::Text => objects_to_text(&word_doc.objects),
+                _ => render_json(&json!(word_doc.objects), format),
+            };
+            write_output(output, &rendered)?;
+            Ok(exit::SUCCESS)
+        }
+        Command::Macros {
+            file,
+            extract,
+            source,
+        } => {
+            let word_doc = open_document(&input_file(file)?, password)?;
+            if let (Some(dir), Some(vba_project)) = (extract, &word_doc.vba_project) {
+                fs::create_dir_all(&dir).map_err(|err| CliError::io(&dir, err))?;
+                for module in &vba_project.modules {
+                    let extension = match module.module_type.as_str() {
+                        "document" => "cls",
+                        _ => "bas",
+                    };
+                    let path = dir.join(format!("{}.{extension}", file_name_of(&module.name)));
+                    fs::write(&path, &module.source).map_err(|err| CliError::io(&path, err))?;
+                }
+            }
+            let rendered = match (format, &word_doc.vba_project) {
+                (OutputFormat::Text, None) => "no macros\n".to_string(),
+                (OutputFormat::Text, Some(vba_project)) => vba_project_to_text(vba_project, source),
+                _ => render_json(&json!(word_doc.vba_project), format),
+            };
+            write_output(output, &rendered)?;
+            Ok(exit::SUCCESS)
+        }
+        Command::Notes { file } => {
+            let word_doc = open_decrypted_document(&input_file(file)?, password)?;
+            let rendered = match format {
+                OutputFormat::Text if word_doc.notes.is_empty() => "no notes\n".to_string(),
+                OutputFormat::Text => notes_to_text(&word_doc.notes),
+                _ => render_json(&json!(word_doc.notes), format),
+            };
+            write_output(output, &rendered)?;
+            Ok(exit::SUCCESS)
+        }
+        Command::Fields { file } => {
+            let word_doc = open_decrypted_document(&input_file(file)?, password)?;
+            let rendered = match format {
+                OutputFormat::Text if word_doc.fields.is_empty() => "no fields\n".to_string(),
+                OutputFormat::Text => fields_to_text(&word_doc.fields),
+                _ => render_json(&json!(word_doc.fields), format),
+            };
+            write_output(output, &rendered)?;
+            Ok(exit::SUCCESS)
+        }
+        Command::Residual { file } => {
+            let word_doc = open_decrypted_document(&input_file(file)?, password)?;
+            let rendered = match format {
+                OutputFormat::Text if word_doc.residual_text.is_empty() => {
+                    "no residual text\n".to_string()
+                }
+                OutputFormat::Text => residual_text_to_text(&word_doc.residual_text),
+                _ => render_json(&json!(word_doc.residual_text), format),
+            };
+            write_output(output, &rendered)?;
+            Ok(exit::SUCCESS)
+        }
+        Command::Batch { dir } => run_batch(&dir, output, format, password),
+        Command::Schema => {
+            write_output(output, &render_json(&json_schema(), OutputFormat::Pretty))?;
+            Ok(exit::SUCCESS)
+        }
+        Command::Serve {
+            bind,
+            port,
+            allow_origin,
+        } => {
+            let bind = bind
+                .or(config.server.bind)
+                .unwrap_or_else(|| "127.0.0.1".to_string());
+            let port = port.or(config.server.port).unwrap_or(8080);
+            let address = format!("{bind}:{port}");
+            let allow_origin = allow_origin.or(config.server.allow_origin);
+
+            eprintln!("serving on http://{address}");
+            server::serve(&address, allow_origin.as_deref())
+                .map_err(|err| CliError::Io(format!("could not serve on {address}: {err}")))?;
+            Ok(exit::SUCCESS)
+        }
+    }
+}
+
+/// Decodes every `.doc` file in `dir`. When an output directory is given the logical JSON of each
+/// document is written there, and a summary of the run is always printed to stdout
+fn run_batch(
+    dir: &Path,
+    output_dir: Option<&Path>,
+    format: OutputFormat,
+    password: Option<&str>,
+) -> Result<u8, CliError> {
+    let mut files = fs::read_dir(dir)
+        .map_err(|err| CliError::io(dir, err))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .extension()
+                    .is_some_and(|extension| extension.eq_ignore_ascii_case("doc"))
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+
+    if let Some(output_dir) = output_dir {
+        fs::create_dir_all(output_dir).map_err(|err| CliError::io(output_dir, err))?;
+    }
+
+    let mut summary = Vec::with_capacity(files.len());
+    let mut exit_code = exit::SUCCESS;
+
+    for path in files {
+        let result = open_document(&path, password).and_then(|word_doc| {
+            if let Some(output_dir) = output_dir {
+                let file_name = path.file_stem().unwrap_or_default().to_string_lossy();
+                let output_path = output_dir.join(format!("{file_name}.json"));
+                let rendered = render_json(&word_doc.to_json_logical(), format);
+                fs::write(&output_path, rendered).map_err(|err| CliError::io(&output_path, err))?;
+            }
+            Ok((word_doc.fib.nFib, word_doc.needs_password()))
+        });
+
+        let entry = match result {
+            Ok((n_fib, needs_password)) => json!({
+                "file": path.display().to_string(),
+                // only the unencrypted parts of documents without a password are decoded
+                "status": if needs_password { "encrypted" } else { "ok" },
+                "nFib": n_fib,
+            }),
+            Err(err) => {
+                exit_code = exit_code.max(err.exit_code());
+                json!({
+                    "file": path.display().to_string(),
+                    "status": "error",
+                    "error": err.to_string(),
+                })
+            }
+        };
+        summary.push(entry);
+    }
+
+    let rendered = match format {
+        OutputFormat::Text => summary
+            .iter()
+            .map(|entry| match entry["status"].as_str() {
+                Some("ok") => format!("ok    {} (nFib {})\n", plain(&entry["file"]), entry["nFib"]),
+                Some("encrypted") => format!(
+                    "ok    {} (nFib {}, encrypted, a password is needed to decode its content)\n",
+                    plain(&entry["file"]),
+                    entry["nFib"]
+                ),
+                _ => format!("error {}\n", plain(&entry["error"])),
+            })
+            .collect(),
+        _ => render_json(&Value::from(summary), format),
+    };
+    write_output(None, &rendered)?;
+
+    Ok(exit_code)
+}
+
+/// Opens and decodes the document at `path`, decrypting it with `password` if it is encrypted.
+/// The decoder still asserts on a lot of malformed input, so panics are turned into decode errors
+/// rather than taking the whole run down
+fn open_document(path: &Path, password: Option<&str>) -> Result<WordDocument, CliError> {
+    let bytes = fs::read(path).map_err(|err| CliError::io(path, err))?;
+
+    match panic::catch_unwind(AssertUnwindSafe(|| {
+        WordDocument::read_bytes_with_password(bytes, password)
+    })) {
+        Ok(Ok(word_doc)) => Ok(word_doc),
+        Ok(Err(err)) => Err(CliError::Decode(format!("{}: {err}", path.display()))),
+        Err(panic) => {
+            let message = panic
+                .downcast_ref::<String>()
+                .map(String::as_str)
+                .or_else(|| panic.downcast_ref::<&str>().copied())
+                .unwrap_or("decoder panicked");
+            Err(CliError::Decode(format!("{}: {message}", path.display())))
+        }
+    }
+}
+
+/// Opens the document at `path` like `open_document`, for the commands that need its content.
+/// Encrypted documents are only decoded this far with their password
+fn open_decrypted_document(path: &Path, password: Option<&str>) -> Result<WordDocument, CliError> {
+    let word_doc = open_document(path, password)?;
+    match &word_doc.encryption {
+        Some(encryption) if word_doc.needs_password() => Err(CliError::Decode(format!(
+            "{}: the document is protected with {}, a password is needed to decode its content",
+            path.display(),
+            encryption.encryptionType
+        ))),
+        _ => Ok(word_doc),
+    }
+}
+
+/// The rsids of a .doc file's Plrsid or of a .docx file's `w:rsids`
+fn read_rsids(path: &Path, password: Option<&str>) -> Result<Vec<String>, CliError> {
+    let bytes = fs::read(path).map_err(|err| CliError::io(path, err))?;
+    if is_zip(&bytes) {
+        return docx_rsids(&bytes)
+            .map_err(|err| CliError::Decode(format!("{}: {err}", path.display())));
+    }
+
+    let word_doc = open_decrypted_document(path, password)?;
+    Ok(word_doc
+        .rsids
+        .table
+        .into_iter()
+        .map(|session| session.rsid)
+        .collect())
+}
+
+fn write_output(output: Option<&Path>, rendered: &str) -> Result<(), CliError> {
+    match output {
+        Some(path) => fs::write(path, rendered).map_err(|err| CliError::io(path, err)),
+        None => {
+            let mut stdout = io::stdout().lock();
+            stdout
+                .write_all(rendered.as_bytes())
+                .and_then(|_| stdout.flush())
+                .map_err(|err| CliError::Io(format!("stdout: {err}")))
+        }
+    }
+}
+
+fn render_json(value: &Value, format: OutputFormat) -> String {
+    let rendered = match format {
+        OutputFormat::Json => serde_json::to_string(value),
+        _ => serde_json::to_string_pretty(value),
+    };
+    rendered.expect("a JSON value is always serializable") + "\n"
+}
+
+/// Strings without their quotes, anything else as JSON
+fn plain(value: &Value) -> String {
+    match value {
+        Value::String(string) => string.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn structures_to_text(structures: &[Structure]) -> String {
+    fn write_structure(text: &mut String, structure: &Structure, depth: usize) {
+        let indent = "  ".repeat(depth);
+        text.push_str(&format!("{indent}{}\n", structure.name));
+        for item in &structure.structure {
+            text.push_str(&format!("{indent}  {}: {}\n", item.name, item.value));
+        }
+        for substructure in structure.substructs.iter().flatten() {
+            write_structure(text, substructure, depth + 1);
+        }
+    }
+
+    let mut text = String::new();
+    for structure in structures {
+        write_structure(&mut text, structure, 0);
+    }
+    text
+}
+
+fn physical_structures_to_text(structures: &[PhysicalStructure]) -> String {
+    structures
+        .iter()
+        .map(|structure| {
+            format!(
+                "{} [{}..{}] {} - {} ({} bytes){}\n",
+                structure.stream_name,
+                structure.start_index,
+                structure.end_index,
+                structure.structure_name.as_deref().unwrap_or("-"),
+                structure.description.as_deref().unwrap_or(""),
+                structure.bytes.len(),
+                structure
+                    .diagnostic
+                    .as_ref()
+                    .map(|code| format!(" [{code}]"))
+                    .unwrap_or_default(),
+            )
+        })
+        .collect()
+}
+
+/// One line per picture with its index, as used for the names of extracted pictures
+fn pictures_to_text(pictures: &[Picture]) -> String {
+    pictures
+        .iter()
+        .enumerate()
+        .map(|(i, picture)| {
+            format!(
+                "{i}: {} {} bytes sha256:{} ({} {}@{:#X})\n",
+                picture.blip_type.extension(),
+                picture.size,
+                picture.sha256,
+                picture.source,
+                picture.stream_name,
+                picture.offset,
+            )
+        })
+        .collect()
+}
+
+/// One line per object with its index, as used for the names of extracted payloads
+fn objects_to_text(objects: &[EmbeddedObject]) -> String {
+    objects
+        .iter()
+        .enumerate()
+        .map(|(i, object)| {
+            let mut line = format!(
+                "{i}: {} {} clsid:{}",
+                object.storage_name,
+                object.prog_id.as_deref().unwrap_or("-"),
+                object.clsid,
+            );
+            if let (Some(source), Some(size), Some(sha256)) = (
+                &object.payload_source,
+                object.payload_size,
+                &object.payload_sha256,
+            ) {
+                line.push_str(&format!(" {source} {size} bytes sha256:{sha256}"));
+            }
+            if let Some(cp) = object.cp {
+                line.push_str(&format!(" at cp {cp}"));
+            }
+            if let Some(field_code) = &object.field_code {
+                line.push_str(&format!(" ({field_code})"));
+            }
+            line + "\n"
+        })
+        .collect()
+}
+
+/// One line per directory entry, indented by the depth of its path. Entries that are not linked
+/// into the tree come last
+fn directory_to_text(directory: &[DirectoryEntry]) -> String {
+    let mut entries = directory.iter().collect::<Vec<_>>();
+    // by path component so that the entries of a storage follow it
+    entries.sort_by_key(|entry| {
+        let components = entry
+            .path
+            .as_ref()
+            .map(|path| path.split('/').collect::<Vec<_>>());
+        (components.is_none(), components)
+    });
+
+    entries
+        .into_iter()
+        .map(|entry| {
+            let (indent, name) = match &entry.path {
+                Some(path) => ("  ".repeat(path.matches('/').count() - 1), path.clone()),
+                None => (String::new(), format!("(unlinked) {}", entry.name)),
+            };
+            let link = |link: Option<u32>| link.map_or("-".to_string(), |sid| sid.to_string());
+            format!(
+                "{indent}{} {} sid:{} {} bytes start:{} clsid:{} state:{:#x} created:{} modified:{} {} left:{} right:{} child:{}\n",
+                name.escape_debug(),
+                entry.object_type,
+                entry.sid,
+                entry.size,
+                entry.starting_sector,
+                entry.clsid,
+                entry.state_bits,
+                entry.creation_time,
+                entry.modified_time,
+                entry.color,
+                link(entry.left_sibling),
+                link(entry.right_sibling),
+                link(entry.child),
+            )
+        })
+        .collect()
+}
+
+/// One line per note with its mark, the main text around its reference and its text
+fn notes_to_text(notes: &[Note]) -> String {
+    notes
+        .iter()
+        .map(|note| {
+            let numbering = match note.number {
+                Some(number) => format!("{number}"),
+                None => "custom".to_string(),
+            };
+            format!(
+                "{} {numbering} at CP {}: ...{}[{}]{}... {}\n",
+                note.kind,
+                note.anchor_cp,
+                note.context_before,
+                note.mark,
+                note.context_after,
+                note.text
+            )
+        })
+        .collect()
+}
+
+/// The share of the rsids both documents have, then the shared rsids
+fn rsid_overlap_to_text(overlap: &RsidOverlap) -> String {
+    format!(
+        "overlap {:.3}: {} shared, {} only in the reference, {} only in the compared\n{}",
+        overlap.overlap,
+        overlap.shared.len(),
+        overlap.only_in_reference,
+        overlap.only_in_compared,
+        overlap
+            .shared
+            .iter()
+            .map(|rsid| format!("{rsid}\n"))
+            .collect::<String>()
+    )
+}
+
+/// One line per field with its instruction and result, nested fields indented below their field
+fn fields_to_text(fields: &[Field]) -> String {
+    fields
+        .iter()
+        .map(|field| {
+            let cp = |cp: Option<i32>| cp.map_or("-".to_string(), |cp| cp.to_string());
+            let line = format!(
+                "{}{} {}..{} {}: {} => {}\n",
+                "  ".repeat(field.depth as usize),
+                field.story,
+                field.begin_cp,
+                cp(field.end_cp),
+                field.field_type.as_deref().unwrap_or("-"),
+                field.instruction.escape_debug(),
+                field.result.escape_debug()
+            );
+            line + &fields_to_text(&field.children)
+        })
+        .collect()
+}
+
+/// One line per run of residual text, with where it was found
+fn residual_text_to_text(residual_text: &[ResidualText]) -> String {
+    residual_text
+        .iter()
+        .map(|run| {
+            format!(
+                "{} {:#x} {} bytes {} ({}): {}\n",
+                run.stream_name,
+                run.offset,
+                run.len,
+                run.encoding,
+                run.source,
+                run.text.escape_debug()
+            )
+        })
+        .collect()
+}
+
+/// The project followed by one line per module, with the source of the modules if `source`
+fn vba_project_to_text(vba_project: &VbaProject, source: bool) -> String {
+    let mut text = format!(
+        "project {} ({} modules)\n",
+        vba_project.name.as_deref().unwrap_or("-"),
+        vba_project.modules.len()
+    );
+    for module in &vba_project.modules {
+        text.push_str(&format!(
+            "{} {} {} bytes sha256:{}",
+            module.name, module.module_type, module.source_size, module.source_sha256
+        ));
+        if !module.auto_exec.is_empty() {
+            text.push_str(&format!(" AUTO-EXEC: {}", module.auto_exec.join(", ")));
+        }
+        text.push('\n');
+        if source {
+            text.push_str(&module.source);
+            if !module.source.ends_with('\n') {
+                text.push('\n');
+            }
+        }
+    }
+    text
+}
+
+/// `name` with the characters that can not appear in a file name replaced by `_`
+fn file_name_of(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// One line per diagnostic, preceded by a heading. Empty if there are none
+fn diagnostics_to_text(diagnostics: &[Diagnostic]) -> String {
+    if diagnostics.is_empty() {
+        return String::new();
+    }
+
+    let mut text = format!("\ndiagnostics ({}):\n", diagnostics.len());
+    for diagnostic in diagnostics {
+        let severity = match diagnostic.severity {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        text.push_str(&format!(
+            "  {severity:<7} [{}] {}\n",
+            diagnostic.code, diagnostic.message
+        ));
+    }
+    text
+}
+
+/// One line per stream, followed by its gaps, overlaps and structures past its end
+fn coverage_to_text(coverage: &[StreamCoverage]) -> String {
+    let name_width = coverage
+        .iter()
+        .map(|stream| stream.stream_name.len())
+        .max()
+        .unwrap_or_default();
+
+    let mut text = String::new();
+    for stream in coverage {
+        text.push_str(&format!(
+            "{:name_width$}  {:>8} bytes  {:>5.1}% explained  {} gaps  {} overlaps\n",
+            stream.stream_name,
+            stream.stream_len,
+            stream.percent_explained,
+            stream.gaps.len(),
+            stream.overlaps.len(),
+        ));
+        for gap in &stream.gaps {
+            text.push_str(&format!(
+                "  gap       0x{:08X}..0x{:08X} ({} bytes)\n",
+                gap.start,
+                gap.end,
+                gap.end - gap.start
+            ));
+        }
+        for (kind, ranges) in [
+            ("overlap", &stream.overlaps),
+            ("past end", &stream.out_of_bounds),
+        ] {
+            for range in ranges {
+                text.push_str(&format!(
+                    "  {kind:<8}  0x{:08X}..0x{:08X} ({})\n",
+                    range.start,
+                    range.end,
+                    range.structures.join(", ")
+                ));
+            }
+        }
+    }
+    text
+}
+
+/// Renders the output of any of the `compare_to_*` functions as a list of what differs
+fn comparison_to_text(comparison: &Value) -> String {
+    fn describe(entry: &Value) -> String {
+        for key in ["ref_structure", "comp_structure"] {
+            let structure = &entry[key];
+            if let Some(name) = structure["name"].as_str() {
+                return name.to_string();
+            }
+            if let Some(stream_name) = structure["stream_name"].as_str() {
+                return format!(
+                    "{stream_name} [{}..{}] {}",
+                    structure["start_index"],
+                    structure["end_index"],
+                    structure["description"].as_str().unwrap_or(""),
+                );
+            }
+        }
+        plain(&entry["name"])
+    }
+
+    fn write_entry(text: &mut String, entry: &Value, depth: usize) {
+        let indent = "  ".repeat(depth);
+        let name = describe(entry);
+
+        let difference_indices = members(&entry["difference_indices"]);
+        if entry.get("ref_structure").is_some_and(Value::is_null) {
+            text.push_str(&format!("{indent}+ {name} (only in compared document)\n"));
+        } else if entry.get("comp_structure").is_some_and(Value::is_null) {
+            text.push_str(&format!("{indent}- {name} (only in reference document)\n"));
+        } else if !difference_indices.is_empty() {
+            text.push_str(&format!(
+                "{indent}~ {name}: {} differing ranges\n",
+                difference_indices.len()
+            ));
+        } else {
+            let items = report::paired_items(&entry["ref_structure"], &entry["comp_structure"]);
+            let differences = members(&entry["structure_differences"]);
+            for ((ref_item, comp_item), different) in items.into_iter().zip(differences) {
+                if different.as_bool() == Some(true) {
+                    let value = |item: Option<&Value>| {
+                        item.map_or("(none)".to_string(), |item| plain(&item["value"]))
+                    };
+                    text.push_str(&format!(
+                        "{indent}~ {name}.{}: {} != {}\n",
+                        plain(&ref_item.or(comp_item).unwrap()["name"]),
+                        value(ref_item),
+                        value(comp_item),
+                    ));
+                }
+            }
+        }
+
+        for substructure in members(&entry["substructure_differences"]) {
+            write_entry(text, substructure, depth + 1);
+        }
+    }
+
+    let mut text = String::new();
+    for entry in members(&comparison["data"]) {
+        write_entry(&mut text, entry, 0);
+    }
+    if text.is_empty() {
+        text.push_str("no differences\n");
+    }
+    text
+}
+
+/// The elements of `value`, or none if it is not an array
+fn members(value: &Value) -> &[Value] {
+    value.as_array().map(Vec::as_slice).unwrap_or_default()
+}
+
+/// Checks the comparisons (the `data` of any of the `compare_to_*` functions) for a reported
+/// difference
+fn json_has_differences(value: &Value) -> bool {
+    match value {
+        Value::Array(entries) => entries.iter().any(json_has_differences),
+        Value::Object(entry) => {
+            let missing_side = ["ref_structure", "comp_structure"]
+                .iter()
+                .any(|key| entry.get(*key).is_some_and(Value::is_null));
+            let differing_ranges = entry
+                .get("difference_indices")
+                .is_some_and(|ranges| !members(ranges).is_empty());
+            let differing_items = entry.get("structure_differences").is_some_and(|items| {
+                members(items)
+                    .iter()
+                    .any(|item| item.as_bool() == Some(true))
+            });
+            let differing_substructures = entry
+                .get("substructure_differences")
+                .is_some_and(json_has_differences);
+
+            missing_side || differing_ranges || differing_items || differing_substructures
+        }
+        _ => false,
     }
 }
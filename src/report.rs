@@ -0,0 +1,382 @@
+//! Renders comparisons as a single self-contained HTML file, so that they can be sent to someone
+//! who does not run the frontend. All of the CSS and JS is inline, the file opens offline.
+//!
+//! The renderer works on the JSON of `WordDocument::compare_to_logical` and
+//! `WordDocument::compare_to_physical`, so saved comparisons can be rendered as well.
+
+use serde_json::Value;
+use std::fmt::Write;
+
+/// Bytes per row of the hex views
+const HEX_ROW_LEN: usize = 16;
+
+const STYLE: &str = r#"
+body { font-family: system-ui, sans-serif; margin: 0 2em 2em; color: #1f2328; }
+header { position: sticky; top: 0; background: #fff; padding: 1em 0; border-bottom: 1px solid #d0d7de; }
+h1 { margin: 0 0 0.3em; font-size: 1.4em; }
+.files span { margin-right: 2em; }
+.controls button { margin-right: 0.5em; }
+details { margin: 0.3em 0 0.3em 1em; border-left: 3px solid #d0d7de; padding-left: 0.5em; }
+details.differs { border-color: #d4a72c; }
+details.added { border-color: #1a7f37; }
+details.removed { border-color: #cf222e; }
+summary { cursor: pointer; padding: 0.2em 0; }
+.badge { font-size: 0.8em; padding: 0 0.5em; border-radius: 1em; background: #eaeef2; margin-left: 0.5em; }
+.differs > summary .badge { background: #fff8c5; }
+.added > summary .badge { background: #dafbe1; }
+.removed > summary .badge { background: #ffebe9; }
+table { border-collapse: collapse; margin: 0.3em 0; font-size: 0.9em; }
+th, td { border: 1px solid #d0d7de; padding: 0.15em 0.6em; text-align: left; vertical-align: top; }
+td.value { font-family: ui-monospace, monospace; word-break: break-all; max-width: 30em; }
+tr.differs td { background: #fff8c5; }
+.hex-pair { display: flex; gap: 2em; flex-wrap: wrap; }
+.hex-pair h4 { margin: 0.3em 0; }
+pre.hex { margin: 0; font-size: 0.85em; }
+pre.hex .offset { color: #6e7781; }
+pre.hex .diff { background: #ffcecb; color: #82071e; }
+body.only-differences .same { display: none; }
+"#;
+
+const SCRIPT: &str = r#"
+document.querySelectorAll(".controls button").forEach(function (button) {
+  button.addEventListener("click", function () {
+    var open = button.dataset.action === "expand";
+    document.querySelectorAll("details").forEach(function (details) { details.open = open; });
+  });
+});
+document.getElementById("only-differences").addEventListener("change", function (event) {
+  document.body.classList.toggle("only-differences", event.target.checked);
+});
+"#;
+
+/// Renders the comparisons of `compared` against `reference` as an HTML document.
+/// `comparisons` holds the JSON output of any of the `compare_to_*` functions, the logical and
+/// physical comparisons get their own section
+pub fn comparison_report(reference: &str, compared: &str, comparisons: &[&Value]) -> String {
+    let mut html = String::new();
+
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    let _ = writeln!(
+        html,
+        "<title>Comparison of {} and {}</title>",
+        escape(reference),
+        escape(compared)
+    );
+    let _ = writeln!(html, "<style>{STYLE}</style>\n</head>\n<body>");
+
+    html.push_str("<header>\n<h1>Document comparison</h1>\n");
+    let _ = writeln!(
+        html,
+        "<div class=\"files\"><span><b>Reference:</b> {}</span><span><b>Compared:</b> {}</span></div>",
+        escape(reference),
+        escape(compared)
+    );
+    html.push_str(
+        "<div class=\"controls\">\
+         <button data-action=\"expand\">Expand all</button>\
+         <button data-action=\"collapse\">Collapse all</button>\
+         <label><input type=\"checkbox\" id=\"only-differences\"> Only differences</label>\
+         </div>\n</header>\n",
+    );
+
+    for comparison in comparisons {
+        let entries = comparison["data"]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        match comparison["kind"].as_str() {
+            Some("logical_comparison") => {
+                html.push_str("<section>\n<h2>Logical structures</h2>\n");
+                for entry in entries {
+                    write_logical_entry(&mut html, entry);
+                }
+                html.push_str("</section>\n");
+            }
+            Some("physical_comparison") => {
+                html.push_str("<section>\n<h2>Physical structures</h2>\n");
+                for entry in entries {
+                    write_physical_entry(&mut html, entry);
+                }
+                html.push_str("</section>\n");
+            }
+            kind => {
+                let _ = writeln!(
+                    html,
+                    "<p>Comparisons of kind {} are not rendered.</p>",
+                    escape(kind.unwrap_or("unknown"))
+                );
+            }
+        }
+    }
+
+    let _ = write!(html, "<script>{SCRIPT}</script>\n</body>\n</html>\n");
+    html
+}
+
+/// Whether an entry of a comparison is only in one of the documents or differs
+fn entry_status(entry: &Value, differs: bool) -> &'static str {
+    if entry["ref_structure"].is_null() {
+        "added"
+    } else if entry["comp_structure"].is_null() {
+        "removed"
+    } else if differs {
+        "differs"
+    } else {
+        "same"
+    }
+}
+
+fn write_logical_entry(html: &mut String, entry: &Value) {
+    let ref_structure = &entry["ref_structure"];
+    let comp_structure = &entry["comp_structure"];
+    let structure = if ref_structure.is_null() {
+        comp_structure
+    } else {
+        ref_structure
+    };
+
+    let differences = entry["structure_differences"]
+        .as_array()
+        .map(|differences| {
+            differences
+                .iter()
+                .filter(|different| different == &&Value::Bool(true))
+                .count()
+        })
+        .unwrap_or_default();
+    let status = entry_status(entry, differences > 0 || logical_has_differences(entry));
+
+    let badge = match status {
+        "added" => "only in compared".to_string(),
+        "removed" => "only in reference".to_string(),
+        "differs" if differences == 0 => "substructures differ".to_string(),
+        "differs" if differences == 1 => "1 differing field".to_string(),
+        "differs" => format!("{differences} differing fields"),
+        _ => "identical".to_string(),
+    };
+    let _ = writeln!(
+        html,
+        "<details class=\"{status}\"{}><summary>{}<span class=\"badge\">{badge}</span></summary>",
+        if status == "same" { "" } else { " open" },
+        escape(structure["name"].as_str().unwrap_or_default()),
+    );
+
+    let pairs = paired_items(ref_structure, comp_structure);
+    if !pairs.is_empty() {
+        html.push_str(
+            "<table>\n<tr><th>Field</th><th>Reference</th><th>Compared</th><th>Description</th></tr>\n",
+        );
+        for (i, (ref_item, comp_item)) in pairs.into_iter().enumerate() {
+            let item = ref_item.or(comp_item).unwrap();
+            let differs = entry["structure_differences"][i] == Value::Bool(true);
+            let _ = writeln!(
+                html,
+                "<tr class=\"{}\"><td>{}</td><td class=\"value\">{}</td><td class=\"value\">{}</td><td>{}</td></tr>",
+                if differs { "differs" } else { "same" },
+                escape(item["name"].as_str().unwrap_or_default()),
+                escape(ref_item.and_then(|item| item["value"].as_str()).unwrap_or_default()),
+                escape(comp_item.and_then(|item| item["value"].as_str()).unwrap_or_default()),
+                escape(item["description"].as_str().unwrap_or_default()),
+            );
+        }
+        html.push_str("</table>\n");
+    }
+
+    for substructure in entry["substructure_differences"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+    {
+        write_logical_entry(html, substructure);
+    }
+
+    html.push_str("</details>\n");
+}
+
+/// Whether anything below a logical comparison entry differs
+fn logical_has_differences(entry: &Value) -> bool {
+    entry["ref_structure"].is_null()
+        || entry["comp_structure"].is_null()
+        || entry["structure_differences"]
+            .as_array()
+            .is_some_and(|differences| differences.contains(&Value::Bool(true)))
+        || entry["substructure_differences"]
+            .as_array()
+            .is_some_and(|substructures| substructures.iter().any(logical_has_differences))
+}
+
+/// The items of the two structures of a logical comparison entry paired by name, in the order of
+/// its `structure_differences`: the items of the reference, then those only the compared has
+pub fn paired_items<'a>(
+    ref_structure: &'a Value,
+    comp_structure: &'a Value,
+) -> Vec<(Option<&'a Value>, Option<&'a Value>)> {
+    let ref_items = items(ref_structure);
+    let comp_items = items(comp_structure);
+    let comp_item = |name: &Value| comp_items.iter().find(|item| item["name"] == *name);
+
+    let mut pairs = ref_items
+        .iter()
+        .map(|item| (Some(item), comp_item(&item["name"])))
+        .collect::<Vec<_>>();
+    pairs.extend(
+        comp_items
+            .iter()
+            .filter(|item| {
+                !ref_items
+                    .iter()
+                    .any(|ref_item| ref_item["name"] == item["name"])
+            })
+            .map(|item| (None, Some(item))),
+    );
+    pairs
+}
+
+fn items(structure: &Value) -> &[Value] {
+    structure["structure"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+fn write_physical_entry(html: &mut String, entry: &Value) {
+    let ref_structure = &entry["ref_structure"];
+    let comp_structure = &entry["comp_structure"];
+    let structure = if ref_structure.is_null() {
+        comp_structure
+    } else {
+        ref_structure
+    };
+    let ranges = difference_ranges(&entry["difference_indices"]);
+    let status = entry_status(entry, !ranges.is_empty());
+
+    let _ = writeln!(
+        html,
+        "<details class=\"{status}\"{}><summary>{} [{}..{}] {} <i>{}</i><span class=\"badge\">{}</span></summary>",
+        if status == "same" { "" } else { " open" },
+        escape(structure["stream_name"].as_str().unwrap_or_default()),
+        structure["start_index"],
+        structure["end_index"],
+        escape(structure["structure_name"].as_str().unwrap_or_default()),
+        escape(structure["description"].as_str().unwrap_or_default()),
+        match (status, ranges.len()) {
+            ("added", _) => "only in compared".to_string(),
+            ("removed", _) => "only in reference".to_string(),
+            (_, 0) => "identical".to_string(),
+            (_, 1) => "1 differing range".to_string(),
+            (_, n) => format!("{n} differing ranges"),
+        },
+    );
+
+    html.push_str("<div class=\"hex-pair\">\n");
+    for (title, structure) in [("Reference", ref_structure), ("Compared", comp_structure)] {
+        let bytes =
+            hex::decode(structure["bytes"].as_str().unwrap_or_default()).unwrap_or_default();
+        let start_index = structure["start_index"].as_u64().unwrap_or_default() as usize;
+        let _ = writeln!(html, "<div><h4>{title}</h4>");
+        write_hex_view(html, &bytes, start_index, &ranges);
+        html.push_str("</div>\n");
+    }
+    html.push_str("</div>\n</details>\n");
+}
+
+/// The `difference_indices` of a physical comparison, these are indices of nibbles
+fn difference_ranges(difference_indices: &Value) -> Vec<(usize, usize)> {
+    difference_indices
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|range| Some((range[0].as_u64()? as usize, range[1].as_u64()? as usize)))
+        .collect()
+}
+
+/// Writes `bytes` as rows of offsets and hex, colouring each byte that has a nibble within one of
+/// the `nibble_ranges`
+fn write_hex_view(
+    html: &mut String,
+    bytes: &[u8],
+    start_index: usize,
+    nibble_ranges: &[(usize, usize)],
+) {
+    let differs = |byte_index: usize| {
+        nibble_ranges
+            .iter()
+            .any(|(start, end)| byte_index * 2 + 1 >= *start && byte_index * 2 < *end)
+    };
+
+    html.push_str("<pre class=\"hex\">");
+    for (row_index, row) in bytes.chunks(HEX_ROW_LEN).enumerate() {
+        let offset = start_index + row_index * HEX_ROW_LEN;
+        let _ = write!(html, "<span class=\"offset\">{offset:08X}</span> ");
+        for (i, byte) in row.iter().enumerate() {
+            if differs(row_index * HEX_ROW_LEN + i) {
+                let _ = write!(html, " <span class=\"diff\">{byte:02X}</span>");
+            } else {
+                let _ = write!(html, " {byte:02X}");
+            }
+        }
+        html.push('\n');
+    }
+    html.push_str("</pre>\n");
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            character => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_logical_report_highlights_differences() {
+        let comparison = json!({
+            "schema_version": 1,
+            "kind": "logical_comparison",
+            "data": [{
+                "ref_structure": {"name": "Fib", "structure": [
+                    {"name": "nFib", "value": "193"},
+                    {"name": "lKey", "value": "<0>", "description": "Encryption key"},
+                ], "substructs": null},
+                "comp_structure": {"name": "Fib", "structure": [
+                    {"name": "nFib", "value": "193"},
+                    {"name": "lKey", "value": "1"},
+                ], "substructs": null},
+                "structure_differences": [false, true],
+                "substructure_differences": [],
+            }],
+        });
+
+        let html = comparison_report("a.doc", "b&c.doc", &[&comparison]);
+        assert!(html.contains("b&amp;c.doc"));
+        assert!(html.contains("<details class=\"differs\" open><summary>Fib"));
+        assert!(
+            html.contains("<tr class=\"differs\"><td>lKey</td><td class=\"value\">&lt;0&gt;</td>")
+        );
+        assert!(html.contains("<tr class=\"same\"><td>nFib</td>"));
+    }
+
+    #[test]
+    fn test_hex_view_colours_differing_bytes() {
+        let mut html = String::new();
+        // nibbles 3..4 are the low nibble of the second byte
+        write_hex_view(&mut html, &[0x00, 0x01, 0x02], 0x10, &[(3, 4)]);
+        assert_eq!(
+            html,
+            "<pre class=\"hex\"><span class=\"offset\">00000010</span>  00 <span class=\"diff\">01</span> 02\n</pre>\n"
+        );
+    }
+}
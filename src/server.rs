@@ -0,0 +1,420 @@
+//! A small local HTTP server that serves the JSON used by the frontend.
+//!
+//! Documents are uploaded as the raw request body and are cached by the SHA-256 of their
+//! content, so viewing the same document again (or uploading it twice) does not decode it again.
+//! The least recently used documents are dropped once the cache is full.
+//!
+//! | Method | Path                                   | Response                                 |
+//! |--------|----------------------------------------|------------------------------------------|
+//! | POST   | `/documents`                           | `{ id, cached }` of the uploaded file    |
+//! | GET    | `/documents`                           | ids of all cached documents              |
+//! | GET    | `/documents/{id}/logical`              | `WordDocument::to_json_logical`          |
+//! | GET    | `/documents/{id}/physical`             | `WordDocument::to_json_physical`         |
+//! | GET    | `/documents/{id}/coverage`             | `WordDocument::to_json_coverage`         |
+//! | GET    | `/compare/{id}/{other_id}/logical`     | `WordDocument::compare_to_logical`       |
+//! | GET    | `/compare/{id}/{other_id}/physical`    | `WordDocument::compare_to_physical`      |
+//! | GET    | `/compare/{id}/{other_id}/text`        | `WordDocument::compare_to_text`          |
+
+use crate::decoder::WordDocument;
+use serde_derive::Serialize;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::{
+    cell::Cell,
+    collections::HashMap,
+    io::{self, Read},
+    panic::{self, AssertUnwindSafe},
+};
+use tiny_http::{Header, Method, Request, Response, Server};
+use ts_rs::TS;
+
+/// Uploads larger than this are refused, Word 97-2003 files are limited to 2GB but anything
+/// near that is not something we want to hold in memory
+pub const MAX_UPLOAD_SIZE: usize = 256 * 1024 * 1024;
+
+/// Number of documents the cache holds before it drops the least recently used
+pub const MAX_CACHED_DOCUMENTS: usize = 32;
+
+/// Total size of the uploads the cache holds before it drops the least recently used
+pub const MAX_CACHED_BYTES: usize = 1024 * 1024 * 1024;
+
+/// Response to an upload
+#[derive(Debug, Serialize, TS)]
+#[ts(export)]
+pub struct DocumentUpload {
+    pub id: String,
+    /// Whether the document had already been uploaded
+    pub cached: bool,
+}
+
+/// Body of every response that is not a success
+#[derive(Debug, Serialize, TS)]
+#[ts(export)]
+pub struct ApiError {
+    pub error: String,
+}
+
+/// Decoded documents, keyed by the hex encoded SHA-256 of their bytes. The least recently used
+/// documents are dropped when there are more than `max_documents` or their uploads add up to
+/// more than `max_bytes`
+pub struct DocumentCache {
+    documents: HashMap<String, CachedDocument>,
+    /// Counts the uses of the documents, to tell which was used last
+    clock: Cell<u64>,
+    max_documents: usize,
+    max_bytes: usize,
+}
+
+struct CachedDocument {
+    word_doc: WordDocument,
+    /// Size of the upload
+    size: usize,
+    last_used: Cell<u64>,
+}
+
+impl Default for DocumentCache {
+    fn default() -> Self {
+        Self::with_limits(MAX_CACHED_DOCUMENTS, MAX_CACHED_BYTES)
+    }
+}
+
+impl DocumentCache {
+    pub fn with_limits(max_documents: usize, max_bytes: usize) -> Self {
+        DocumentCache {
+            documents: HashMap::new(),
+            clock: Cell::new(0),
+            max_documents,
+            max_bytes,
+        }
+    }
+
+    /// Decodes and caches `bytes`, returning the id of the document and whether it was already cached
+    pub fn insert(&mut self, bytes: Vec<u8>) -> Result<(String, bool), String> {
+        let id = content_hash(&bytes);
+        if self.get(&id).is_some() {
+            return Ok((id, true));
+        }
+        let size = bytes.len();
+
+        let word_doc =
+            match panic::catch_unwind(AssertUnwindSafe(|| WordDocument::read_bytes(bytes))) {
+                Ok(Ok(word_doc)) => word_doc,
+                Ok(Err(err)) => return Err(err.to_string()),
+                Err(_) => return Err("the document could not be decoded".to_string()),
+            };
+
+        self.evict(size);
+        let cached = CachedDocument {
+            word_doc,
+            size,
+            last_used: Cell::new(self.tick()),
+        };
+        self.documents.insert(id.clone(), cached);
+
+        Ok((id, false))
+    }
+
+    pub fn get(&self, id: &str) -> Option<&WordDocument> {
+        let cached = self.documents.get(id)?;
+        cached.last_used.set(self.tick());
+        Some(&cached.word_doc)
+    }
+
+    /// Drops the least recently used documents until a document of `size` bytes fits
+    fn evict(&mut self, size: usize) {
+        loop {
+            let cached_bytes = self
+                .documents
+                .values()
+                .map(|cached| cached.size)
+                .sum::<usize>();
+            if self.documents.is_empty()
+                || (self.documents.len() < self.max_documents
+                    && cached_bytes + size <= self.max_bytes)
+            {
+                return;
+            }
+
+            let least_recently_used = self
+                .documents
+                .iter()
+                .min_by_key(|(_, cached)| cached.last_used.get())
+                .map(|(id, _)| id.clone())
+                .expect("the cache is not empty");
+            self.documents.remove(&least_recently_used);
+        }
+    }
+
+    fn tick(&self) -> u64 {
+        self.clock.set(self.clock.get() + 1);
+        self.clock.get()
+    }
+
+    pub fn ids(&self) -> Vec<&str> {
+        let mut ids = self
+            .documents
+            .keys()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        ids.sort();
+        ids
+    }
+}
+
+/// Hex encoded SHA-256 of `bytes`, used as the id of an uploaded document
+pub fn content_hash(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+/// Serves the API on `address` (e.g. `127.0.0.1:8080`) until the process is stopped. Only the
+/// web page of `allow_origin` gets CORS headers, any other page could read the uploaded documents
+pub fn serve(address: &str, allow_origin: Option<&str>) -> io::Result<()> {
+    let allow_origin = allow_origin
+        .map(|origin| {
+            Header::from_bytes("Access-Control-Allow-Origin", origin).map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid origin '{origin}'"),
+                )
+            })
+        })
+        .transpose()?;
+    let server = Server::http(address).map_err(io::Error::other)?;
+    let mut cache = DocumentCache::default();
+
+    // Requests are handled one at a time, the decoder keeps its streams in a `RefCell`
+    for mut request in server.incoming_requests() {
+        let (status, body) = handle_request(&mut cache, &mut request);
+        let body = if body.is_null() {
+            String::new()
+        } else {
+            body.to_string()
+        };
+        let mut response = Response::from_string(body)
+            .with_status_code(status)
+            .with_header(header("Content-Type", "application/json"));
+        if let Some(allow_origin) = &allow_origin {
+            response = response
+                .with_header(allow_origin.clone())
+                .with_header(header("Access-Control-Allow-Methods", "GET, POST, OPTIONS"))
+                .with_header(header("Access-Control-Allow-Headers", "Content-Type"))
+                .with_header(header("Vary", "Origin"));
+        }
+
+        if let Err(err) = request.respond(response) {
+            eprintln!("failed to respond: {err}");
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_request(cache: &mut DocumentCache, request: &mut Request) -> (u16, Value) {
+    let method = request.method().clone();
+    let url = request.url().to_string();
+
+    if method == Method::Post && route_path(&url) == ["documents"] {
+        let mut bytes = Vec::new();
+        let reader = request.as_reader();
+        if let Err(err) = reader
+            .take(MAX_UPLOAD_SIZE as u64 + 1)
+            .read_to_end(&mut bytes)
+        {
+            return error(400, &format!("could not read the upload: {err}"));
+        }
+        return upload(cache, bytes);
+    }
+
+    // a panic in the decoder must not take down the server with every cached document
+    catch_panic(|| route(cache, &method, &url))
+}
+
+/// Runs `handle`, answering with a 500 if it panics
+fn catch_panic(handle: impl FnOnce() -> (u16, Value)) -> (u16, Value) {
+    panic::catch_unwind(AssertUnwindSafe(handle))
+        .unwrap_or_else(|_| error(500, "the request could not be handled"))
+}
+
+/// Handles an uploaded document
+pub fn upload(cache: &mut DocumentCache, bytes: Vec<u8>) -> (u16, Value) {
+    if bytes.is_empty() {
+        return error(400, "the request body should contain the document");
+    }
+    if bytes.len() > MAX_UPLOAD_SIZE {
+        return error(413, "the document is too large");
+    }
+
+    match cache.insert(bytes) {
+        Ok((id, cached)) => (
+            if cached { 200 } else { 201 },
+            json!(DocumentUpload { id, cached }),
+        ),
+        Err(err) => error(422, &err),
+    }
+}
+
+/// Handles every request that does not carry a body
+pub fn route(cache: &DocumentCache, method: &Method, url: &str) -> (u16, Value) {
+    if *method == Method::Options {
+        return (204, Value::Null);
+    }
+    if *method != Method::Get {
+        return error(405, "method not allowed");
+    }
+
+    let get_document = |id: &str| {
+        cache
+            .get(id)
+            .ok_or_else(|| error(404, &format!("no document with id '{id}', upload it first")))
+    };
+
+    let result = match route_path(url).as_slice() {
+        ["documents"] => Ok((200, json!(cache.ids()))),
+        ["documents", id, "logical"] => get_document(id).map(|doc| (200, doc.to_json_logical())),
+        ["documents", id, "physical"] => get_document(id)
+            .and_then(|doc| doc.to_json_physical().map_err(unreadable))
+            .map(|physical| (200, physical)),
+        ["documents", id, "coverage"] => get_document(id)
+            .and_then(|doc| doc.to_json_coverage().map_err(unreadable))
+            .map(|coverage| (200, coverage)),
+        ["compare", id, other_id, mode] => get_document(id).and_then(|doc| {
+            let other_doc = get_document(other_id)?;
+            // comparing needs the content, which the server can not decrypt without a password
+            if let Some(encrypted_id) = [(id, doc), (other_id, other_doc)]
+                .into_iter()
+                .find_map(|(id, doc)| doc.needs_password().then_some(id))
+            {
+                return Err(error(
+                    422,
+                    &format!("document '{encrypted_id}' is encrypted, it can not be compared"),
+                ));
+            }
+            match *mode {
+                "logical" => Ok((200, doc.compare_to_logical(other_doc))),
+                "physical" => doc
+                    .compare_to_physical(other_doc)
+                    .map(|comparison| (200, comparison))
+                    .map_err(unreadable),
+                "text" => Ok((200, doc.compare_to_text(other_doc))),
+                _ => Err(error(404, &format!("unknown comparison '{mode}'"))),
+            }
+        }),
+        _ => Err(error(404, "not found")),
+    };
+
+    result.unwrap_or_else(|err| err)
+}
+
+/// Splits the path of `url` into its segments, ignoring any query string
+fn route_path(url: &str) -> Vec<&str> {
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect()
+}
+
+/// The answer for a document whose structures could not be read from its streams
+fn unreadable(err: io::Error) -> (u16, Value) {
+    error(422, &err.to_string())
+}
+
+fn error(status: u16, message: &str) -> (u16, Value) {
+    let error = ApiError {
+        error: message.to_string(),
+    };
+    (status, json!(error))
+}
+
+fn header(name: &str, value: &str) -> Header {
+    Header::from_bytes(name.as_bytes(), value.as_bytes()).expect("static header is valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route_path() {
+        assert_eq!(route_path("/documents"), vec!["documents"]);
+        assert_eq!(
+            route_path("/compare/ab/cd/text?pretty=1"),
+            vec!["compare", "ab", "cd", "text"]
+        );
+        assert_eq!(route_path("/"), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_upload_rejects_invalid_documents() {
+        let mut cache = DocumentCache::default();
+
+        let (status, _) = upload(&mut cache, vec![]);
+        assert_eq!(status, 400);
+
+        let (status, body) = upload(&mut cache, b"not a compound file".to_vec());
+        assert_eq!(status, 422);
+        assert!(body["error"].is_string());
+        assert!(cache.ids().is_empty());
+    }
+
+    #[test]
+    fn test_panics_are_internal_errors() {
+        let (status, body) = catch_panic(|| panic!("decoder bug"));
+        assert_eq!(status, 500);
+        assert!(body["error"].is_string());
+
+        assert_eq!(catch_panic(|| (200, json!([]))), (200, json!([])));
+    }
+
+    #[test]
+    fn test_cache_drops_least_recently_used() {
+        let documents = [&b"one\r"[..], b"two\r", b"three\r"]
+            .map(|text| crate::decoder::tests::empty_document(text));
+        let ids = documents.each_ref().map(|bytes| content_hash(bytes));
+
+        let mut cache = DocumentCache::with_limits(2, usize::MAX);
+        cache.insert(documents[0].clone()).unwrap();
+        cache.insert(documents[1].clone()).unwrap();
+        assert!(cache.get(&ids[0]).is_some());
+        cache.insert(documents[2].clone()).unwrap();
+        let mut expected = vec![ids[0].as_str(), ids[2].as_str()];
+        expected.sort();
+        assert_eq!(cache.ids(), expected);
+
+        // the byte limit leaves room for a single document
+        let mut cache = DocumentCache::with_limits(10, documents[0].len());
+        cache.insert(documents[0].clone()).unwrap();
+        assert_eq!(
+            cache.insert(documents[1].clone()),
+            Ok((ids[1].clone(), false))
+        );
+        assert_eq!(cache.ids(), vec![ids[1].as_str()]);
+    }
+
+    #[test]
+    fn test_encrypted_documents_are_not_compared() {
+        let mut cache = DocumentCache::default();
+        let (status, body) = upload(&mut cache, crate::decoder::tests::obfuscated_document());
+        assert_eq!(status, 201);
+        let id = body["id"].as_str().unwrap();
+
+        let (status, _) = route(&cache, &Method::Get, &format!("/documents/{id}/logical"));
+        assert_eq!(status, 200);
+        let (status, body) = route(&cache, &Method::Get, &format!("/compare/{id}/{id}/text"));
+        assert_eq!(status, 422);
+        assert!(body["error"].as_str().unwrap().contains("encrypted"));
+    }
+
+    #[test]
+    fn test_unknown_documents() {
+        let cache = DocumentCache::default();
+
+        let (status, body) = route(&cache, &Method::Get, "/documents");
+        assert_eq!((status, body), (200, json!([])));
+
+        let (status, _) = route(&cache, &Method::Get, "/documents/abc/logical");
+        assert_eq!(status, 404);
+
+        let (status, _) = route(&cache, &Method::Delete, "/documents");
+        assert_eq!(status, 405);
+    }
+}